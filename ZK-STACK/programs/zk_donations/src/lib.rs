@@ -1,9 +1,14 @@
 use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{transfer_checked, Mint, Token, TokenAccount, TransferChecked};
 use account_compression::program::AccountCompression; // Import the Light Protocol program
 use account_compression::cpi::accounts::InitializeBatchedStateMerkleTreeAndQueue as AccCompInitializeBatchedTreeAndQueue;
 use account_compression::cpi::initialize_batched_state_merkle_tree;
-use account_compression::cpi::accounts::BatchAppend;
-use account_compression::cpi::batch_append;
+use account_compression::cpi::accounts::{AppendLeaves, BatchAppend};
+use account_compression::cpi::{append_leaves_to_merkle_trees, batch_append};
+use light_batched_merkle_tree::merkle_tree::InstructionDataBatchAppendInputs;
+use light_compressed_account::instruction_data::compressed_proof::CompressedProof;
+use light_hasher::hash_to_field_size::hashv_to_bn254_field_size_be;
 
 // Define the Account Compression Program ID
 mod light_programs {
@@ -11,6 +16,12 @@ mod light_programs {
     declare_id!("compr6CUsB5m2jS4Y3831ztGSTnDpnKJTKS95d64XVq");
 }
 
+// SPL Noop program, used by Light Protocol as the log wrapper for tree changelogs
+mod noop_program {
+    use anchor_lang::declare_id;
+    declare_id!("noop8ytexvkpCuqbf6FB89BSuNemHtPRqaNC31GWivW");
+}
+
 declare_id!("9PY5ThauBNu2GJCKmeP5y8LN6zbNvEdXnVaKwnvHGZCW");
 
 #[program]
@@ -25,10 +36,13 @@ pub mod zk_donations {
     /// - `title`: The title of the campaign.
     /// - `description`: A description of the campaign.
     /// - `params_bytes`: Serialized InitStateTreeAccountsInstructionData.
+    /// - `goal_amount`: Fundraising goal in base units (0 = no goal).
+    /// - `deadline`: Unix timestamp the campaign runs until (0 = no deadline).
     ///
     /// Accounts:
     /// - `user`: The signer initializing the campaign.
     /// - `campaign`: The campaign account (PDA) to be initialized.
+    /// - `mint`: The token mint donations are made in.
     /// - `merkle_tree`: The account for the Merkle tree, created via CPI to Light Protocol.
     /// - `output_queue`: The account for the output queue, created via CPI to Light Protocol.
     /// - `light_account_compression_program`: The Light Protocol account compression program.
@@ -39,6 +53,8 @@ pub mod zk_donations {
         title: String,
         description: String,
         params_bytes: Vec<u8>, // Serialized InitStateTreeAccountsInstructionData
+        goal_amount: u64,
+        deadline: i64,
     ) -> Result<()> {
         msg!("Initializing campaign and Light Protocol state...");
         let campaign = &mut ctx.accounts.campaign;
@@ -47,6 +63,13 @@ pub mod zk_donations {
         campaign.title = title.clone(); // Clone if title is used in PDA seeds
         campaign.description = description;
         campaign.bump = ctx.bumps.campaign; // Actualización para la nueva versión de Anchor
+        campaign.mint = ctx.accounts.mint.key();
+        campaign.goal_amount = goal_amount;
+        campaign.deadline = deadline;
+        campaign.total_raised = 0;
+        campaign.donation_count = 0;
+        campaign.last_update_time = Clock::get()?.unix_timestamp;
+        campaign.latest_merkle_root = [0u8; 32];
 
         // Prepare CPI accounts for creating the Merkle Tree and Queue
         let cpi_program = ctx.accounts.light_account_compression_program.to_account_info();
@@ -87,27 +110,95 @@ pub mod zk_donations {
         Ok(())
     }
 
+    /// Records a compressed donation by inserting its leaf into the campaign's output queue.
+    ///
+    /// The amount in `leaf_data` is transferred from the donor's token account to the
+    /// campaign creator's before the leaf is queued, so `total_raised` only counts
+    /// tokens the campaign actually received. The program hashes `leaf_data` itself
+    /// and appends that hash, one leaf per call. Queued leaves reach the tree later
+    /// through `settle_donation_batch`.
     pub fn donate_compressed_amount(
         ctx: Context<DonateCompressedAmount>,
         _campaign_id: u64, // Used to derive campaign PDA, might not be needed if campaign account is passed directly and validated
-        _leaf_data: Vec<u8>, // Placeholder for actual donation data / leaf representation
-        proof_data: Vec<u8>, // Placeholder for ZK proof data
+        leaf_data: Vec<u8>, // Serialized donation leaf: [leaf_version u8 | amount u64 LE | ...]
     ) -> Result<()> {
         msg!("Attempting to make a compressed donation...");
 
+        let now = Clock::get()?.unix_timestamp;
+        let deadline = ctx.accounts.campaign.deadline;
+        require!(deadline == 0 || now <= deadline, ErrorCode::CampaignEnded);
+
+        let amount = leaf_amount(&leaf_data)?;
+        ctx.accounts.lock_donated_tokens(amount)?;
+
+        // Batched trees hash leaves with Poseidon over BN254, so the leaf must be a
+        // field element; a raw 32-byte hash usually is not
+        let leaf_hash = hashv_to_bn254_field_size_be(&[&leaf_data]);
+
+        let campaign = &ctx.accounts.campaign;
+
+        // Leaves are appended to the output queue, passed as the only remaining account
+        let cpi_program = ctx.accounts.light_account_compression_program.to_account_info();
+        let cpi_accounts = AppendLeaves {
+            fee_payer: ctx.accounts.user_donator.to_account_info(),
+            authority: campaign.to_account_info(), // Campaign PDA is authority
+            registered_program_pda: None, // Not used in this context
+            system_program: ctx.accounts.system_program.to_account_info(),
+        };
+        let campaign_pda_seeds = &[
+            b"campaign".as_ref(),
+            campaign.user.as_ref(),
+            &campaign.campaign_id.to_le_bytes()[..],
+            &[campaign.bump]
+        ];
+        let signer_seeds = &[&campaign_pda_seeds[..]];
+
+        // CPI call to append_leaves_to_merkle_trees (Light Protocol)
+        append_leaves_to_merkle_trees(
+            CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds)
+                .with_remaining_accounts(vec![ctx.accounts.output_queue.to_account_info()]),
+            vec![(0, leaf_hash)]
+        )?;
+
+        let campaign = &mut ctx.accounts.campaign;
+        campaign.record_donation(amount, now)?;
+
+        msg!(
+            "Compressed donation of {} queued for campaign: {}, total raised {}",
+            amount,
+            campaign.title,
+            campaign.total_raised
+        );
+        Ok(())
+    }
+
+    /// Settles the campaign's queued donation leaves into its Merkle tree.
+    ///
+    /// Light Protocol checks `compressed_proof` against `new_root`; once the append
+    /// succeeds the root is recorded as the campaign's latest. Counters are not
+    /// touched here, since every leaf was already counted when it was queued.
+    pub fn settle_donation_batch(
+        ctx: Context<SettleDonationBatch>,
+        _campaign_id: u64,
+        new_root: [u8; 32], // Tree root after the queued donation leaves are appended
+        compressed_proof: CompressedProof, // Proof of the append, checked by Light Protocol
+    ) -> Result<()> {
         let campaign = &ctx.accounts.campaign;
-        // TODO: 1. Client must serialize InstructionDataBatchAppendInputs (from Light Protocol) and pass as proof_data or a new param.
-        // For ahora, proof_data se espera que sea el Vec<u8> serializado correcto.
-        // Ver: external/light-protocol/programs/account-compression/src/lib.rs (batch_append)
 
-        // Prepare CPI context for batch_append
+        // batch_append takes the Borsh-serialized InstructionDataBatchAppendInputs
+        let batch_append_inputs = InstructionDataBatchAppendInputs {
+            new_root,
+            compressed_proof,
+        };
+        let batch_append_data = batch_append_inputs.try_to_vec()?;
+
         let cpi_program = ctx.accounts.light_account_compression_program.to_account_info();
         let cpi_accounts = BatchAppend {
             authority: campaign.to_account_info(), // Campaign PDA is authority
             registered_program_pda: None, // Not used in this context
-            log_wrapper: ctx.accounts.campaign.to_account_info(), // Placeholder, NOOP account may be needed
+            log_wrapper: ctx.accounts.log_wrapper.to_account_info(),
             merkle_tree: ctx.accounts.merkle_tree.to_account_info(),
-            output_queue: ctx.accounts.output_queue.to_account_info(), // Ahora utilizamos output_queue propiamente
+            output_queue: ctx.accounts.output_queue.to_account_info(),
         };
         let campaign_pda_seeds = &[
             b"campaign".as_ref(),
@@ -118,17 +209,46 @@ pub mod zk_donations {
         let signer_seeds = &[&campaign_pda_seeds[..]];
 
         // CPI call to batch_append (Light Protocol)
-        // TODO: Replace proof_data with the correct serialization of InstructionDataBatchAppendInputs
         batch_append(
             CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds),
-            proof_data // Should be the serialized InstructionDataBatchAppendInputs
+            batch_append_data
         )?;
 
-        msg!("Compressed donation processed for campaign: {}", campaign.title);
+        let campaign = &mut ctx.accounts.campaign;
+        campaign.latest_merkle_root = new_root;
+        campaign.last_update_time = Clock::get()?.unix_timestamp;
+
+        msg!("Donation batch settled for campaign: {}", campaign.title);
         Ok(())
     }
 }
 
+/// Donation amount from a serialized leaf, laid out as heart_of_blockchain's
+/// `DonationLeaf`: a version byte followed by the little-endian u64 amount.
+pub fn leaf_amount(leaf_data: &[u8]) -> Result<u64> {
+    let amount_bytes = leaf_data
+        .get(1..9)
+        .ok_or(error!(ErrorCode::InvalidLeafData))?;
+    let mut amount = [0u8; 8];
+    amount.copy_from_slice(amount_bytes);
+    Ok(u64::from_le_bytes(amount))
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Donation leaf is too short to contain an amount")]
+    InvalidLeafData,
+
+    #[msg("Arithmetic overflow")]
+    ArithmeticOverflow,
+
+    #[msg("Campaign deadline has passed")]
+    CampaignEnded,
+
+    #[msg("Donor's token balance is below the donated amount")]
+    InsufficientDonorBalance,
+}
+
 /// Context for the initialize_campaign instruction.
 #[derive(Accounts)]
 #[instruction(campaign_id: u64, title: String, description: String, params_bytes: Vec<u8>)]
@@ -147,6 +267,9 @@ pub struct InitializeCampaign<'info> {
     )]
     pub campaign: Account<'info, Campaign>,
 
+    /// The token mint donations to this campaign are made in.
+    pub mint: Account<'info, Mint>,
+
     /// CHECK: The Merkle tree account to be initialized by Light Protocol.
     /// Assumed to be created and rent-paid by the client before this instruction.
     #[account(mut)]
@@ -167,13 +290,73 @@ pub struct InitializeCampaign<'info> {
 
 /// Context for the donate_compressed_amount instruction
 #[derive(Accounts)]
-#[instruction(_campaign_id: u64, _leaf_data: Vec<u8>, proof_data: Vec<u8>)] // Match args with handler
+#[instruction(_campaign_id: u64, leaf_data: Vec<u8>)] // Match args with handler
 pub struct DonateCompressedAmount<'info> {
     #[account(mut)]
-    pub user_donator: Signer<'info>, // The one making the donation and signing
+    pub user_donator: Signer<'info>, // The donor, paying both the tokens and the append fee
 
-    // Campaign account, needs to be verified or use Account<'info, Campaign>
-    #[account(mut, has_one = merkle_tree)] // merkle_tree field in Campaign struct must match the merkle_tree account below.
+    // The leaf can only go into the queue recorded on the campaign, in its mint
+    #[account(mut, has_one = output_queue, has_one = mint)]
+    pub campaign: Account<'info, Campaign>,
+
+    pub mint: Account<'info, Mint>,
+
+    /// Donor's token account the leaf's amount is transferred from
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = user_donator,
+    )]
+    pub donor_token_account: Account<'info, TokenAccount>,
+
+    /// Campaign creator's token account the donation is transferred into
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = campaign.user,
+    )]
+    pub campaign_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: The Output queue account associated with the campaign.
+    #[account(mut)] // Queue is modified
+    pub output_queue: UncheckedAccount<'info>,
+
+    #[account(address = light_programs::ID)]
+    pub light_account_compression_program: Program<'info, AccountCompression>,
+
+    /// Solana System Program, used by Light Protocol to charge the append fee.
+    pub system_program: Program<'info, System>,
+
+    pub token_program: Program<'info, Token>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+impl<'info> DonateCompressedAmount<'info> {
+    /// Transfer `amount` from the donor's token account into the campaign's,
+    /// rejecting leaves that claim more than the donor holds
+    fn lock_donated_tokens(&self, amount: u64) -> Result<()> {
+        require!(self.donor_token_account.amount >= amount, ErrorCode::InsufficientDonorBalance);
+
+        let cpi_accounts = TransferChecked {
+            from: self.donor_token_account.to_account_info(),
+            to: self.campaign_token_account.to_account_info(),
+            mint: self.mint.to_account_info(),
+            authority: self.user_donator.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(self.token_program.to_account_info(), cpi_accounts);
+        transfer_checked(cpi_ctx, amount, self.mint.decimals)
+    }
+}
+
+/// Context for the settle_donation_batch instruction
+#[derive(Accounts)]
+#[instruction(_campaign_id: u64)]
+pub struct SettleDonationBatch<'info> {
+    pub payer: Signer<'info>, // Anyone holding a valid batch proof, typically the forester
+
+    // merkle_tree and output_queue fields in Campaign must match the accounts below.
+    #[account(mut, has_one = merkle_tree, has_one = output_queue)]
     pub campaign: Account<'info, Campaign>,
 
     /// CHECK: The Merkle tree account associated with the campaign.
@@ -182,14 +365,15 @@ pub struct DonateCompressedAmount<'info> {
     pub merkle_tree: UncheckedAccount<'info>,
 
     /// CHECK: The Output queue account associated with the campaign.
-    #[account(mut)] // Queue is modified
+    #[account(mut)] // Queue is drained into the tree
     pub output_queue: UncheckedAccount<'info>,
 
+    /// CHECK: SPL Noop program Light Protocol logs changelog entries through.
+    #[account(address = noop_program::ID)]
+    pub log_wrapper: UncheckedAccount<'info>,
+
     #[account(address = light_programs::ID)]
     pub light_account_compression_program: Program<'info, AccountCompression>,
-
-    // Payer for any transaction fees if not user_donator implicitly
-    // pub system_program: Program<'info, System>, // May not be needed if no new accounts created by this instruction directly
 }
 
 /// State account for a donation campaign.
@@ -209,7 +393,20 @@ pub struct Campaign {
     pub output_queue: Pubkey,
     /// PDA bump seed.
     pub bump: u8,
-    // TODO: Add other fields like goal amount, deadline, total raised (if needed off-chain)
+    /// Token mint donations are made in.
+    pub mint: Pubkey,
+    /// Fundraising goal in base units (0 = no goal).
+    pub goal_amount: u64,
+    /// Unix timestamp the campaign runs until (0 = no deadline).
+    pub deadline: i64,
+    /// Sum of the token amounts transferred in with every queued donation leaf.
+    pub total_raised: u64,
+    /// Number of donation leaves this program has queued.
+    pub donation_count: u64,
+    /// Unix timestamp of initialization or the latest donation or settlement.
+    pub last_update_time: i64,
+    /// Tree root after the latest settled batch (zero until the first one).
+    pub latest_merkle_root: [u8; 32],
 }
 
 impl Campaign {
@@ -222,5 +419,22 @@ impl Campaign {
         + 4 + 200 // description String (assuming max 200 chars)
         + 32 // merkle_tree pubkey
         + 32 // output_queue pubkey
-        + 1; // bump u8
+        + 1 // bump u8
+        + 32 // mint pubkey
+        + 8 // goal_amount u64
+        + 8 // deadline i64
+        + 8 // total_raised u64
+        + 8 // donation_count u64
+        + 8 // last_update_time i64
+        + 32; // latest_merkle_root [u8; 32]
+
+    /// Count one queued donation leaf worth `amount`.
+    pub fn record_donation(&mut self, amount: u64, now: i64) -> Result<()> {
+        self.total_raised = self.total_raised.checked_add(amount)
+            .ok_or(error!(ErrorCode::ArithmeticOverflow))?;
+        self.donation_count = self.donation_count.checked_add(1)
+            .ok_or(error!(ErrorCode::ArithmeticOverflow))?;
+        self.last_update_time = now;
+        Ok(())
+    }
 }
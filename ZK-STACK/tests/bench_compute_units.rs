@@ -0,0 +1,262 @@
+//! Compute-unit benchmarks
+//!
+//! Runs each instruction against the compiled program in a BanksClient and
+//! fails if it consumes more compute units than its documented ceiling.
+//! Requires `anchor build` output (heart_of_blockchain.so) and the Light
+//! account compression program (account_compression.so) in the BPF_OUT_DIR,
+//! and the crate's `client` feature for the instruction builders.
+//!
+//! NOT RUN: this benchmark imports the program crate, which needs the Light
+//! Protocol sources to build. It is not listed in Cargo.toml, has never been
+//! compiled or run in this tree, and does not count as coverage; see README.md.
+
+use anchor_lang::solana_program::program_pack::Pack;
+use anchor_lang::solana_program::pubkey::Pubkey;
+use anchor_spl::associated_token::{get_associated_token_address, spl_associated_token_account};
+use anchor_spl::token::spl_token;
+use heart_of_blockchain::client::{
+    campaign_pda, commitment_binding_ix, create_campaign_tree_account_ix, donate_amount_ix, donate_compressed_ix,
+    init_campaign_ix, init_doner_ix, init_global_config_ix,
+};
+use heart_of_blockchain::{DonateCompressed, InitCampaignParams};
+use solana_program_test::{BanksClient, ProgramTest};
+use solana_sdk::instruction::Instruction;
+use solana_sdk::signature::{Keypair, Signer};
+use solana_sdk::system_instruction;
+use solana_sdk::transaction::Transaction;
+
+/// Documented compute ceilings; raise deliberately, never to make a regression pass
+const INIT_CAMPAIGN_CU_CEILING: u64 = 150_000;
+const DONATE_AMOUNT_CU_CEILING: u64 = 60_000;
+const DONATE_COMPRESSED_CU_CEILING: u64 = 120_000;
+
+const CAMPAIGN_ID: u64 = 7;
+const TITLE: &str = "Clean Water";
+const MAX_DEPTH: u32 = 20;
+const MAX_BUFFER_SIZE: u32 = 64;
+const DONOR_COMMITMENT: [u8; 32] = [3u8; 32];
+
+#[tokio::test]
+async fn bench_instruction_compute_units() {
+    let mut program_test = ProgramTest::new("heart_of_blockchain", heart_of_blockchain::ID, None);
+    program_test.add_program("account_compression", light_program_id(), None);
+    program_test.prefer_bpf(true);
+    let (mut banks_client, payer, _) = program_test.start().await;
+
+    let mint = Keypair::new();
+    let merkle_tree = Keypair::new();
+    let donor = Keypair::new();
+
+    // Program-wide config, a 6-decimal mint and a funded donor
+    execute(&mut banks_client, &payer, &[init_global_config_ix(payer.pubkey(), 300)], &[]).await;
+    create_mint(&mut banks_client, &payer, &mint).await;
+    execute(
+        &mut banks_client,
+        &payer,
+        &[system_instruction::transfer(&payer.pubkey(), &donor.pubkey(), 1_000_000_000)],
+        &[],
+    )
+    .await;
+    execute(
+        &mut banks_client,
+        &payer,
+        &[
+            spl_associated_token_account::instruction::create_associated_token_account(
+                &payer.pubkey(),
+                &donor.pubkey(),
+                &mint.pubkey(),
+                &spl_token::ID,
+            ),
+            spl_token::instruction::mint_to(
+                &spl_token::ID,
+                &mint.pubkey(),
+                &get_associated_token_address(&donor.pubkey(), &mint.pubkey()),
+                &payer.pubkey(),
+                &[],
+                10_000_000,
+            )
+            .unwrap(),
+        ],
+        &[],
+    )
+    .await;
+    execute(
+        &mut banks_client,
+        &payer,
+        &[create_campaign_tree_account_ix(
+            payer.pubkey(),
+            merkle_tree.pubkey(),
+            MAX_DEPTH,
+            MAX_BUFFER_SIZE,
+        )],
+        &[&merkle_tree],
+    )
+    .await;
+
+    // init_campaign
+    let units = compute_units(
+        &mut banks_client,
+        &payer,
+        &[init_campaign_ix(
+            payer.pubkey(),
+            payer.pubkey(),
+            mint.pubkey(),
+            merkle_tree.pubkey(),
+            None,
+            false,
+            0,
+            CAMPAIGN_ID,
+            TITLE.to_string(),
+            campaign_params(),
+        )],
+        &[],
+    )
+    .await;
+    assert_under_ceiling("init_campaign", units, INIT_CAMPAIGN_CU_CEILING);
+
+    // donate_amount
+    execute(
+        &mut banks_client,
+        &payer,
+        &[init_doner_ix(donor.pubkey(), campaign_pda(CAMPAIGN_ID, TITLE))],
+        &[&donor],
+    )
+    .await;
+    let units = compute_units(
+        &mut banks_client,
+        &payer,
+        &[donate_amount_ix(
+            donor.pubkey(),
+            payer.pubkey(),
+            mint.pubkey(),
+            CAMPAIGN_ID,
+            TITLE.to_string(),
+            1_000_000,
+            None,
+            None,
+            false,
+            None,
+            None,
+        )],
+        &[&donor],
+    )
+    .await;
+    assert_under_ceiling("donate_amount", units, DONATE_AMOUNT_CU_CEILING);
+
+    // donate_compressed, preceded by the donor's ed25519 commitment binding
+    let clock = banks_client.get_sysvar::<solana_sdk::clock::Clock>().await.unwrap();
+    let binding_message = DonateCompressed::commitment_binding_message(&donor.pubkey(), &DONOR_COMMITMENT, clock.slot);
+    let binding_signature = donor.sign_message(&binding_message);
+    let units = compute_units(
+        &mut banks_client,
+        &payer,
+        &[
+            commitment_binding_ix(donor.pubkey(), binding_signature.into(), DONOR_COMMITMENT, clock.slot),
+            donate_compressed_ix(
+                donor.pubkey(),
+                payer.pubkey(),
+                mint.pubkey(),
+                merkle_tree.pubkey(),
+                None,
+                CAMPAIGN_ID,
+                TITLE.to_string(),
+                proof_data(500, clock.unix_timestamp),
+                DONOR_COMMITMENT,
+                clock.slot,
+            ),
+        ],
+        &[&donor],
+    )
+    .await;
+    assert_under_ceiling("donate_compressed", units, DONATE_COMPRESSED_CU_CEILING);
+}
+
+fn light_program_id() -> Pubkey {
+    "compr6CUsB5m2jS4Y3831ztGSTnDpnKJTKS95d64XVq".parse().unwrap()
+}
+
+fn campaign_params() -> InitCampaignParams {
+    InitCampaignParams {
+        description: "Wells for rural schools".to_string(),
+        max_depth: MAX_DEPTH,
+        max_buffer_size: MAX_BUFFER_SIZE,
+        tier_thresholds: [100, 1_000, 10_000],
+        uri: None,
+        goal_amount: 50_000_000,
+        require_whole_tokens: false,
+        soft_goal: 0,
+        hard_cap: 0,
+        kyc_threshold: 0,
+        allow_self_donation: true,
+        deadline: 0,
+        gate_mint: None,
+        keywords: vec![],
+        max_donors: 0,
+        platform_referrer: None,
+        platform_fee_bps: 0,
+        circuit_breaker_threshold: 0,
+        start_time: 0,
+        max_single_donation: 0,
+        emit_events: true,
+        symbol: None,
+    }
+}
+
+/// 89-byte mock proof: leaf version, amount, donor commitment, nullifier, campaign id, timestamp
+fn proof_data(amount: u64, timestamp: i64) -> Vec<u8> {
+    let mut data = vec![heart_of_blockchain::LEAF_VERSION];
+    data.extend_from_slice(&amount.to_le_bytes());
+    data.extend_from_slice(&DONOR_COMMITMENT);
+    data.extend_from_slice(&[4u8; 32]);
+    data.extend_from_slice(&CAMPAIGN_ID.to_le_bytes());
+    data.extend_from_slice(&timestamp.to_le_bytes());
+    data
+}
+
+async fn create_mint(banks_client: &mut BanksClient, payer: &Keypair, mint: &Keypair) {
+    let rent = banks_client.get_rent().await.unwrap();
+    execute(
+        banks_client,
+        payer,
+        &[
+            system_instruction::create_account(
+                &payer.pubkey(),
+                &mint.pubkey(),
+                rent.minimum_balance(spl_token::state::Mint::LEN),
+                spl_token::state::Mint::LEN as u64,
+                &spl_token::ID,
+            ),
+            spl_token::instruction::initialize_mint(&spl_token::ID, &mint.pubkey(), &payer.pubkey(), None, 6).unwrap(),
+        ],
+        &[mint],
+    )
+    .await;
+}
+
+async fn execute(banks_client: &mut BanksClient, payer: &Keypair, instructions: &[Instruction], signers: &[&Keypair]) {
+    let blockhash = banks_client.get_latest_blockhash().await.unwrap();
+    let mut all_signers = vec![payer];
+    all_signers.extend_from_slice(signers);
+    let tx = Transaction::new_signed_with_payer(instructions, Some(&payer.pubkey()), &all_signers, blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+}
+
+/// Simulate `instructions` to read their compute usage, then execute them so later steps see their state
+async fn compute_units(banks_client: &mut BanksClient, payer: &Keypair, instructions: &[Instruction], signers: &[&Keypair]) -> u64 {
+    let blockhash = banks_client.get_latest_blockhash().await.unwrap();
+    let mut all_signers = vec![payer];
+    all_signers.extend_from_slice(signers);
+    let tx = Transaction::new_signed_with_payer(instructions, Some(&payer.pubkey()), &all_signers, blockhash);
+
+    let simulation = banks_client.simulate_transaction(tx).await.unwrap();
+    simulation.result.unwrap().unwrap();
+    let units = simulation.simulation_details.unwrap().units_consumed;
+
+    execute(banks_client, payer, instructions, signers).await;
+    units
+}
+
+fn assert_under_ceiling(name: &str, units: u64, ceiling: u64) {
+    println!("{}: {} / {} compute units", name, units, ceiling);
+    assert!(units <= ceiling, "{} consumed {} compute units, over its {} ceiling", name, units, ceiling);
+}
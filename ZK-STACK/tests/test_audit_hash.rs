@@ -0,0 +1,146 @@
+fn main() {
+    println!("Testing Campaign Audit Hash Chain");
+
+    // Replay of: init_campaign, donate_amount, donate_first_time, donate_amount
+    let operations = [
+        Operation::InitCampaign { creator: [1; 32], mint: [2; 32], campaign_id: 7, title: "Clean Water", merkle_tree: [3; 32] },
+        Operation::Donation { discriminator: DONATE_AMOUNT, donor: [4; 32], amount: 500, platform_fee: 0 },
+        Operation::Donation { discriminator: DONATE_FIRST_TIME, donor: [5; 32], amount: 1_000, platform_fee: 25 },
+        Operation::Donation { discriminator: DONATE_AMOUNT, donor: [4; 32], amount: 250, platform_fee: 0 },
+    ];
+
+    let mut campaign = MockCampaign::default();
+    let mut emitted = Vec::new();
+    for operation in &operations {
+        emitted.push(campaign.apply(operation));
+    }
+
+    // Test case: Replay matches
+    println!("\nTest 1: An auditor replaying the same instructions should reach the stored hash");
+    let mut replayed = MockCampaign::default();
+    for operation in &operations {
+        replayed.apply(operation);
+    }
+    if replayed.audit_hash == campaign.audit_hash && campaign.audit_hash != [0; 32] {
+        println!("✅ Test 1 passed: Final chained hash matches");
+    } else {
+        panic!("Test 1 failed: {:?} != {:?}", replayed.audit_hash, campaign.audit_hash);
+    }
+
+    // Test case: Events carry each step
+    println!("\nTest 2: Each event should carry the hash after its instruction");
+    let all_distinct = emitted.windows(2).all(|pair| pair[0] != pair[1]);
+    if all_distinct && *emitted.last().unwrap() == campaign.audit_hash {
+        println!("✅ Test 2 passed: {} distinct hashes, last equals the stored one", emitted.len());
+    } else {
+        panic!("Test 2 failed: Event hashes {:?}", emitted);
+    }
+
+    // Test case: Tampered history
+    println!("\nTest 3: Altering any replayed donation should change the final hash");
+    let mut tampered_ops = operations.clone();
+    tampered_ops[2] = Operation::Donation { discriminator: DONATE_FIRST_TIME, donor: [5; 32], amount: 1_001, platform_fee: 25 };
+    let mut tampered = MockCampaign::default();
+    for operation in &tampered_ops {
+        tampered.apply(operation);
+    }
+    if tampered.audit_hash != campaign.audit_hash {
+        println!("✅ Test 3 passed: Tampered history detected");
+    } else {
+        panic!("Test 3 failed: Tampered history produced the same hash");
+    }
+
+    // Test case: Instruction identity matters
+    println!("\nTest 4: The same donation through a different instruction should chain differently");
+    let mut swapped_ops = operations.clone();
+    swapped_ops[1] = Operation::Donation { discriminator: DONATE_FIRST_TIME, donor: [4; 32], amount: 500, platform_fee: 0 };
+    let mut swapped = MockCampaign::default();
+    for operation in &swapped_ops {
+        swapped.apply(operation);
+    }
+    if swapped.audit_hash != campaign.audit_hash {
+        println!("✅ Test 4 passed: Discriminator is part of the chain");
+    } else {
+        panic!("Test 4 failed: Discriminator ignored");
+    }
+
+    // Test case: Reordering
+    println!("\nTest 5: Reordering donations should change the final hash");
+    let mut reordered_ops = operations.clone();
+    reordered_ops.swap(1, 3);
+    let mut reordered = MockCampaign::default();
+    for operation in &reordered_ops {
+        reordered.apply(operation);
+    }
+    if reordered.audit_hash != campaign.audit_hash {
+        println!("✅ Test 5 passed: Order is part of the chain");
+    } else {
+        panic!("Test 5 failed: Reordered history produced the same hash");
+    }
+
+    println!("\n✅✅✅ All Audit Hash tests passed! ✅✅✅");
+}
+
+// Stand-ins for the Anchor instruction discriminators
+const INIT_CAMPAIGN: [u8; 8] = [1; 8];
+const DONATE_AMOUNT: [u8; 8] = [2; 8];
+const DONATE_FIRST_TIME: [u8; 8] = [3; 8];
+
+#[derive(Clone)]
+enum Operation {
+    InitCampaign { creator: [u8; 32], mint: [u8; 32], campaign_id: u64, title: &'static str, merkle_tree: [u8; 32] },
+    Donation { discriminator: [u8; 8], donor: [u8; 32], amount: u64, platform_fee: u64 },
+}
+
+#[derive(Default)]
+struct MockCampaign {
+    total_donation_received: u64,
+    audit_hash: [u8; 32],
+}
+
+impl MockCampaign {
+    /// Mirror of CampaignInfo::chain_audit, with a stand-in 256-bit hash for keccak
+    fn chain_audit(&mut self, instruction_discriminator: &[u8], key_fields: &[&[u8]]) -> [u8; 32] {
+        let mut inputs: Vec<&[u8]> = vec![&self.audit_hash, instruction_discriminator];
+        inputs.extend_from_slice(key_fields);
+        self.audit_hash = mock_hashv(&inputs);
+        self.audit_hash
+    }
+
+    /// Mirror of the chain updates in init_campaign and TokenDonation::process
+    fn apply(&mut self, operation: &Operation) -> [u8; 32] {
+        match operation {
+            Operation::InitCampaign { creator, mint, campaign_id, title, merkle_tree } => {
+                self.audit_hash = [0; 32];
+                self.chain_audit(
+                    &INIT_CAMPAIGN,
+                    &[creator, mint, &campaign_id.to_le_bytes(), title.as_bytes(), merkle_tree],
+                )
+            }
+            Operation::Donation { discriminator, donor, amount, platform_fee } => {
+                self.total_donation_received += amount;
+                let total = self.total_donation_received;
+                self.chain_audit(
+                    discriminator,
+                    &[donor, &amount.to_le_bytes(), &platform_fee.to_le_bytes(), &total.to_le_bytes()],
+                )
+            }
+        }
+    }
+}
+
+/// FNV-1a over the concatenated inputs, spread across four lanes
+fn mock_hashv(inputs: &[&[u8]]) -> [u8; 32] {
+    let mut out = [0u8; 32];
+    for (lane, chunk) in out.chunks_mut(8).enumerate() {
+        let mut hash: u64 = 0xcbf2_9ce4_8422_2325 ^ lane as u64;
+        for input in inputs {
+            for byte in input.iter() {
+                hash ^= u64::from(*byte);
+                hash = hash.wrapping_mul(0x0100_0000_01b3);
+            }
+        }
+        chunk.copy_from_slice(&hash.to_le_bytes());
+    }
+    out
+}
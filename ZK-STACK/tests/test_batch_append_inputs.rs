@@ -0,0 +1,84 @@
+fn main() {
+    println!("Testing zk_donations batch_append Inputs");
+
+    let inputs = InstructionDataBatchAppendInputs {
+        new_root: [1u8; 32],
+        compressed_proof: CompressedProof { a: [2u8; 32], b: [3u8; 64], c: [4u8; 32] },
+    };
+    let data = inputs.serialize();
+
+    // Test case: Serialized length
+    println!("\nTest 1: Serialized inputs should be 32 + 32 + 64 + 32 = 160 bytes");
+    if data.len() == 160 {
+        println!("✅ Test 1 passed: 160 bytes");
+    } else {
+        panic!("Test 1 failed: Expected 160 bytes, got {}", data.len());
+    }
+
+    // Test case: Field order matches Light Protocol's schema
+    println!("\nTest 2: Bytes should follow new_root, then proof a, b, c");
+    if data[..32] == [1u8; 32] && data[32..64] == [2u8; 32] && data[64..128] == [3u8; 64] && data[128..] == [4u8; 32] {
+        println!("✅ Test 2 passed: [new_root | a | b | c]");
+    } else {
+        panic!("Test 2 failed: Field order differs from the schema");
+    }
+
+    // Test case: Round trip
+    println!("\nTest 3: Deserializing the bytes should give back the same inputs");
+    match InstructionDataBatchAppendInputs::deserialize(&data) {
+        Some(decoded) if decoded == inputs => println!("✅ Test 3 passed: Round trip is lossless"),
+        other => panic!("Test 3 failed: Unexpected decode {:?}", other),
+    }
+
+    // Test case: Raw bytes are no longer forwarded
+    println!("\nTest 4: Truncated payloads should not decode as batch_append inputs");
+    if InstructionDataBatchAppendInputs::deserialize(&data[..159]).is_none() {
+        println!("✅ Test 4 passed: 159-byte payload rejected");
+    } else {
+        panic!("Test 4 failed: Truncated payload decoded");
+    }
+
+    println!("\n✅✅✅ All Batch Append Input tests passed! ✅✅✅");
+}
+
+/// Mirror of light_compressed_account::instruction_data::compressed_proof::CompressedProof
+#[derive(Debug, PartialEq)]
+struct CompressedProof {
+    a: [u8; 32],
+    b: [u8; 64],
+    c: [u8; 32],
+}
+
+/// Mirror of light_batched_merkle_tree::merkle_tree::InstructionDataBatchAppendInputs,
+/// which zk_donations imports rather than redefines
+#[derive(Debug, PartialEq)]
+struct InstructionDataBatchAppendInputs {
+    new_root: [u8; 32],
+    compressed_proof: CompressedProof,
+}
+
+impl InstructionDataBatchAppendInputs {
+    /// Borsh encoding: fixed-size arrays are written in field order with no prefix
+    fn serialize(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(160);
+        data.extend_from_slice(&self.new_root);
+        data.extend_from_slice(&self.compressed_proof.a);
+        data.extend_from_slice(&self.compressed_proof.b);
+        data.extend_from_slice(&self.compressed_proof.c);
+        data
+    }
+
+    fn deserialize(data: &[u8]) -> Option<Self> {
+        if data.len() != 160 {
+            return None;
+        }
+        Some(Self {
+            new_root: data[..32].try_into().ok()?,
+            compressed_proof: CompressedProof {
+                a: data[32..64].try_into().ok()?,
+                b: data[64..128].try_into().ok()?,
+                c: data[128..160].try_into().ok()?,
+            },
+        })
+    }
+}
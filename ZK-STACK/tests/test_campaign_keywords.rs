@@ -0,0 +1,90 @@
+fn main() {
+    println!("Testing Campaign Search Keywords");
+
+    // Test case: Keywords within bounds
+    println!("\nTest 1: Up to five keywords of up to 20 bytes should be accepted at init");
+    let keywords = strings(&["water", "education", "rural", "wells", "a".repeat(MAX_KEYWORD_LEN).as_str()]);
+    match init_campaign(keywords.clone()) {
+        Ok((campaign, event)) if campaign.keywords == keywords && event.keywords == keywords => {
+            println!("✅ Test 1 passed: Keywords stored and emitted in CampaignCreatedEvent");
+        }
+        other => panic!("Test 1 failed: Unexpected result {:?}", other),
+    }
+
+    // Test case: Too many keywords
+    println!("\nTest 2: More than five keywords should be rejected");
+    match init_campaign(strings(&["a", "b", "c", "d", "e", "f"])) {
+        Err(e) if e == "TooManyKeywords" => println!("✅ Test 2 passed: Sixth keyword rejected"),
+        other => panic!("Test 2 failed: Expected TooManyKeywords, got {:?}", other),
+    }
+
+    // Test case: Keyword too long
+    println!("\nTest 3: A keyword longer than 20 bytes should be rejected");
+    match init_campaign(vec!["a".repeat(MAX_KEYWORD_LEN + 1)]) {
+        Err(e) if e == "KeywordTooLong" => println!("✅ Test 3 passed: 21-byte keyword rejected"),
+        other => panic!("Test 3 failed: Expected KeywordTooLong, got {:?}", other),
+    }
+
+    // Test case: Updating keywords through update_campaign_metadata
+    println!("\nTest 4: update_campaign_metadata should replace keywords only when provided");
+    let (mut campaign, _) = init_campaign(strings(&["water"])).unwrap();
+    update_campaign_metadata(&mut campaign, None).unwrap();
+    if campaign.keywords != strings(&["water"]) {
+        panic!("Test 4 failed: Keywords changed when None was passed");
+    }
+    update_campaign_metadata(&mut campaign, Some(strings(&["health", "clinics"]))).unwrap();
+    if campaign.keywords != strings(&["health", "clinics"]) {
+        panic!("Test 4 failed: Keywords not replaced");
+    }
+    match update_campaign_metadata(&mut campaign, Some(vec!["b".repeat(25)])) {
+        Err(e) if e == "KeywordTooLong" && campaign.keywords == strings(&["health", "clinics"]) => {
+            println!("✅ Test 4 passed: Keywords replaced when provided, kept on None and on rejection");
+        }
+        other => panic!("Test 4 failed: Expected KeywordTooLong with keywords kept, got {:?}", other),
+    }
+
+    println!("\n✅✅✅ All Campaign Keywords tests passed! ✅✅✅");
+}
+
+const MAX_KEYWORDS: usize = 5;
+const MAX_KEYWORD_LEN: usize = 20;
+
+#[derive(Debug)]
+struct MockCampaign {
+    keywords: Vec<String>,
+}
+
+#[derive(Debug)]
+struct MockCampaignCreatedEvent {
+    keywords: Vec<String>,
+}
+
+fn strings(values: &[&str]) -> Vec<String> {
+    values.iter().map(|value| value.to_string()).collect()
+}
+
+/// Mirror of CampaignInfo::validate_keywords
+fn validate_keywords(keywords: &[String]) -> Result<(), String> {
+    if keywords.len() > MAX_KEYWORDS {
+        return Err("TooManyKeywords".into());
+    }
+    if !keywords.iter().all(|keyword| keyword.len() <= MAX_KEYWORD_LEN) {
+        return Err("KeywordTooLong".into());
+    }
+    Ok(())
+}
+
+fn init_campaign(keywords: Vec<String>) -> Result<(MockCampaign, MockCampaignCreatedEvent), String> {
+    validate_keywords(&keywords)?;
+    let campaign = MockCampaign { keywords };
+    let event = MockCampaignCreatedEvent { keywords: campaign.keywords.clone() };
+    Ok((campaign, event))
+}
+
+fn update_campaign_metadata(campaign: &mut MockCampaign, keywords: Option<Vec<String>>) -> Result<(), String> {
+    if let Some(keywords) = keywords {
+        validate_keywords(&keywords)?;
+        campaign.keywords = keywords;
+    }
+    Ok(())
+}
@@ -0,0 +1,202 @@
+fn main() {
+    println!("Testing Campaign Mint Change");
+
+    // Test case: Change before any donation
+    println!("\nTest 1: The creator should be able to change the mint before any donation");
+    let mut campaign = MockCampaign::new(USDC, 6);
+    let event = campaign.change_campaign_mint(CREATOR, PYUSD, 6).unwrap();
+    if campaign.mint == PYUSD && campaign.mint_decimals == 6 && campaign.token_account == (CREATOR, PYUSD)
+        && event.old_mint == USDC && event.new_mint == PYUSD
+    {
+        println!("✅ Test 1 passed: Mint and token account updated");
+    } else {
+        panic!("Test 1 failed: Campaign still on mint {}", campaign.mint);
+    }
+
+    // Test case: Old mint no longer accepted
+    println!("\nTest 2: Donations in the old mint should be rejected after the change");
+    match campaign.donate(USDC, 100) {
+        Err("MintMismatch") => println!("✅ Test 2 passed: Rejected with MintMismatch"),
+        other => panic!("Test 2 failed: Expected MintMismatch, got {:?}", other),
+    }
+
+    // Test case: Change after a donation
+    println!("\nTest 3: Changing the mint after a donation should be rejected");
+    campaign.donate(PYUSD, 100).unwrap();
+    match campaign.change_campaign_mint(CREATOR, USDC, 6) {
+        Err("MintChangeNotAllowed") if campaign.mint == PYUSD => {
+            println!("✅ Test 3 passed: Rejected with MintChangeNotAllowed")
+        }
+        other => panic!("Test 3 failed: Expected MintChangeNotAllowed, got {:?}", other),
+    }
+
+    // Test case: Refund pool in the old mint
+    println!("\nTest 4: A funded refund pool should also block the change");
+    let mut campaign = MockCampaign::new(USDC, 6);
+    campaign.refund_pool = 50;
+    match campaign.change_campaign_mint(CREATOR, PYUSD, 6) {
+        Err("MintChangeNotAllowed") => println!("✅ Test 4 passed: Rejected with MintChangeNotAllowed"),
+        other => panic!("Test 4 failed: Expected MintChangeNotAllowed, got {:?}", other),
+    }
+
+    // Test case: Non-creator
+    println!("\nTest 5: Only the creator should be able to change the mint");
+    let mut campaign = MockCampaign::new(USDC, 6);
+    match campaign.change_campaign_mint(STRANGER, PYUSD, 6) {
+        Err("Unauthorized") => println!("✅ Test 5 passed: Rejected with Unauthorized"),
+        other => panic!("Test 5 failed: Expected Unauthorized, got {:?}", other),
+    }
+
+    // Test case: Change after a counter reset
+    println!("\nTest 6: Changing the mint after donations were reset away should still be rejected");
+    let mut campaign = MockCampaign::new(USDC, 6);
+    campaign.donate(USDC, 100).unwrap();
+    campaign.reset_counters();
+    match campaign.change_campaign_mint(CREATOR, PYUSD, 6) {
+        Err("MintChangeNotAllowed") if campaign.total_donation_received == 0 => {
+            println!("✅ Test 6 passed: Rejected with MintChangeNotAllowed despite a zero total")
+        }
+        other => panic!("Test 6 failed: Expected MintChangeNotAllowed, got {:?}", other),
+    }
+
+    // Test case: Open pledges in the old mint
+    println!("\nTest 7: A stranger's open pledge should not block the change, and should be voided by it");
+    let mut campaign = MockCampaign::new(USDC, 6);
+    campaign.pledge(STRANGER, 75);
+    match campaign.change_campaign_mint(CREATOR, PYUSD, 6) {
+        Ok(event) if event.voided_pledged_total == 75 && campaign.pledged_total == 0 && campaign.mint == PYUSD => {
+            println!("✅ Test 7 passed: Mint changed, 75 of pledges voided")
+        }
+        other => panic!("Test 7 failed: Unexpected result {:?}", other),
+    }
+
+    // Test case: Voided pledge
+    println!("\nTest 8: A pledge voided by a mint change can be cancelled but not fulfilled");
+    campaign.pledge(CREATOR, 40);
+    let fulfilled = campaign.fulfill_pledge(STRANGER);
+    campaign.cancel_pledge(STRANGER);
+    if fulfilled == Err("PledgeMintChanged") && campaign.pledged_total == 40 && campaign.total_donation_received == 0 {
+        println!("✅ Test 8 passed: Rejected with PledgeMintChanged, cancel left new-mint pledges intact");
+    } else {
+        panic!("Test 8 failed: Got {:?}, pledged_total {}", fulfilled, campaign.pledged_total);
+    }
+
+    // Test case: Different decimals
+    println!("\nTest 9: A mint with different decimals should be rejected, keeping the base-unit settings' scale");
+    let mut campaign = MockCampaign::new(USDC, 6);
+    match campaign.change_campaign_mint(CREATOR, BONK, 5) {
+        Err("MintDecimalsMismatch") if campaign.mint == USDC && campaign.mint_decimals == 6 => {
+            println!("✅ Test 9 passed: Rejected with MintDecimalsMismatch")
+        }
+        other => panic!("Test 9 failed: Expected MintDecimalsMismatch, got {:?}", other),
+    }
+
+    println!("\n✅✅✅ All Change Mint tests passed! ✅✅✅");
+}
+
+const CREATOR: u8 = 1;
+const STRANGER: u8 = 2;
+const USDC: u8 = 10;
+const BONK: u8 = 11;
+const PYUSD: u8 = 12;
+
+/// Mock event struct representing CampaignMintChangedEvent
+#[derive(Debug)]
+struct CampaignMintChangedEvent {
+    old_mint: u8,
+    new_mint: u8,
+    voided_pledged_total: u64,
+}
+
+#[derive(Debug)]
+struct MockCampaign {
+    creator: u8,
+    mint: u8,
+    // (authority, mint) of the associated token account
+    token_account: (u8, u8),
+    mint_decimals: u8,
+    total_donation_received: u64,
+    ever_received: bool,
+    pledged_total: u64,
+    refund_pool: u64,
+    // Open pledges: (donor, amount, mint at pledge time)
+    pledges: Vec<(u8, u64, u8)>,
+}
+
+impl MockCampaign {
+    fn new(mint: u8, mint_decimals: u8) -> Self {
+        Self {
+            creator: CREATOR,
+            mint,
+            token_account: (CREATOR, mint),
+            mint_decimals,
+            total_donation_received: 0,
+            ever_received: false,
+            pledged_total: 0,
+            refund_pool: 0,
+            pledges: Vec::new(),
+        }
+    }
+
+    /// Mirror of ChangeCampaignMint::change_campaign_mint
+    fn change_campaign_mint(&mut self, signer: u8, new_mint: u8, decimals: u8) -> Result<CampaignMintChangedEvent, &'static str> {
+        if signer != self.creator {
+            return Err("Unauthorized");
+        }
+        if self.ever_received || self.refund_pool != 0 {
+            return Err("MintChangeNotAllowed");
+        }
+        if decimals != self.mint_decimals {
+            return Err("MintDecimalsMismatch");
+        }
+        let old_mint = self.mint;
+        let voided_pledged_total = self.pledged_total;
+        self.pledged_total = 0;
+        self.mint = new_mint;
+        self.token_account = (self.creator, new_mint);
+        Ok(CampaignMintChangedEvent { old_mint, new_mint, voided_pledged_total })
+    }
+
+    /// Mirror of CreatePledge::pledge, recording the current mint
+    fn pledge(&mut self, donor: u8, amount: u64) {
+        self.pledges.push((donor, amount, self.mint));
+        self.pledged_total += amount;
+    }
+
+    /// Mirror of FulfillPledge's pledge mint constraint and the donation it makes
+    fn fulfill_pledge(&mut self, donor: u8) -> Result<(), &'static str> {
+        let index = self.pledges.iter().position(|pledge| pledge.0 == donor).ok_or("AccountNotInitialized")?;
+        let (_, amount, mint) = self.pledges[index];
+        if mint != self.mint {
+            return Err("PledgeMintChanged");
+        }
+        self.donate(mint, amount)?;
+        self.pledges.remove(index);
+        self.pledged_total -= amount;
+        Ok(())
+    }
+
+    /// Mirror of CancelPledge::cancel_pledge, which only releases pledges in the current mint
+    fn cancel_pledge(&mut self, donor: u8) {
+        let index = self.pledges.iter().position(|pledge| pledge.0 == donor).unwrap();
+        let (_, amount, mint) = self.pledges.remove(index);
+        if mint == self.mint {
+            self.pledged_total -= amount;
+        }
+    }
+
+    /// Mirror of DonateAmount's has_one = mint constraint and apply_donation
+    fn donate(&mut self, mint: u8, amount: u64) -> Result<(), &'static str> {
+        if mint != self.mint {
+            return Err("MintMismatch");
+        }
+        self.total_donation_received += amount;
+        self.ever_received = true;
+        Ok(())
+    }
+
+    /// Mirror of ResetCounters::reset_counters, which leaves ever_received set
+    fn reset_counters(&mut self) {
+        self.total_donation_received = 0;
+    }
+}
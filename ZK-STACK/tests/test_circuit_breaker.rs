@@ -0,0 +1,147 @@
+fn main() {
+    println!("Testing Donation Circuit Breaker");
+
+    // Test case: Flood past the threshold
+    println!("\nTest 1: A flood of donations past the threshold should auto-pause the campaign");
+    let mut campaign = MockCampaign::new(5);
+    let mut tripped_events = Vec::new();
+    for i in 0..6 {
+        match campaign.apply_donation(100, 1_000 + i) {
+            Ok(Some(event)) => tripped_events.push(event),
+            Ok(None) => {}
+            Err(e) => panic!("Test 1 failed: Donation {} rejected with {}", i, e),
+        }
+    }
+    if campaign.paused && tripped_events.len() == 1 && tripped_events[0].window_count == 6 {
+        println!("✅ Test 1 passed: Sixth donation in the window emitted CircuitBreakerTrippedEvent");
+    } else {
+        panic!("Test 1 failed: paused {}, events {:?}", campaign.paused, tripped_events);
+    }
+
+    // Test case: Donations while paused
+    println!("\nTest 2: Donations to a tripped campaign should be rejected");
+    match campaign.apply_donation(100, 1_010) {
+        Err("CampaignPaused") if campaign.donation_count == 6 => {
+            println!("✅ Test 2 passed: Rejected with CampaignPaused")
+        }
+        other => panic!("Test 2 failed: Expected CampaignPaused, got {:?}", other),
+    }
+
+    // Test case: Manual unpause
+    println!("\nTest 3: Only the creator or admin should be able to unpause");
+    if campaign.set_campaign_paused(STRANGER, false, 1_020) != Err("Unauthorized") {
+        panic!("Test 3 failed: A stranger unpaused the campaign");
+    }
+    campaign.set_campaign_paused(ADMIN, false, 1_020).unwrap();
+    if !campaign.paused && campaign.apply_donation(100, 1_021) == Ok(None) {
+        println!("✅ Test 3 passed: Admin unpaused and donations resumed in a fresh window");
+    } else {
+        panic!("Test 3 failed: Campaign still paused");
+    }
+
+    // Test case: Steady donations
+    println!("\nTest 4: Donations spread across windows should never trip the breaker");
+    let mut campaign = MockCampaign::new(5);
+    for i in 0..50 {
+        campaign.apply_donation(100, 1_000 + i * 15).unwrap();
+    }
+    if !campaign.paused && campaign.donation_count == 50 {
+        println!("✅ Test 4 passed: 50 donations at 4 per window kept the campaign open");
+    } else {
+        panic!("Test 4 failed: Campaign paused after {} donations", campaign.donation_count);
+    }
+
+    // Test case: Disabled breaker
+    println!("\nTest 5: A threshold of 0 should disable the breaker");
+    let mut campaign = MockCampaign::new(0);
+    for _ in 0..1_000 {
+        campaign.apply_donation(1, 1_000).unwrap();
+    }
+    if !campaign.paused {
+        println!("✅ Test 5 passed: 1000 donations in one second were all accepted");
+    } else {
+        panic!("Test 5 failed: Disabled breaker tripped");
+    }
+
+    println!("\n✅✅✅ All Circuit Breaker tests passed! ✅✅✅");
+}
+
+const CIRCUIT_BREAKER_WINDOW_SECONDS: i64 = 60;
+const CREATOR: u8 = 1;
+const ADMIN: u8 = 2;
+const STRANGER: u8 = 3;
+
+/// Mock event struct representing CircuitBreakerTrippedEvent
+#[derive(Debug, PartialEq)]
+struct CircuitBreakerTrippedEvent {
+    threshold: u32,
+    window_count: u32,
+    window_start: i64,
+}
+
+struct MockCampaign {
+    paused: bool,
+    circuit_breaker_threshold: u32,
+    breaker_window_start: i64,
+    breaker_window_count: u32,
+    donation_count: u64,
+    total_donation_received: u64,
+}
+
+impl MockCampaign {
+    fn new(circuit_breaker_threshold: u32) -> Self {
+        Self {
+            paused: false,
+            circuit_breaker_threshold,
+            breaker_window_start: 0,
+            breaker_window_count: 0,
+            donation_count: 0,
+            total_donation_received: 0,
+        }
+    }
+
+    /// Mirror of CampaignInfo::record_donation_rate
+    fn record_donation_rate(&mut self, now: i64) -> bool {
+        if self.circuit_breaker_threshold == 0 {
+            return false;
+        }
+        if now.saturating_sub(self.breaker_window_start) >= CIRCUIT_BREAKER_WINDOW_SECONDS {
+            self.breaker_window_start = now;
+            self.breaker_window_count = 0;
+        }
+        self.breaker_window_count = self.breaker_window_count.saturating_add(1);
+        self.breaker_window_count > self.circuit_breaker_threshold
+    }
+
+    /// Mirror of the circuit breaker in apply_donation
+    fn apply_donation(&mut self, amount: u64, now: i64) -> Result<Option<CircuitBreakerTrippedEvent>, &'static str> {
+        if self.paused {
+            return Err("CampaignPaused");
+        }
+        self.total_donation_received += amount;
+        self.donation_count += 1;
+
+        if self.record_donation_rate(now) {
+            self.paused = true;
+            return Ok(Some(CircuitBreakerTrippedEvent {
+                threshold: self.circuit_breaker_threshold,
+                window_count: self.breaker_window_count,
+                window_start: self.breaker_window_start,
+            }));
+        }
+        Ok(None)
+    }
+
+    /// Mirror of SetCampaignPaused::set_campaign_paused
+    fn set_campaign_paused(&mut self, authority: u8, paused: bool, now: i64) -> Result<(), &'static str> {
+        if authority != CREATOR && authority != ADMIN {
+            return Err("Unauthorized");
+        }
+        self.paused = paused;
+        if !paused {
+            self.breaker_window_start = now;
+            self.breaker_window_count = 0;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,69 @@
+use std::collections::HashMap;
+
+fn main() {
+    println!("Testing First-Use Commitment Ownership");
+
+    let victim = [7u8; 32];
+    let attacker = [9u8; 32];
+    let commitment = [3u8; 32];
+
+    // Test case: First use claims the commitment
+    println!("\nTest 1: The first donor to use a commitment should become its owner");
+    let mut program = MockProgram::default();
+    match program.claim_commitment(victim, commitment, 1_000) {
+        Ok(()) if program.owners[&commitment].owner == victim => println!("✅ Test 1 passed: Commitment recorded for the victim"),
+        other => panic!("Test 1 failed: Unexpected result {:?}", other),
+    }
+
+    // Test case: Owner donates again
+    println!("\nTest 2: The owner should keep donating under their commitment");
+    match program.claim_commitment(victim, commitment, 2_000) {
+        Ok(()) if program.owners[&commitment].first_used_at == 1_000 => println!("✅ Test 2 passed: Record unchanged"),
+        other => panic!("Test 2 failed: Unexpected result {:?}", other),
+    }
+
+    // Test case: Attacker binds the victim's commitment
+    println!("\nTest 3: Another signer should not bind a commitment seen on-chain, even with their own valid binding");
+    match program.claim_commitment(attacker, commitment, 3_000) {
+        Err("CommitmentOwnershipUnverified") if program.owners[&commitment].owner == victim => {
+            println!("✅ Test 3 passed: Rejected with CommitmentOwnershipUnverified")
+        }
+        other => panic!("Test 3 failed: Expected CommitmentOwnershipUnverified, got {:?}", other),
+    }
+
+    // Test case: Separate commitments
+    println!("\nTest 4: Claims on one commitment should not affect another");
+    match program.claim_commitment(attacker, [4u8; 32], 3_000) {
+        Ok(()) if program.owners.len() == 2 => println!("✅ Test 4 passed: Attacker owns only their own commitment"),
+        other => panic!("Test 4 failed: Unexpected result {:?}", other),
+    }
+
+    println!("\n✅✅✅ All Commitment Ownership tests passed! ✅✅✅");
+}
+
+/// Mirror of CommitmentOwner
+#[derive(Debug)]
+struct MockCommitmentOwner {
+    owner: [u8; 32],
+    first_used_at: i64,
+}
+
+#[derive(Default)]
+struct MockProgram {
+    // `[b"commitment", commitment]` PDAs that exist
+    owners: HashMap<[u8; 32], MockCommitmentOwner>,
+}
+
+impl MockProgram {
+    /// Mirror of DonateCompressed::claim_commitment
+    fn claim_commitment(&mut self, donor: [u8; 32], commitment: [u8; 32], now: i64) -> Result<(), &'static str> {
+        if let Some(record) = self.owners.get(&commitment) {
+            if record.owner != donor {
+                return Err("CommitmentOwnershipUnverified");
+            }
+            return Ok(());
+        }
+        self.owners.insert(commitment, MockCommitmentOwner { owner: donor, first_used_at: now });
+        Ok(())
+    }
+}
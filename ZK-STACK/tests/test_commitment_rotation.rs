@@ -0,0 +1,206 @@
+use std::collections::HashMap;
+
+fn main() {
+    println!("Testing Donor Commitment Rotation");
+
+    let donor = [7u8; 32];
+    let attacker = [9u8; 32];
+    let old_commitment = [3u8; 32];
+    let new_commitment = [4u8; 32];
+
+    // A donor who has made two compressed donations under old_commitment
+    let mut stats = MockDonorStats::new(donor);
+    stats.record_compressed_donation(1_000, old_commitment).unwrap();
+    stats.record_compressed_donation(2_500, old_commitment).unwrap();
+
+    // Test case: Signed rotation
+    println!("\nTest 1: A rotation signed by the donor over donor || old || new should be accepted");
+    let ix = rotation_ix(donor, donor, old_commitment, new_commitment);
+    match stats.update_commitment(donor, new_commitment, &ix) {
+        Ok(()) if stats.donor_commitment == new_commitment => println!("✅ Test 1 passed: Commitment rotated"),
+        other => panic!("Test 1 failed: Unexpected result {:?}", other),
+    }
+
+    // Test case: Total continuity
+    println!("\nTest 2: The donor's lifetime total should carry over the rotation");
+    stats.record_compressed_donation(500, new_commitment).unwrap();
+    if stats.lifetime_donated == 4_000 {
+        println!("✅ Test 2 passed: Lifetime total 4000 across both commitments");
+    } else {
+        panic!("Test 2 failed: Lifetime total {}", stats.lifetime_donated);
+    }
+
+    // Test case: Signature by another key
+    println!("\nTest 3: A rotation signed by someone else should be rejected");
+    let forged = rotation_ix(attacker, donor, new_commitment, [5u8; 32]);
+    match stats.update_commitment(donor, [5u8; 32], &forged) {
+        Err("CommitmentRotationUnverified") if stats.donor_commitment == new_commitment => {
+            println!("✅ Test 3 passed: Rejected with CommitmentRotationUnverified")
+        }
+        other => panic!("Test 3 failed: Expected CommitmentRotationUnverified, got {:?}", other),
+    }
+
+    // Test case: Stale old commitment
+    println!("\nTest 4: A signature over a stale old commitment should be rejected");
+    let replayed = rotation_ix(donor, donor, old_commitment, [5u8; 32]);
+    match stats.update_commitment(donor, [5u8; 32], &replayed) {
+        Err("CommitmentRotationUnverified") => println!("✅ Test 4 passed: Only the stored commitment can be rotated away"),
+        other => panic!("Test 4 failed: Expected CommitmentRotationUnverified, got {:?}", other),
+    }
+
+    // Test case: No stored commitment
+    println!("\nTest 5: A donor without a compressed donation should have nothing to rotate");
+    let mut fresh = MockDonorStats::new(donor);
+    let ix = rotation_ix(donor, donor, [0u8; 32], new_commitment);
+    match fresh.update_commitment(donor, new_commitment, &ix) {
+        Err("CommitmentRotationUnverified") => println!("✅ Test 5 passed: Zero commitment cannot be rotated"),
+        other => panic!("Test 5 failed: Expected CommitmentRotationUnverified, got {:?}", other),
+    }
+
+    // Test case: Other donor's stats
+    println!("\nTest 6: Another signer should not rotate this donor's commitment");
+    match stats.update_commitment(attacker, [5u8; 32], &rotation_ix(attacker, attacker, new_commitment, [5u8; 32])) {
+        Err("Unauthorized") => println!("✅ Test 6 passed: Rejected with Unauthorized"),
+        other => panic!("Test 6 failed: Expected Unauthorized, got {:?}", other),
+    }
+
+    // Test case: Donation under another commitment
+    println!("\nTest 7: A compressed donation should not silently replace the stored commitment");
+    match stats.record_compressed_donation(100, old_commitment) {
+        Err("DonorCommitmentMismatch") if stats.donor_commitment == new_commitment && stats.lifetime_donated == 4_000 => {
+            println!("✅ Test 7 passed: Rejected with DonorCommitmentMismatch")
+        }
+        other => panic!("Test 7 failed: Expected DonorCommitmentMismatch, got {:?}", other),
+    }
+
+    // Test case: Rotated commitment claimed for the donor
+    println!("\nTest 8: The rotated-to commitment should be owned by the donor before anyone can use it");
+    if stats.owners.get(&new_commitment) == Some(&donor) && stats.claim_commitment(attacker, new_commitment) == Err("CommitmentOwnershipUnverified") {
+        println!("✅ Test 8 passed: Attacker's donate_compressed under the published commitment rejected");
+    } else {
+        panic!("Test 8 failed: New commitment not claimed for the donor");
+    }
+
+    // Test case: Rotating to someone else's commitment
+    println!("\nTest 9: Rotating to a commitment another donor owns should be rejected");
+    let taken_commitment = [8u8; 32];
+    stats.claim_commitment(attacker, taken_commitment).unwrap();
+    let ix = rotation_ix(donor, donor, new_commitment, taken_commitment);
+    match stats.update_commitment(donor, taken_commitment, &ix) {
+        Err("CommitmentOwnershipUnverified") if stats.donor_commitment == new_commitment => {
+            println!("✅ Test 9 passed: Rejected with CommitmentOwnershipUnverified")
+        }
+        other => panic!("Test 9 failed: Expected CommitmentOwnershipUnverified, got {:?}", other),
+    }
+
+    println!("\n✅✅✅ All Commitment Rotation tests passed! ✅✅✅");
+}
+
+const ED25519_PROGRAM_ID: [u8; 32] = [0xed; 32];
+
+struct MockInstruction {
+    program_id: [u8; 32],
+    data: Vec<u8>,
+}
+
+/// Mirror of the DonorGlobalStats fields update_commitment touches, plus the
+/// CommitmentOwner records by commitment
+struct MockDonorStats {
+    donor: [u8; 32],
+    lifetime_donated: u64,
+    donor_commitment: [u8; 32],
+    owners: HashMap<[u8; 32], [u8; 32]>,
+}
+
+impl MockDonorStats {
+    fn new(donor: [u8; 32]) -> Self {
+        Self { donor, lifetime_donated: 0, donor_commitment: [0u8; 32], owners: HashMap::new() }
+    }
+
+    /// Mirror of DonateCompressed::claim_commitment and
+    /// UpdateCommitment::claim_new_commitment
+    fn claim_commitment(&mut self, signer: [u8; 32], commitment: [u8; 32]) -> Result<(), &'static str> {
+        let owner = *self.owners.entry(commitment).or_insert(signer);
+        if owner != signer {
+            return Err("CommitmentOwnershipUnverified");
+        }
+        Ok(())
+    }
+
+    /// Mirror of the donor_stats updates in donate_compressed, including
+    /// DonorGlobalStats::record_commitment
+    fn record_compressed_donation(&mut self, amount: u64, donor_commitment: [u8; 32]) -> Result<(), &'static str> {
+        if self.donor_commitment == [0u8; 32] {
+            self.donor_commitment = donor_commitment;
+        }
+        if self.donor_commitment != donor_commitment {
+            return Err("DonorCommitmentMismatch");
+        }
+        self.lifetime_donated += amount;
+        Ok(())
+    }
+
+    /// Mirror of UpdateCommitment::update_commitment
+    fn update_commitment(&mut self, signer: [u8; 32], new_commitment: [u8; 32], previous_ix: &MockInstruction) -> Result<(), &'static str> {
+        if signer != self.donor {
+            return Err("Unauthorized");
+        }
+        let old_commitment = self.donor_commitment;
+        if old_commitment == [0u8; 32] || new_commitment == old_commitment {
+            return Err("CommitmentRotationUnverified");
+        }
+        let expected_message = rotation_message(&signer, &old_commitment, &new_commitment);
+        if !ed25519_signature_valid(previous_ix, &signer, &expected_message) {
+            return Err("CommitmentRotationUnverified");
+        }
+        self.claim_commitment(signer, new_commitment)?;
+        self.donor_commitment = new_commitment;
+        Ok(())
+    }
+}
+
+/// Mirror of UpdateCommitment::rotation_message
+fn rotation_message(donor: &[u8; 32], old_commitment: &[u8; 32], new_commitment: &[u8; 32]) -> [u8; 96] {
+    let mut message = [0u8; 96];
+    message[..32].copy_from_slice(donor);
+    message[32..64].copy_from_slice(old_commitment);
+    message[64..].copy_from_slice(new_commitment);
+    message
+}
+
+/// Mirror of client::commitment_rotation_ix; `claimed_donor` is the donor named in
+/// the message, `signer` the key that actually signed
+fn rotation_ix(signer: [u8; 32], claimed_donor: [u8; 32], old_commitment: [u8; 32], new_commitment: [u8; 32]) -> MockInstruction {
+    const PUBLIC_KEY_OFFSET: u16 = 16;
+    const SIGNATURE_OFFSET: u16 = PUBLIC_KEY_OFFSET + 32;
+    const MESSAGE_OFFSET: u16 = SIGNATURE_OFFSET + 64;
+    let message = rotation_message(&claimed_donor, &old_commitment, &new_commitment);
+
+    let mut data = vec![1u8, 0u8];
+    for field in [SIGNATURE_OFFSET, u16::MAX, PUBLIC_KEY_OFFSET, u16::MAX, MESSAGE_OFFSET, message.len() as u16, u16::MAX] {
+        data.extend_from_slice(&field.to_le_bytes());
+    }
+    data.extend_from_slice(&signer);
+    data.extend_from_slice(&[0u8; 64]);
+    data.extend_from_slice(&message);
+    MockInstruction { program_id: ED25519_PROGRAM_ID, data }
+}
+
+/// Mirror of DonateCompressed::ed25519_signature_valid
+fn ed25519_signature_valid(ix: &MockInstruction, signer: &[u8; 32], expected_message: &[u8]) -> bool {
+    let data = &ix.data;
+    if ix.program_id != ED25519_PROGRAM_ID || data.len() < 16 || data[0] != 1 {
+        return false;
+    }
+    let read_u16 = |at: usize| usize::from(u16::from_le_bytes([data[at], data[at + 1]]));
+
+    let self_referencing = [4, 8, 14].iter().all(|&at| read_u16(at) == usize::from(u16::MAX));
+    let public_key_offset = read_u16(6);
+    let message_offset = read_u16(10);
+    let message_size = read_u16(12);
+
+    self_referencing
+        && data.get(public_key_offset..public_key_offset + 32) == Some(&signer[..])
+        && message_size == expected_message.len()
+        && data.get(message_offset..message_offset + message_size) == Some(expected_message)
+}
@@ -0,0 +1,181 @@
+fn main() {
+    println!("Testing KYC and Token Gate Subject Across Entrypoints");
+
+    let campaign = MockCampaign { kyc_threshold: 1_000, gate_mint: Some(GATE_MINT) };
+
+    // Test case: donate_amount on behalf of a compliant beneficiary
+    println!("\nTest 1: donate_amount on behalf of an attested gate holder should still vet an unverified payer");
+    let accounts = MockComplianceAccounts::of(BENEFICIARY);
+    match donate_amount(&campaign, PAYER, Some(BENEFICIARY), &accounts, 5_000) {
+        Err(e) if e == "KycRequired" => println!("✅ Test 1 passed: Rejected with {}", e),
+        other => panic!("Test 1 failed: Expected KycRequired, got {:?}", other),
+    }
+    match donate_amount(&campaign, PAYER, Some(BENEFICIARY), &accounts, 500) {
+        Ok(subject) if subject == BENEFICIARY => println!("  Below the threshold, checks ran against the beneficiary"),
+        other => panic!("Test 1 failed: Unexpected result {:?}", other),
+    }
+
+    // Test case: donate_amount on behalf with the payer's credentials
+    println!("\nTest 2: The payer's attestation and gate tokens should not cover an unverified beneficiary");
+    let accounts = MockComplianceAccounts::of(PAYER);
+    match donate_amount(&campaign, PAYER, Some(BENEFICIARY), &accounts, 5_000) {
+        Err(e) if e == "KycRequired" => println!("✅ Test 2 passed: Rejected with {}", e),
+        other => panic!("Test 2 failed: Expected KycRequired, got {:?}", other),
+    }
+    match donate_amount(&campaign, PAYER, Some(BENEFICIARY), &accounts, 500) {
+        Err(e) if e == "TokenGateNotSatisfied" => println!("  Below the threshold, rejected with {}", e),
+        other => panic!("Test 2 failed: Expected TokenGateNotSatisfied, got {:?}", other),
+    }
+
+    // Test case: donate_cpi credits the token account owner
+    println!("\nTest 3: donate_cpi should run the same checks against the credited owner, not the delegate");
+    let owner_accounts = MockComplianceAccounts::of(BENEFICIARY);
+    let delegate_accounts = MockComplianceAccounts::of(DELEGATE);
+    let results = [
+        donate_cpi(&campaign, DELEGATE, BENEFICIARY, &owner_accounts, 5_000),
+        donate_cpi(&campaign, DELEGATE, BENEFICIARY, &delegate_accounts, 5_000),
+    ];
+    if results == [Ok(BENEFICIARY), Err("KycRequired".to_string())] {
+        println!("✅ Test 3 passed: Owner's credentials accepted, delegate's rejected");
+    } else {
+        panic!("Test 3 failed: Got {:?}", results);
+    }
+
+    // Test case: Both entrypoints agree
+    println!("\nTest 4: With an attested payer, crediting the same donor should give the same outcome on both entrypoints");
+    for accounts in [MockComplianceAccounts::of(BENEFICIARY), MockComplianceAccounts::of(PAYER)].iter_mut() {
+        accounts.payer_kyc_attestation = Some(MockAttestation { donor: PAYER, verifier: VERIFIER });
+        for amount in [500u64, 5_000].iter() {
+            let on_behalf = donate_amount(&campaign, PAYER, Some(BENEFICIARY), accounts, *amount);
+            let cpi = donate_cpi(&campaign, PAYER, BENEFICIARY, accounts, *amount);
+            if on_behalf != cpi {
+                panic!("Test 4 failed: donate_amount {:?}, donate_cpi {:?}", on_behalf, cpi);
+            }
+        }
+    }
+    println!("✅ Test 4 passed: Outcomes match");
+
+    // Test case: Self-donation is unchanged
+    println!("\nTest 5: A donor donating for themselves should still be checked as before");
+    let accounts = MockComplianceAccounts::of(PAYER);
+    match donate_amount(&campaign, PAYER, None, &accounts, 5_000) {
+        Ok(subject) if subject == PAYER => println!("✅ Test 5 passed: Signer checked as the credited donor"),
+        other => panic!("Test 5 failed: Unexpected result {:?}", other),
+    }
+
+    // Test case: Both payer and beneficiary attested
+    println!("\nTest 6: An attested payer donating on behalf of an attested beneficiary should pass");
+    let mut accounts = MockComplianceAccounts::of(BENEFICIARY);
+    accounts.payer_kyc_attestation = Some(MockAttestation { donor: PAYER, verifier: VERIFIER });
+    match donate_amount(&campaign, PAYER, Some(BENEFICIARY), &accounts, 5_000) {
+        Ok(subject) if subject == BENEFICIARY => println!("✅ Test 6 passed: Both attestations accepted"),
+        other => panic!("Test 6 failed: Unexpected result {:?}", other),
+    }
+
+    // Test case: Payer attestation for someone else
+    println!("\nTest 7: A payer attestation issued to another wallet should not vet the payer");
+    let mut accounts = MockComplianceAccounts::of(BENEFICIARY);
+    accounts.payer_kyc_attestation = Some(MockAttestation { donor: DELEGATE, verifier: VERIFIER });
+    match donate_amount(&campaign, PAYER, Some(BENEFICIARY), &accounts, 5_000) {
+        Err(e) if e == "KycRequired" => println!("✅ Test 7 passed: Rejected with {}", e),
+        other => panic!("Test 7 failed: Expected KycRequired, got {:?}", other),
+    }
+
+    println!("\n✅✅✅ All Compliance Subject tests passed! ✅✅✅");
+}
+
+const PAYER: [u8; 32] = [1u8; 32];
+const BENEFICIARY: [u8; 32] = [2u8; 32];
+const DELEGATE: [u8; 32] = [3u8; 32];
+const VERIFIER: [u8; 32] = [5u8; 32];
+const GATE_MINT: [u8; 32] = [9u8; 32];
+
+struct MockCampaign {
+    kyc_threshold: u64,
+    gate_mint: Option<[u8; 32]>,
+}
+
+struct MockAttestation {
+    donor: [u8; 32],
+    verifier: [u8; 32],
+}
+
+struct MockTokenAccount {
+    owner: [u8; 32],
+    mint: [u8; 32],
+    amount: u64,
+}
+
+/// The optional kyc_attestation, payer_kyc_attestation and gate_token_account the client passes
+struct MockComplianceAccounts {
+    kyc_attestation: Option<MockAttestation>,
+    payer_kyc_attestation: Option<MockAttestation>,
+    gate_token_account: Option<MockTokenAccount>,
+}
+
+impl MockComplianceAccounts {
+    /// An attestation and a funded gate account belonging to `holder`
+    fn of(holder: [u8; 32]) -> Self {
+        Self {
+            kyc_attestation: Some(MockAttestation { donor: holder, verifier: VERIFIER }),
+            payer_kyc_attestation: None,
+            gate_token_account: Some(MockTokenAccount { owner: holder, mint: GATE_MINT, amount: 1 }),
+        }
+    }
+}
+
+/// Mirror of TokenDonation::is_attested
+fn is_attested(attestation: &Option<MockAttestation>, donor: [u8; 32]) -> bool {
+    attestation.as_ref().is_some_and(|attestation| attestation.verifier == VERIFIER && attestation.donor == donor)
+}
+
+/// Mirror of the compliance checks in TokenDonation::process; returns the
+/// subject they ran against. `funder` owns the token account the donation is
+/// paid from.
+fn process(
+    campaign: &MockCampaign,
+    accounts: &MockComplianceAccounts,
+    funder: [u8; 32],
+    credited_donor: [u8; 32],
+    amount: u64,
+) -> Result<[u8; 32], String> {
+    if campaign.kyc_threshold != 0 && amount > campaign.kyc_threshold {
+        if !is_attested(&accounts.kyc_attestation, credited_donor) {
+            return Err("KycRequired".into());
+        }
+        if funder != credited_donor && !is_attested(&accounts.payer_kyc_attestation, funder) {
+            return Err("KycRequired".into());
+        }
+    }
+    if let Some(gate_mint) = campaign.gate_mint {
+        let satisfied = accounts.gate_token_account.as_ref().is_some_and(|account| {
+            account.owner == credited_donor && account.mint == gate_mint && account.amount > 0
+        });
+        if !satisfied {
+            return Err("TokenGateNotSatisfied".into());
+        }
+    }
+    Ok(credited_donor)
+}
+
+/// Mirror of DonateAmount::donate_amount: the signer funds, the beneficiary (if any) is credited
+fn donate_amount(
+    campaign: &MockCampaign,
+    signer: [u8; 32],
+    beneficiary_donor: Option<[u8; 32]>,
+    accounts: &MockComplianceAccounts,
+    amount: u64,
+) -> Result<[u8; 32], String> {
+    process(campaign, accounts, signer, beneficiary_donor.unwrap_or(signer), amount)
+}
+
+/// Mirror of DonateCpi::donate_cpi: the delegate signs, the token account owner funds and is credited
+fn donate_cpi(
+    campaign: &MockCampaign,
+    _delegate: [u8; 32],
+    token_account_owner: [u8; 32],
+    accounts: &MockComplianceAccounts,
+    amount: u64,
+) -> Result<[u8; 32], String> {
+    process(campaign, accounts, token_account_owner, token_account_owner, amount)
+}
@@ -0,0 +1,78 @@
+fn main() {
+    println!("Testing Token Locking for Compressed Donations");
+
+    // Test case: Claimed amount is locked in real tokens
+    println!("\nTest 1: A compressed donation should move the claimed amount to the campaign");
+    let mut ledger = MockLedger { donor_balance: 1_000, campaign_balance: 0, leaves: 0, total_donation_received: 0 };
+    match donate_compressed(&mut ledger, &proof_data(400)) {
+        Ok(()) if ledger.donor_balance == 600 && ledger.campaign_balance == 400 && ledger.leaves == 1 => {
+            println!("✅ Test 1 passed: 400 tokens moved before the leaf was appended");
+        }
+        other => panic!("Test 1 failed: Unexpected result {:?} with ledger {:?}", other, ledger),
+    }
+
+    // Test case: Proof claims more than the donor holds
+    println!("\nTest 2: A proof claiming more than the donor holds should be rejected");
+    let before = ledger.clone();
+    match donate_compressed(&mut ledger, &proof_data(601)) {
+        Err(e) if e == "InsufficientDonorBalance" && ledger == before => {
+            println!("✅ Test 2 passed: Oversized claim rejected with no tokens moved and no leaf appended");
+        }
+        other => panic!("Test 2 failed: Expected InsufficientDonorBalance, got {:?} with ledger {:?}", other, ledger),
+    }
+
+    // Test case: Claim equal to the full balance
+    println!("\nTest 3: A proof claiming exactly the donor's balance should succeed");
+    match donate_compressed(&mut ledger, &proof_data(600)) {
+        Ok(()) if ledger.donor_balance == 0 && ledger.campaign_balance == 1_000 && ledger.total_donation_received == 1_000 => {
+            println!("✅ Test 3 passed: Campaign total matches tokens actually received");
+        }
+        other => panic!("Test 3 failed: Unexpected result {:?} with ledger {:?}", other, ledger),
+    }
+
+    println!("\n✅✅✅ All Compressed Amount Lock tests passed! ✅✅✅");
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct MockLedger {
+    donor_balance: u64,
+    campaign_balance: u64,
+    leaves: u64,
+    total_donation_received: u64,
+}
+
+/// 89-byte mock proof: leaf version, amount, donor commitment, nullifier, campaign id, timestamp
+fn proof_data(amount: u64) -> Vec<u8> {
+    let mut data = vec![1u8];
+    data.extend_from_slice(&amount.to_le_bytes());
+    data.extend_from_slice(&[3u8; 32]);
+    data.extend_from_slice(&[4u8; 32]);
+    data.extend_from_slice(&7u64.to_le_bytes());
+    data.extend_from_slice(&1_700_000_000i64.to_le_bytes());
+    data
+}
+
+/// Mirror of DonateCompressed::donate_compressed: lock tokens, then append the leaf
+fn donate_compressed(ledger: &mut MockLedger, proof_data: &[u8]) -> Result<(), String> {
+    if proof_data.len() < 89 {
+        return Err("InvalidProofFormat".into());
+    }
+    let mut amount_bytes = [0u8; 8];
+    amount_bytes.copy_from_slice(&proof_data[1..9]);
+    let amount = u64::from_le_bytes(amount_bytes);
+    if amount == 0 {
+        return Err("ZeroDonation".into());
+    }
+
+    // lock_donated_tokens
+    if ledger.donor_balance < amount {
+        return Err("InsufficientDonorBalance".into());
+    }
+    ledger.donor_balance -= amount;
+    ledger.campaign_balance += amount;
+
+    // batch_append and apply_donation
+    ledger.leaves += 1;
+    ledger.total_donation_received = ledger.total_donation_received.checked_add(amount).ok_or("ArithmeticOverflow")?;
+    Ok(())
+}
@@ -0,0 +1,124 @@
+use std::collections::HashMap;
+
+fn main() {
+    println!("Testing Creator Campaign Links");
+
+    let mut accounts = MockAccounts::default();
+    let alice = [1u8; 32];
+    let bob = [2u8; 32];
+
+    // Test case: Links created for multiple campaigns
+    println!("\nTest 1: Each campaign should create a link derivable from (creator, index)");
+    for (campaign_id, campaign) in [(7u64, [70u8; 32]), (8, [80; 32]), (42, [42; 32])].iter() {
+        init_campaign(&mut accounts, alice, *campaign_id, *campaign).unwrap();
+    }
+    init_campaign(&mut accounts, bob, 7, [99; 32]).unwrap();
+
+    let link = accounts.links.get(&link_seeds(&alice, 2)).expect("Test 1 failed: link missing");
+    if link.campaign == [42; 32] && link.campaign_id == 42 && link.creator == alice {
+        println!("✅ Test 1 passed: Link read back with the correct campaign");
+    } else {
+        panic!("Test 1 failed: Link points at the wrong campaign");
+    }
+
+    // Test case: Links are scoped by creator
+    println!("\nTest 2: The same index for different creators should derive different links");
+    let alice_link = &accounts.links[&link_seeds(&alice, 0)];
+    let bob_link = &accounts.links[&link_seeds(&bob, 0)];
+    if alice_link.campaign == [70; 32] && bob_link.campaign == [99; 32] {
+        println!("✅ Test 2 passed: Links scoped per creator");
+    } else {
+        panic!("Test 2 failed: Links collided across creators");
+    }
+
+    // Test case: CreatorStats count
+    println!("\nTest 3: CreatorStats should count each creator's campaigns");
+    let alice_count = accounts.stats[&stats_seeds(&alice)].campaign_count;
+    let bob_count = accounts.stats[&stats_seeds(&bob)].campaign_count;
+    if alice_count == 3 && bob_count == 1 {
+        println!("✅ Test 3 passed: Campaign counts are 3 and 1");
+    } else {
+        panic!("Test 3 failed: Expected counts (3, 1), got ({}, {})", alice_count, bob_count);
+    }
+
+    // Test case: Enumerate by index
+    println!("\nTest 4: Indexes 0..campaign_count should find every campaign, whatever their ids");
+    let found: Vec<u64> = (0..alice_count)
+        .map(|index| accounts.links[&link_seeds(&alice, index)].campaign_id)
+        .collect();
+    if found == vec![7, 8, 42] {
+        println!("✅ Test 4 passed: Found campaigns {:?}", found);
+    } else {
+        panic!("Test 4 failed: Found {:?}", found);
+    }
+
+    // Test case: Stale index
+    println!("\nTest 5: A link derived from any index but the current campaign_count should fail");
+    match init_campaign_at(&mut accounts, alice, 1, 9, [90; 32]) {
+        Err(e) if e == "ConstraintSeeds" && accounts.stats[&stats_seeds(&alice)].campaign_count == 3 => {
+            println!("✅ Test 5 passed: Stale index rejected with {}", e)
+        }
+        other => panic!("Test 5 failed: Expected ConstraintSeeds, got {:?}", other),
+    }
+
+    println!("\n✅✅✅ All Creator Campaign Link tests passed! ✅✅✅");
+}
+
+struct CreatorStats {
+    campaign_count: u64,
+}
+
+struct CreatorCampaignLink {
+    creator: [u8; 32],
+    campaign_id: u64,
+    campaign: [u8; 32],
+}
+
+/// Mock account store keyed by PDA seeds
+#[derive(Default)]
+struct MockAccounts {
+    stats: HashMap<Vec<u8>, CreatorStats>,
+    links: HashMap<Vec<u8>, CreatorCampaignLink>,
+}
+
+fn stats_seeds(creator: &[u8; 32]) -> Vec<u8> {
+    [b"creator_stats".as_ref(), creator.as_ref()].concat()
+}
+
+fn link_seeds(creator: &[u8; 32], index: u64) -> Vec<u8> {
+    [b"creator_campaign".as_ref(), creator.as_ref(), index.to_le_bytes().as_ref()].concat()
+}
+
+/// Client side: pass the creator's current campaign_count as the link index
+fn init_campaign(accounts: &mut MockAccounts, creator: [u8; 32], campaign_id: u64, campaign: [u8; 32]) -> Result<(), String> {
+    let index = accounts.stats.get(&stats_seeds(&creator)).map_or(0, |stats| stats.campaign_count);
+    init_campaign_at(accounts, creator, index, campaign_id, campaign)
+}
+
+/// Mirror of the link and stats handling in init_campaign; `link_index` is the
+/// index the client derived the link PDA from
+fn init_campaign_at(
+    accounts: &mut MockAccounts,
+    creator: [u8; 32],
+    link_index: u64,
+    campaign_id: u64,
+    campaign: [u8; 32],
+) -> Result<(), String> {
+    // `init_if_needed` for the stats account
+    let count = accounts.stats.get(&stats_seeds(&creator)).map_or(0, |stats| stats.campaign_count);
+
+    // The seeds constraint derives the link from campaign_count; `init` fails if it already exists
+    if link_index != count {
+        return Err("ConstraintSeeds".into());
+    }
+    let link_key = link_seeds(&creator, count);
+    if accounts.links.contains_key(&link_key) {
+        return Err("AccountAlreadyInUse".into());
+    }
+
+    let stats = accounts.stats.entry(stats_seeds(&creator)).or_insert(CreatorStats { campaign_count: 0 });
+    stats.campaign_count = stats.campaign_count.checked_add(1).ok_or("ArithmeticOverflow")?;
+
+    accounts.links.insert(link_key, CreatorCampaignLink { creator, campaign_id, campaign });
+    Ok(())
+}
@@ -0,0 +1,181 @@
+fn main() {
+    println!("Testing donate_cpi From a Caller Program");
+
+    // Test case: Router donates within its approval
+    println!("\nTest 1: A caller program's PDA approved as delegate should donate on the user's behalf");
+    let mut program = MockProgram::new();
+    program.approve(ALICE, ROUTER_PDA, 1_000);
+    let event = program.router_swap_and_donate(ALICE, 400).unwrap();
+    if event.payer == ROUTER_PDA
+        && event.credited_donor == ALICE
+        && program.balances[&ALICE] == 10_000 - 400
+        && program.total_donation_received == 400
+    {
+        println!("✅ Test 1 passed: 400 moved from Alice's account, credited to Alice");
+    } else {
+        panic!("Test 1 failed: Unexpected event {:?}", event);
+    }
+
+    // Test case: Approval is consumed
+    println!("\nTest 2: The approval should shrink by each donation");
+    if program.accounts[&ALICE].delegated_amount == 600 {
+        println!("✅ Test 2 passed: 600 of the approval remains");
+    } else {
+        panic!("Test 2 failed: Remaining approval {}", program.accounts[&ALICE].delegated_amount);
+    }
+
+    // Test case: Donation above the approval
+    println!("\nTest 3: A donation above the remaining approval should be rejected");
+    match program.router_swap_and_donate(ALICE, 601) {
+        Err("DelegateApprovalInsufficient") if program.total_donation_received == 400 => {
+            println!("✅ Test 3 passed: Rejected with DelegateApprovalInsufficient")
+        }
+        other => panic!("Test 3 failed: Expected DelegateApprovalInsufficient, got {:?}", other),
+    }
+
+    // Test case: Unapproved program
+    println!("\nTest 4: A program the user never approved should be rejected");
+    match program.donate_cpi(OTHER_PDA, OTHER_PDA, ALICE, 100) {
+        Err("DelegateApprovalInsufficient") => println!("✅ Test 4 passed: Rejected with DelegateApprovalInsufficient"),
+        other => panic!("Test 4 failed: Expected DelegateApprovalInsufficient, got {:?}", other),
+    }
+
+    // Test case: Revoked approval
+    println!("\nTest 5: Donations after the user revokes the approval should be rejected");
+    program.revoke(ALICE);
+    match program.router_swap_and_donate(ALICE, 100) {
+        Err("DelegateApprovalInsufficient") => println!("✅ Test 5 passed: Rejected with DelegateApprovalInsufficient"),
+        other => panic!("Test 5 failed: Expected DelegateApprovalInsufficient, got {:?}", other),
+    }
+
+    // Test case: Token gate follows the credited donor
+    println!("\nTest 6: On a gated campaign, the user's gate tokens should count, not the router's");
+    let mut gated = MockProgram::new();
+    gated.gate_holders = vec![ALICE];
+    gated.approve(ALICE, ROUTER_PDA, 1_000);
+    match gated.router_swap_and_donate(ALICE, 100) {
+        Ok(event) if event.credited_donor == ALICE => println!("✅ Test 6 passed: Alice's gate balance admitted the donation"),
+        other => panic!("Test 6 failed: Unexpected result {:?}", other),
+    }
+
+    // Test case: Gate held only by the delegate
+    println!("\nTest 7: A router holding the gate token should not unlock the campaign for a user without it");
+    let mut gated = MockProgram::new();
+    gated.gate_holders = vec![ROUTER_PDA];
+    gated.approve(ALICE, ROUTER_PDA, 1_000);
+    match gated.router_swap_and_donate(ALICE, 100) {
+        Err("TokenGateNotSatisfied") => println!("✅ Test 7 passed: Rejected with TokenGateNotSatisfied"),
+        other => panic!("Test 7 failed: Expected TokenGateNotSatisfied, got {:?}", other),
+    }
+
+    // Test case: Rent for first-time accounts
+    println!("\nTest 8: The first donation should charge account rent to the payer, not the delegate PDA");
+    let mut program = MockProgram::new();
+    program.approve(ALICE, ROUTER_PDA, 1_000);
+    program.router_swap_and_donate(ALICE, 100).unwrap();
+    program.router_swap_and_donate(ALICE, 100).unwrap();
+    if program.rent_paid.get(&ALICE) == Some(&(2 * RENT)) && !program.rent_paid.contains_key(&ROUTER_PDA) {
+        println!("✅ Test 8 passed: Alice paid rent for 2 accounts once, the router PDA paid nothing");
+    } else {
+        panic!("Test 8 failed: Rent paid {:?}", program.rent_paid);
+    }
+
+    println!("\n✅✅✅ All donate_cpi tests passed! ✅✅✅");
+}
+
+const ALICE: u8 = 1;
+const ROUTER_PDA: u8 = 100;
+const OTHER_PDA: u8 = 101;
+const RENT: u64 = 1_000;
+
+/// Mock event struct representing DonationReceivedEvent
+#[derive(Debug)]
+struct DonationReceivedEvent {
+    payer: u8,
+    credited_donor: u8,
+}
+
+/// Mirror of the SPL token account fields donate_cpi reads
+struct MockTokenAccount {
+    owner: u8,
+    delegate: Option<u8>,
+    delegated_amount: u64,
+}
+
+struct MockProgram {
+    accounts: std::collections::HashMap<u8, MockTokenAccount>,
+    balances: std::collections::HashMap<u8, u64>,
+    total_donation_received: u64,
+    // Owners holding the gate mint; empty for ungated campaigns
+    gate_holders: Vec<u8>,
+    // Whether donor_stats and recent_donations exist yet
+    accounts_created: bool,
+    rent_paid: std::collections::HashMap<u8, u64>,
+}
+
+impl MockProgram {
+    fn new() -> Self {
+        let mut accounts = std::collections::HashMap::new();
+        accounts.insert(ALICE, MockTokenAccount { owner: ALICE, delegate: None, delegated_amount: 0 });
+        let mut balances = std::collections::HashMap::new();
+        balances.insert(ALICE, 10_000);
+        Self {
+            accounts,
+            balances,
+            total_donation_received: 0,
+            gate_holders: Vec::new(),
+            accounts_created: false,
+            rent_paid: std::collections::HashMap::new(),
+        }
+    }
+
+    /// SPL token `approve`, signed by the owner
+    fn approve(&mut self, owner: u8, delegate: u8, amount: u64) {
+        let account = self.accounts.get_mut(&owner).unwrap();
+        account.delegate = Some(delegate);
+        account.delegated_amount = amount;
+    }
+
+    /// SPL token `revoke`, signed by the owner
+    fn revoke(&mut self, owner: u8) {
+        let account = self.accounts.get_mut(&owner).unwrap();
+        account.delegate = None;
+        account.delegated_amount = 0;
+    }
+
+    /// Mock caller program: routes part of a swap to the campaign, signing as
+    /// its PDA, with the user as the transaction's fee payer covering any rent
+    fn router_swap_and_donate(&mut self, user: u8, donation: u64) -> Result<DonationReceivedEvent, &'static str> {
+        self.donate_cpi(ROUTER_PDA, user, user, donation)
+    }
+
+    /// Mirror of DonateCpi::donate_cpi, its init_if_needed accounts paid by
+    /// `payer`, its token gate check against the credited donor and the
+    /// delegated transfer_checked
+    fn donate_cpi(
+        &mut self,
+        delegate: u8,
+        payer: u8,
+        doner_token_account: u8,
+        amount: u64,
+    ) -> Result<DonationReceivedEvent, &'static str> {
+        let account = self.accounts.get_mut(&doner_token_account).unwrap();
+        if account.delegate != Some(delegate) || account.delegated_amount < amount {
+            return Err("DelegateApprovalInsufficient");
+        }
+        let credited_donor = account.owner;
+        if !self.gate_holders.is_empty() && !self.gate_holders.contains(&credited_donor) {
+            return Err("TokenGateNotSatisfied");
+        }
+        let account = self.accounts.get_mut(&doner_token_account).unwrap();
+        account.delegated_amount -= amount;
+        *self.balances.get_mut(&credited_donor).unwrap() -= amount;
+        self.total_donation_received += amount;
+        // A failed donation reverts the accounts' creation with it
+        if !self.accounts_created {
+            *self.rent_paid.entry(payer).or_insert(0) += 2 * RENT;
+            self.accounts_created = true;
+        }
+        Ok(DonationReceivedEvent { payer: delegate, credited_donor })
+    }
+}
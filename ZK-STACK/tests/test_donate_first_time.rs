@@ -0,0 +1,96 @@
+use std::collections::HashMap;
+
+fn main() {
+    println!("Testing Single-Transaction First Donations");
+
+    let campaign = 10u8;
+    let alice = 1u8;
+
+    // Test case: Brand-new donor
+    println!("\nTest 1: A donor with no doner account should be able to donate in one instruction");
+    let mut program = MockProgram::default();
+    match program.donate_first_time(alice, campaign, 500) {
+        Ok(()) => {
+            let info = program.doner_accounts.get(&(campaign, alice)).expect("doner account created");
+            if info.doner == alice && info.campaign == campaign && info.amount == 500 && program.campaign_total == 500 {
+                println!("✅ Test 1 passed: DonerInfo created with doner, campaign and amount set");
+            } else {
+                panic!("Test 1 failed: Unexpected doner account {:?}", info);
+            }
+        }
+        Err(e) => panic!("Test 1 failed: First donation rejected with {}", e),
+    }
+
+    // Test case: Existing donor cannot be re-initialized
+    println!("\nTest 2: donate_first_time should not reset an existing doner account");
+    match program.donate_first_time(alice, campaign, 1) {
+        Err(e) if e == "AccountAlreadyInUse" && program.doner_accounts[&(campaign, alice)].amount == 500 => {
+            println!("✅ Test 2 passed: Existing account rejected, amount kept at 500");
+        }
+        other => panic!("Test 2 failed: Expected AccountAlreadyInUse, got {:?}", other),
+    }
+
+    // Test case: Existing donor continues with donate_amount
+    println!("\nTest 3: The donor's later donations should go through donate_amount");
+    program.donate_amount(alice, campaign, 250).unwrap();
+    if program.doner_accounts[&(campaign, alice)].amount == 750 && program.campaign_total == 750 {
+        println!("✅ Test 3 passed: Cumulative amount 750");
+    } else {
+        panic!("Test 3 failed: Unexpected amount {}", program.doner_accounts[&(campaign, alice)].amount);
+    }
+
+    // Test case: Failed donation is atomic
+    println!("\nTest 4: A rejected first donation should not leave a doner account behind");
+    match program.donate_first_time(2, campaign, 0) {
+        Err(e) if e == "ZeroDonation" && !program.doner_accounts.contains_key(&(campaign, 2)) => {
+            println!("✅ Test 4 passed: No account created when the donation fails");
+        }
+        other => panic!("Test 4 failed: Expected ZeroDonation without an account, got {:?}", other),
+    }
+
+    println!("\n✅✅✅ All Donate First Time tests passed! ✅✅✅");
+}
+
+#[derive(Debug, Clone)]
+struct MockDonerInfo {
+    doner: u8,
+    campaign: u8,
+    amount: u64,
+}
+
+#[derive(Default)]
+struct MockProgram {
+    doner_accounts: HashMap<(u8, u8), MockDonerInfo>,
+    campaign_total: u64,
+}
+
+impl MockProgram {
+    /// Mirror of DonateFirstTime: `init` the doner account, then the shared TokenDonation flow
+    fn donate_first_time(&mut self, doner: u8, campaign: u8, amount: u64) -> Result<(), String> {
+        if self.doner_accounts.contains_key(&(campaign, doner)) {
+            return Err("AccountAlreadyInUse".into());
+        }
+        let mut info = MockDonerInfo { doner, campaign, amount: 0 };
+        self.process(&mut info, amount)?;
+        // The account only persists if the whole transaction succeeds
+        self.doner_accounts.insert((campaign, doner), info);
+        Ok(())
+    }
+
+    fn donate_amount(&mut self, doner: u8, campaign: u8, amount: u64) -> Result<(), String> {
+        let mut info = self.doner_accounts.get(&(campaign, doner)).cloned().ok_or("AccountNotInitialized")?;
+        self.process(&mut info, amount)?;
+        self.doner_accounts.insert((campaign, doner), info);
+        Ok(())
+    }
+
+    /// Mirror of TokenDonation::process
+    fn process(&mut self, info: &mut MockDonerInfo, amount: u64) -> Result<(), String> {
+        if amount == 0 {
+            return Err("ZeroDonation".into());
+        }
+        info.amount = info.amount.checked_add(amount).ok_or("ArithmeticOverflow")?;
+        self.campaign_total = self.campaign_total.checked_add(amount).ok_or("ArithmeticOverflow")?;
+        Ok(())
+    }
+}
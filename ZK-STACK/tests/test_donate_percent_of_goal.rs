@@ -0,0 +1,57 @@
+fn main() {
+    println!("Testing Donation as a Percentage of Goal");
+
+    // Test case: Common quick-button percentages
+    println!("\nTest 1: Percentages should convert to the expected token amounts");
+    let cases = [
+        (1_000_000, 100, 10_000),     // 1%
+        (1_000_000, 2_500, 250_000),  // 25%
+        (1_000_000, 10_000, 1_000_000), // 100%
+        (999, 3_333, 332),            // rounds down
+        (1_000_000, 0, 0),            // 0%
+    ];
+    for (goal, bps, expected) in cases.iter() {
+        match percent_of_goal(*goal, *bps) {
+            Ok(amount) if amount == *expected => {}
+            other => panic!("Test 1 failed: {} bps of {} expected {}, got {:?}", bps, goal, expected, other),
+        }
+    }
+    println!("✅ Test 1 passed: Percentages converted correctly");
+
+    // Test case: Large goals must not overflow the intermediate product
+    println!("\nTest 2: Maximum goal at 100% should not overflow");
+    match percent_of_goal(u64::MAX, 10_000) {
+        Ok(amount) if amount == u64::MAX => println!("✅ Test 2 passed: No overflow for u64::MAX goal"),
+        other => panic!("Test 2 failed: Expected u64::MAX, got {:?}", other),
+    }
+
+    // Test case: Goal not configured
+    println!("\nTest 3: Campaigns without a goal should be rejected");
+    match percent_of_goal(0, 100) {
+        Err(e) if e == "GoalNotConfigured" => println!("✅ Test 3 passed: Rejected with {}", e),
+        other => panic!("Test 3 failed: Expected GoalNotConfigured, got {:?}", other),
+    }
+
+    // Test case: Percentage above 100%
+    println!("\nTest 4: Percentages above 10000 bps should be rejected");
+    match percent_of_goal(1_000_000, 10_001) {
+        Err(e) if e == "InvalidPercentBps" => println!("✅ Test 4 passed: Rejected with {}", e),
+        other => panic!("Test 4 failed: Expected InvalidPercentBps, got {:?}", other),
+    }
+
+    println!("\n✅✅✅ All Donate Percent of Goal tests passed! ✅✅✅");
+}
+
+const BPS_DENOMINATOR: u64 = 10_000;
+
+/// Mirror of the amount computation in donate_percent_of_goal
+fn percent_of_goal(goal_amount: u64, percent_bps: u16) -> Result<u64, String> {
+    if goal_amount == 0 {
+        return Err("GoalNotConfigured".into());
+    }
+    if u64::from(percent_bps) > BPS_DENOMINATOR {
+        return Err("InvalidPercentBps".into());
+    }
+    u64::try_from(u128::from(goal_amount) * u128::from(percent_bps) / u128::from(BPS_DENOMINATOR))
+        .map_err(|_| "ArithmeticOverflow".to_string())
+}
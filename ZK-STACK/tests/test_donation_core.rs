@@ -0,0 +1,105 @@
+fn main() {
+    println!("Testing Shared Donation Core (apply_donation)");
+
+    // Test case: Basic state update
+    println!("\nTest 1: A donation should update totals, count and timestamp");
+    let mut campaign = new_campaign(1_000);
+    apply_donation(&mut campaign, 100, 1_700_000_000).unwrap();
+    if campaign.total_donation_received == 100 && campaign.donation_count == 1 && campaign.last_update_time == 1_700_000_000 {
+        println!("✅ Test 1 passed: Totals, count and timestamp updated");
+    } else {
+        panic!("Test 1 failed: Campaign state not updated correctly");
+    }
+
+    // Test case: Goal crossing
+    println!("\nTest 2: GoalReachedEvent should fire exactly when the goal is crossed");
+    let below = apply_donation(&mut campaign, 899, 1_700_000_100).unwrap();
+    let crossing = apply_donation(&mut campaign, 1, 1_700_000_200).unwrap();
+    let after = apply_donation(&mut campaign, 500, 1_700_000_300).unwrap();
+    match (below, crossing, after) {
+        (None, Some(event), None) if event.total_donation_received == 1_000 && event.goal_amount == 1_000 => {
+            println!("✅ Test 2 passed: Goal event emitted once, at the crossing donation");
+        }
+        _ => panic!("Test 2 failed: Goal event emitted at the wrong time"),
+    }
+
+    // Test case: Overshooting the goal in one donation
+    println!("\nTest 3: A single donation overshooting the goal should still fire the event");
+    let mut campaign = new_campaign(1_000);
+    match apply_donation(&mut campaign, 5_000, 1_700_000_000).unwrap() {
+        Some(event) if event.total_donation_received == 5_000 => println!("✅ Test 3 passed: Event fired on overshoot"),
+        _ => panic!("Test 3 failed: No goal event on overshoot"),
+    }
+
+    // Test case: No goal configured
+    println!("\nTest 4: Campaigns without a goal should never emit a goal event");
+    let mut campaign = new_campaign(0);
+    if apply_donation(&mut campaign, 1, 1_700_000_000).unwrap().is_none() {
+        println!("✅ Test 4 passed: No goal event without a goal");
+    } else {
+        panic!("Test 4 failed: Goal event emitted without a goal");
+    }
+
+    // Test case: Total overflow
+    println!("\nTest 5: Overflowing the total should fail");
+    let mut campaign = new_campaign(0);
+    campaign.total_donation_received = u64::MAX;
+    match apply_donation(&mut campaign, 1, 1_700_000_000) {
+        Err(e) if e == "ArithmeticOverflow" => println!("✅ Test 5 passed: Total overflow rejected"),
+        _ => panic!("Test 5 failed: Total overflow not rejected"),
+    }
+
+    // Test case: Count overflow
+    println!("\nTest 6: Overflowing the donation count should fail");
+    let mut campaign = new_campaign(0);
+    campaign.donation_count = u64::MAX;
+    match apply_donation(&mut campaign, 1, 1_700_000_000) {
+        Err(e) if e == "ArithmeticOverflow" => println!("✅ Test 6 passed: Count overflow rejected"),
+        _ => panic!("Test 6 failed: Count overflow not rejected"),
+    }
+
+    println!("\n✅✅✅ All Donation Core tests passed! ✅✅✅");
+}
+
+/// Mock campaign with the fields touched by apply_donation
+struct MockCampaign {
+    goal_amount: u64,
+    total_donation_received: u64,
+    donation_count: u64,
+    last_update_time: i64,
+}
+
+/// Mock event struct representing GoalReachedEvent
+struct GoalReachedEvent {
+    goal_amount: u64,
+    total_donation_received: u64,
+}
+
+fn new_campaign(goal_amount: u64) -> MockCampaign {
+    MockCampaign {
+        goal_amount,
+        total_donation_received: 0,
+        donation_count: 0,
+        last_update_time: 0,
+    }
+}
+
+/// Mirror of apply_donation; `now` stands in for Clock::get()
+fn apply_donation(campaign: &mut MockCampaign, amount: u64, now: i64) -> Result<Option<GoalReachedEvent>, String> {
+    let previous_total = campaign.total_donation_received;
+
+    campaign.total_donation_received = previous_total.checked_add(amount)
+        .ok_or("ArithmeticOverflow")?;
+    campaign.donation_count = campaign.donation_count.checked_add(1)
+        .ok_or("ArithmeticOverflow")?;
+    campaign.last_update_time = now;
+
+    let goal_amount = campaign.goal_amount;
+    if goal_amount > 0 && previous_total < goal_amount && campaign.total_donation_received >= goal_amount {
+        return Ok(Some(GoalReachedEvent {
+            goal_amount,
+            total_donation_received: campaign.total_donation_received,
+        }));
+    }
+    Ok(None)
+}
@@ -0,0 +1,104 @@
+use std::collections::HashMap;
+
+fn main() {
+    println!("Testing Donor Lifetime Stats");
+
+    // Test case: Donations to two campaigns
+    println!("\nTest 1: Lifetime total should sum donations across two campaigns");
+    let mut program = MockProgram::default();
+    let donor = 1u8;
+    program.donate_amount(donor, 10, 400).unwrap();
+    program.donate_amount(donor, 20, 250).unwrap();
+    let stats = program.get_donor_lifetime(donor).expect("stats created lazily");
+    if stats.lifetime_donated == 650 && stats.campaigns_supported == 2 {
+        println!("✅ Test 1 passed: 400 + 250 = 650 across 2 campaigns");
+    } else {
+        panic!("Test 1 failed: Unexpected stats {:?}", stats);
+    }
+
+    // Test case: Repeat donation to the same campaign
+    println!("\nTest 2: A repeat donation should add to the total without counting the campaign again");
+    program.donate_amount(donor, 10, 50).unwrap();
+    let stats = program.get_donor_lifetime(donor).unwrap();
+    if stats.lifetime_donated == 700 && stats.campaigns_supported == 2 {
+        println!("✅ Test 2 passed: Total 700, still 2 campaigns");
+    } else {
+        panic!("Test 2 failed: Unexpected stats {:?}", stats);
+    }
+
+    // Test case: Compressed donations
+    println!("\nTest 3: Compressed donations should add to the lifetime total only");
+    program.donate_compressed(donor, 300).unwrap();
+    let stats = program.get_donor_lifetime(donor).unwrap();
+    if stats.lifetime_donated == 1_000 && stats.campaigns_supported == 2 {
+        println!("✅ Test 3 passed: Compressed amount included in the lifetime total");
+    } else {
+        panic!("Test 3 failed: Unexpected stats {:?}", stats);
+    }
+
+    // Test case: Overflow
+    println!("\nTest 4: Lifetime overflow should be rejected");
+    match program.donate_amount(donor, 30, u64::MAX) {
+        Err(e) if e == "ArithmeticOverflow" => println!("✅ Test 4 passed: Overflow rejected"),
+        other => panic!("Test 4 failed: Expected ArithmeticOverflow, got {:?}", other),
+    }
+
+    // Test case: Donor that never donated
+    println!("\nTest 5: A donor with no donations should have no stats account");
+    if program.get_donor_lifetime(2).is_none() {
+        println!("✅ Test 5 passed: Stats are only created by a donation");
+    } else {
+        panic!("Test 5 failed: Stats exist for a donor who never donated");
+    }
+
+    println!("\n✅✅✅ All Donor Lifetime tests passed! ✅✅✅");
+}
+
+#[derive(Debug, Clone, Default)]
+struct MockDonorGlobalStats {
+    lifetime_donated: u64,
+    campaigns_supported: u64,
+}
+
+impl MockDonorGlobalStats {
+    /// Mirror of DonorGlobalStats::record_donation
+    fn record_donation(&mut self, amount: u64, first_to_campaign: bool) -> Result<(), String> {
+        self.lifetime_donated = self.lifetime_donated.checked_add(amount).ok_or("ArithmeticOverflow")?;
+        if first_to_campaign {
+            self.campaigns_supported = self.campaigns_supported.checked_add(1).ok_or("ArithmeticOverflow")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+struct MockProgram {
+    // DonerInfo.amount keyed by (donor, campaign)
+    doner_amounts: HashMap<(u8, u8), u64>,
+    // DonorGlobalStats PDAs, created with init_if_needed
+    donor_stats: HashMap<u8, MockDonorGlobalStats>,
+}
+
+impl MockProgram {
+    fn donate_amount(&mut self, donor: u8, campaign: u8, amount: u64) -> Result<(), String> {
+        let previous = *self.doner_amounts.get(&(donor, campaign)).unwrap_or(&0);
+        let new_amount = previous.checked_add(amount).ok_or("ArithmeticOverflow")?;
+        // Work on a copy so a failed donation leaves no trace, as a failed transaction would
+        let mut stats = self.donor_stats.get(&donor).cloned().unwrap_or_default();
+        stats.record_donation(amount, previous == 0)?;
+        self.doner_amounts.insert((donor, campaign), new_amount);
+        self.donor_stats.insert(donor, stats);
+        Ok(())
+    }
+
+    fn donate_compressed(&mut self, donor: u8, amount: u64) -> Result<(), String> {
+        let mut stats = self.donor_stats.get(&donor).cloned().unwrap_or_default();
+        stats.record_donation(amount, false)?;
+        self.donor_stats.insert(donor, stats);
+        Ok(())
+    }
+
+    fn get_donor_lifetime(&self, donor: u8) -> Option<MockDonorGlobalStats> {
+        self.donor_stats.get(&donor).cloned()
+    }
+}
@@ -0,0 +1,213 @@
+fn main() {
+    println!("Testing get_full_state Consolidated Getter");
+
+    // Realistic sequence: three donors, a repeat donation, a pledge, a snapshot,
+    // a refund pool top-up and a season boundary
+    let mut campaign = MockCampaign::new();
+    campaign.donate(ALICE, 2_000_000, 100);
+    campaign.donate(BOB, 1_500_000, 110);
+    campaign.donate(ALICE, 500_000, 120);
+    campaign.donate(CAROL, 1_000_000, 130);
+    campaign.pledge(750_000);
+    campaign.snapshot_root([8; 32]);
+    campaign.fund_refund_pool(250_000);
+    campaign.start_new_season(140);
+
+    // Test case: Totals and counts
+    println!("\nTest 1: Totals and counters should reflect the whole sequence");
+    let event = get_full_state(&campaign);
+    if event.total_donation_received == 5_000_000
+        && event.pledged_total == 750_000
+        && event.refund_pool == 250_000
+        && event.donation_count == 4
+        && event.unique_donor_count == 3
+        && event.snapshot_count == 1
+    {
+        println!("✅ Test 1 passed: 5000000 raised over 4 donations from 3 donors");
+    } else {
+        panic!("Test 1 failed: Unexpected counters {:?}", event);
+    }
+
+    // Test case: Goal, schedule and progress
+    println!("\nTest 2: Goal, schedule and progress fields should be populated");
+    if event.goal_amount == 10_000_000
+        && event.soft_goal == 4_000_000
+        && event.hard_cap == 12_000_000
+        && event.progress_bps == 5_000
+        && event.start_time == 50
+        && event.deadline == 10_000
+        && event.decimals == 6
+    {
+        println!("✅ Test 2 passed: 50% of goal, schedule 50..10000");
+    } else {
+        panic!("Test 2 failed: Unexpected goal fields {:?}", event);
+    }
+
+    // Test case: Status, root and season
+    println!("\nTest 3: Status flags, Merkle root and season should be populated");
+    if !event.paused && !event.is_failed && event.merkle_root == [8; 32] && event.season == 1 && event.last_update_time == 140 {
+        println!("✅ Test 3 passed: Active, root [8; 32], season 1");
+    } else {
+        panic!("Test 3 failed: Unexpected status fields {:?}", event);
+    }
+
+    // Test case: Read-only
+    println!("\nTest 4: get_full_state should not modify the campaign");
+    let before = campaign.clone();
+    get_full_state(&campaign);
+    get_full_state(&campaign);
+    if campaign == before {
+        println!("✅ Test 4 passed: Campaign unchanged after two calls");
+    } else {
+        panic!("Test 4 failed: Campaign state changed");
+    }
+
+    // Test case: Failed campaign status
+    println!("\nTest 5: A latched failure should be reported");
+    let mut failed = MockCampaign::new();
+    failed.is_failed = true;
+    failed.paused = true;
+    let event = get_full_state(&failed);
+    if event.is_failed && event.paused && event.progress_bps == 0 {
+        println!("✅ Test 5 passed: is_failed and paused reported");
+    } else {
+        panic!("Test 5 failed: Unexpected status {:?}", event);
+    }
+
+    println!("\n✅✅✅ All Full State tests passed! ✅✅✅");
+}
+
+const ALICE: u8 = 1;
+const BOB: u8 = 2;
+const CAROL: u8 = 3;
+
+/// Mock event struct representing FullStateEvent
+#[derive(Debug)]
+struct FullStateEvent {
+    total_donation_received: u64,
+    pledged_total: u64,
+    refund_pool: u64,
+    donation_count: u64,
+    unique_donor_count: u64,
+    snapshot_count: u64,
+    goal_amount: u64,
+    soft_goal: u64,
+    hard_cap: u64,
+    progress_bps: u64,
+    start_time: i64,
+    deadline: i64,
+    paused: bool,
+    is_failed: bool,
+    merkle_root: [u8; 32],
+    season: u16,
+    last_update_time: i64,
+    decimals: u8,
+}
+
+/// Mirror of the CampaignInfo fields FullStateEvent reports
+#[derive(Clone, Debug, PartialEq)]
+struct MockCampaign {
+    total_donation_received: u64,
+    pledged_total: u64,
+    refund_pool: u64,
+    donation_count: u64,
+    unique_donor_count: u64,
+    snapshot_count: u64,
+    goal_amount: u64,
+    soft_goal: u64,
+    hard_cap: u64,
+    start_time: i64,
+    deadline: i64,
+    paused: bool,
+    is_failed: bool,
+    latest_merkle_root: [u8; 32],
+    season: u16,
+    last_update_time: i64,
+    mint_decimals: u8,
+    donors: Vec<u8>,
+}
+
+impl MockCampaign {
+    fn new() -> Self {
+        Self {
+            total_donation_received: 0,
+            pledged_total: 0,
+            refund_pool: 0,
+            donation_count: 0,
+            unique_donor_count: 0,
+            snapshot_count: 0,
+            goal_amount: 10_000_000,
+            soft_goal: 4_000_000,
+            hard_cap: 12_000_000,
+            start_time: 50,
+            deadline: 10_000,
+            paused: false,
+            is_failed: false,
+            latest_merkle_root: [0; 32],
+            season: 0,
+            last_update_time: 0,
+            mint_decimals: 6,
+            donors: Vec::new(),
+        }
+    }
+
+    fn donate(&mut self, donor: u8, amount: u64, now: i64) {
+        if !self.donors.contains(&donor) {
+            self.donors.push(donor);
+            self.unique_donor_count += 1;
+        }
+        self.total_donation_received += amount;
+        self.donation_count += 1;
+        self.last_update_time = now;
+    }
+
+    fn pledge(&mut self, amount: u64) {
+        self.pledged_total += amount;
+    }
+
+    fn snapshot_root(&mut self, root: [u8; 32]) {
+        self.latest_merkle_root = root;
+        self.snapshot_count += 1;
+    }
+
+    fn fund_refund_pool(&mut self, amount: u64) {
+        self.refund_pool += amount;
+    }
+
+    fn start_new_season(&mut self, now: i64) {
+        self.season += 1;
+        self.last_update_time = now;
+    }
+
+    /// Mirror of CampaignInfo::progress_bps
+    fn progress_bps(&self) -> u64 {
+        if self.goal_amount == 0 {
+            return 0;
+        }
+        (self.total_donation_received as u128 * 10_000 / self.goal_amount as u128).min(10_000) as u64
+    }
+}
+
+/// Mirror of GetFullState::get_full_state
+fn get_full_state(campaign: &MockCampaign) -> FullStateEvent {
+    FullStateEvent {
+        total_donation_received: campaign.total_donation_received,
+        pledged_total: campaign.pledged_total,
+        refund_pool: campaign.refund_pool,
+        donation_count: campaign.donation_count,
+        unique_donor_count: campaign.unique_donor_count,
+        snapshot_count: campaign.snapshot_count,
+        goal_amount: campaign.goal_amount,
+        soft_goal: campaign.soft_goal,
+        hard_cap: campaign.hard_cap,
+        progress_bps: campaign.progress_bps(),
+        start_time: campaign.start_time,
+        deadline: campaign.deadline,
+        paused: campaign.paused,
+        is_failed: campaign.is_failed,
+        merkle_root: campaign.latest_merkle_root,
+        season: campaign.season,
+        last_update_time: campaign.last_update_time,
+        decimals: campaign.mint_decimals,
+    }
+}
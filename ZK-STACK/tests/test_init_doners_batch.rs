@@ -0,0 +1,201 @@
+use std::collections::HashMap;
+
+fn main() {
+    println!("Testing Batch Doner Initialization");
+
+    // Test case: Five donors, one pre-existing
+    println!("\nTest 1: A batch of five donors should create four accounts and skip the existing one");
+    let mut program = MockProgram::new(0);
+    program.init_doner(3).unwrap();
+    program.doners.get_mut(&3).unwrap().amount = 700;
+    let payer_before = program.payer_lamports;
+    let event = program.init_doners_batch(&[1, 2, 3, 4, 5], &[pda(1), pda(2), pda(3), pda(4), pda(5)]).unwrap();
+    if event.requested == 5 && event.created == 4 && program.doners.len() == 5 && program.unique_donor_count == 0 {
+        println!("✅ Test 1 passed: 4 created, 1 skipped");
+    } else {
+        panic!("Test 1 failed: Unexpected event {:?}", event);
+    }
+
+    // Test case: Existing account untouched
+    println!("\nTest 2: The pre-existing doner account should keep its amount");
+    if program.doners[&3].amount == 700 {
+        println!("✅ Test 2 passed: Amount still 700");
+    } else {
+        panic!("Test 2 failed: Existing doner account was reset");
+    }
+
+    // Test case: Payer pays rent for the created accounts only
+    println!("\nTest 3: The creator should pay rent for every created account");
+    if payer_before - program.payer_lamports == 4 * DONER_RENT {
+        println!("✅ Test 3 passed: Paid rent for 4 accounts");
+    } else {
+        panic!("Test 3 failed: Paid {}", payer_before - program.payer_lamports);
+    }
+
+    // Test case: Re-running the batch
+    println!("\nTest 4: Re-running the same batch should create nothing");
+    let event = program.init_doners_batch(&[1, 2, 3, 4, 5], &[pda(1), pda(2), pda(3), pda(4), pda(5)]).unwrap();
+    if event.created == 0 {
+        println!("✅ Test 4 passed: All five skipped");
+    } else {
+        panic!("Test 4 failed: Created {} accounts", event.created);
+    }
+
+    // Test case: Mismatched accounts
+    println!("\nTest 5: A remaining account that is not the donor's PDA should fail the batch");
+    let mut program = MockProgram::new(0);
+    match program.init_doners_batch(&[1, 2], &[pda(1), pda(9)]) {
+        Err("DonorAccountMismatch") => println!("✅ Test 5 passed: Rejected with DonorAccountMismatch"),
+        other => panic!("Test 5 failed: Expected DonorAccountMismatch, got {:?}", other),
+    }
+
+    // Test case: Batch bounds
+    println!("\nTest 6: Empty, oversized and short batches should be rejected");
+    let donors: Vec<u8> = (1..=11).collect();
+    let pdas: Vec<u16> = donors.iter().map(|donor| pda(*donor)).collect();
+    let results = [
+        program.init_doners_batch(&[], &[]).err(),
+        program.init_doners_batch(&donors, &pdas).err(),
+        program.init_doners_batch(&[1, 2], &[pda(1)]).err(),
+    ];
+    if results.iter().all(|result| *result == Some("InvalidDonerBatch")) {
+        println!("✅ Test 6 passed: Rejected with InvalidDonerBatch");
+    } else {
+        panic!("Test 6 failed: Got {:?}", results);
+    }
+
+    // Test case: Donor cap
+    println!("\nTest 7: Pre-registered donors should not fill max_donors until they donate");
+    let mut program = MockProgram::new(2);
+    program.init_doners_batch(&[1, 2, 3], &[pda(1), pda(2), pda(3)]).unwrap();
+    let results = [program.donate(3, 50), program.donate(3, 50), program.donate(1, 50), program.donate(2, 50)];
+    if results == [Ok(()), Ok(()), Ok(()), Err("MaxDonorsReached")] && program.unique_donor_count == 2 {
+        println!("✅ Test 7 passed: Slots taken by the first two donors to donate, third rejected");
+    } else {
+        panic!("Test 7 failed: Count {}, results {:?}", program.unique_donor_count, results);
+    }
+
+    // Test case: Non-creator
+    println!("\nTest 8: Only the campaign creator should be able to pre-register donors");
+    let mut program = MockProgram::new(2);
+    match program.init_doners_batch_as(ATTACKER, &[1, 2], &[pda(1), pda(2)]) {
+        Err("Unauthorized") if program.doners.is_empty() => println!("✅ Test 8 passed: Rejected with Unauthorized, no accounts created"),
+        other => panic!("Test 8 failed: Expected Unauthorized, got {:?}", other),
+    }
+
+    // Test case: Pre-funded PDA
+    println!("\nTest 9: A doner PDA that already received lamports should still be created");
+    let mut program = MockProgram::new(0);
+    program.address_lamports.insert(pda(1), 1_000);
+    let payer_before = program.payer_lamports;
+    match program.init_doners_batch(&[1], &[pda(1)]) {
+        Ok(event) if event.created == 1
+            && payer_before - program.payer_lamports == DONER_RENT - 1_000
+            && program.address_lamports[&pda(1)] == DONER_RENT => {
+            println!("✅ Test 9 passed: Topped up by {} instead of failing", DONER_RENT - 1_000);
+        }
+        other => panic!("Test 9 failed: Unexpected result {:?}", other),
+    }
+
+    println!("\n✅✅✅ All Batch Doner Initialization tests passed! ✅✅✅");
+}
+
+const MAX_DONERS_BATCH: usize = 10;
+const DONER_RENT: u64 = 1_454_640;
+const CREATOR: u8 = 200;
+const ATTACKER: u8 = 201;
+
+/// Stand-in for the `[b"doner", campaign, donor]` PDA derivation
+fn pda(donor: u8) -> u16 {
+    1_000 + u16::from(donor)
+}
+
+/// Mock event struct representing DonersBatchInitializedEvent
+#[derive(Debug)]
+struct DonersBatchInitializedEvent {
+    requested: u32,
+    created: u32,
+}
+
+#[derive(Debug)]
+struct MockDonerInfo {
+    amount: u64,
+}
+
+struct MockProgram {
+    doners: HashMap<u8, MockDonerInfo>,
+    unique_donor_count: u64,
+    max_donors: u64,
+    payer_lamports: u64,
+    // Lamports held at each address, including ones sent before the account exists
+    address_lamports: HashMap<u16, u64>,
+}
+
+impl MockProgram {
+    fn new(max_donors: u64) -> Self {
+        Self { doners: HashMap::new(), unique_donor_count: 0, max_donors, payer_lamports: 1_000_000_000, address_lamports: HashMap::new() }
+    }
+
+    /// Mirror of CampaignInfo::admit_donor
+    fn admit_donor(&mut self) -> Result<(), &'static str> {
+        if self.max_donors != 0 && self.unique_donor_count >= self.max_donors {
+            return Err("MaxDonorsReached");
+        }
+        self.unique_donor_count += 1;
+        Ok(())
+    }
+
+    /// Mirror of InitDoner::init_doner
+    fn init_doner(&mut self, donor: u8) -> Result<(), &'static str> {
+        self.doners.insert(donor, MockDonerInfo { amount: 0 });
+        Ok(())
+    }
+
+    /// Mirror of TokenDonation::process, which admits the donor on their first donation
+    fn donate(&mut self, donor: u8, amount: u64) -> Result<(), &'static str> {
+        let first_donation = self.doners.get(&donor).ok_or("AccountNotInitialized")?.amount == 0;
+        if first_donation {
+            self.admit_donor()?;
+        }
+        self.doners.get_mut(&donor).unwrap().amount += amount;
+        Ok(())
+    }
+
+    fn init_doners_batch(&mut self, donors: &[u8], doner_accounts: &[u16]) -> Result<DonersBatchInitializedEvent, &'static str> {
+        self.init_doners_batch_as(CREATOR, donors, doner_accounts)
+    }
+
+    /// Mirror of InitDonersBatch::init_doners_batch, including the has_one creator constraint
+    fn init_doners_batch_as(&mut self, signer: u8, donors: &[u8], doner_accounts: &[u16]) -> Result<DonersBatchInitializedEvent, &'static str> {
+        if signer != CREATOR {
+            return Err("Unauthorized");
+        }
+        if donors.is_empty() || donors.len() > MAX_DONERS_BATCH || doner_accounts.len() != donors.len() {
+            return Err("InvalidDonerBatch");
+        }
+
+        let mut created = 0;
+        for (donor, doner_account) in donors.iter().zip(doner_accounts) {
+            if *doner_account != pda(*donor) {
+                return Err("DonorAccountMismatch");
+            }
+            if self.doners.contains_key(donor) {
+                continue;
+            }
+            self.create_pda_account(*doner_account)?;
+            self.doners.insert(*donor, MockDonerInfo { amount: 0 });
+            created += 1;
+        }
+        Ok(DonersBatchInitializedEvent { requested: donors.len() as u32, created })
+    }
+
+    /// Mirror of util::create_pda_account: create_account for an empty address,
+    /// otherwise top up to rent exemption, then allocate and assign
+    fn create_pda_account(&mut self, account: u16) -> Result<(), &'static str> {
+        let existing = self.address_lamports.get(&account).copied().unwrap_or(0);
+        let top_up = DONER_RENT.saturating_sub(existing);
+        self.payer_lamports = self.payer_lamports.checked_sub(top_up).ok_or("InsufficientFunds")?;
+        self.address_lamports.insert(account, existing + top_up);
+        Ok(())
+    }
+}
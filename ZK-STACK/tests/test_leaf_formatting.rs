@@ -9,10 +9,10 @@ fn main() {
     let leaf_data = format_donation_leaf(&donation_data, campaign_id);
     
     // Verify leaf data has the correct structure
-    if leaf_data.len() == 56 { // 8 + 32 + 8 + 8 = 56 bytes
-        println!("✅ Test 1 passed: Leaf data has correct length");
+    if leaf_data.len() == 57 && leaf_data[0] == LEAF_VERSION { // 1 + 8 + 32 + 8 + 8 = 57 bytes
+        println!("✅ Test 1 passed: Leaf data has correct length and version prefix");
     } else {
-        panic!("Test 1 failed: Leaf data has incorrect length. Expected 56, got {}", leaf_data.len());
+        panic!("Test 1 failed: Leaf data has incorrect length or version. Expected 57, got {}", leaf_data.len());
     }
     
     // Test case: Verify leaf data contents
@@ -56,6 +56,8 @@ fn main() {
     println!("\n✅✅✅ All Compressed State Update tests passed! ✅✅✅");
 }
 
+const LEAF_VERSION: u8 = 1;
+
 /// Test struct to represent donation data
 #[derive(Debug)]
 struct DonationData {
@@ -77,7 +79,8 @@ fn create_test_donation_data(amount: u64, commitment: [u8; 32], timestamp: i64)
 fn format_donation_leaf(donation: &DonationData, campaign_id: u64) -> Vec<u8> {
     let mut leaf_data = Vec::new();
     
-    // Serialize in the same order as in our implementation
+    // Serialize in the same order as in our implementation, prefixed by the schema version
+    leaf_data.push(LEAF_VERSION);
     leaf_data.extend_from_slice(&donation.amount.to_le_bytes());
     leaf_data.extend_from_slice(&donation.donor_commitment);
     leaf_data.extend_from_slice(&donation.timestamp.to_le_bytes());
@@ -89,18 +92,18 @@ fn format_donation_leaf(donation: &DonationData, campaign_id: u64) -> Vec<u8> {
 /// Extract components from a leaf
 fn extract_leaf_components(leaf_data: &[u8]) -> (u64, [u8; 32], i64, u64) {
     let mut amount_bytes = [0u8; 8];
-    amount_bytes.copy_from_slice(&leaf_data[0..8]);
+    amount_bytes.copy_from_slice(&leaf_data[1..9]);
     let amount = u64::from_le_bytes(amount_bytes);
     
     let mut commitment = [0u8; 32];
-    commitment.copy_from_slice(&leaf_data[8..40]);
+    commitment.copy_from_slice(&leaf_data[9..41]);
     
     let mut timestamp_bytes = [0u8; 8];
-    timestamp_bytes.copy_from_slice(&leaf_data[40..48]);
+    timestamp_bytes.copy_from_slice(&leaf_data[41..49]);
     let timestamp = i64::from_le_bytes(timestamp_bytes);
     
     let mut campaign_id_bytes = [0u8; 8];
-    campaign_id_bytes.copy_from_slice(&leaf_data[48..56]);
+    campaign_id_bytes.copy_from_slice(&leaf_data[49..57]);
     let campaign_id = u64::from_le_bytes(campaign_id_bytes);
     
     (amount, commitment, timestamp, campaign_id)
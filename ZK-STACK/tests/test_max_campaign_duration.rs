@@ -0,0 +1,85 @@
+fn main() {
+    println!("Testing Maximum Campaign Duration");
+
+    const NOW: i64 = 1_700_000_000;
+    const MAX_DURATION: u64 = 180 * 86_400;
+    let config = MockGlobalConfig { max_campaign_duration: MAX_DURATION };
+
+    // Test case: Exactly at the maximum
+    println!("\nTest 1: A deadline exactly max_campaign_duration away should be accepted");
+    match config.validate_deadline(NOW + MAX_DURATION as i64, NOW) {
+        Ok(()) => println!("✅ Test 1 passed: Deadline at now + 180 days accepted"),
+        Err(e) => panic!("Test 1 failed: Unexpected {}", e),
+    }
+
+    // Test case: One second past the maximum
+    println!("\nTest 2: A deadline one second past the maximum should be rejected");
+    match config.validate_deadline(NOW + MAX_DURATION as i64 + 1, NOW) {
+        Err("CampaignTooLong") => println!("✅ Test 2 passed: Rejected with CampaignTooLong"),
+        other => panic!("Test 2 failed: Expected CampaignTooLong, got {:?}", other),
+    }
+
+    // Test case: Distant future
+    println!("\nTest 3: A deadline billions of seconds out should be rejected");
+    match config.validate_deadline(i64::MAX, NOW) {
+        Err("CampaignTooLong") => println!("✅ Test 3 passed: i64::MAX deadline rejected"),
+        other => panic!("Test 3 failed: Expected CampaignTooLong, got {:?}", other),
+    }
+
+    // Test case: Cap disabled
+    println!("\nTest 4: A max_campaign_duration of 0 should not cap deadlines");
+    let uncapped = MockGlobalConfig { max_campaign_duration: 0 };
+    match uncapped.validate_deadline(i64::MAX, NOW) {
+        Ok(()) => println!("✅ Test 4 passed: Any deadline accepted"),
+        Err(e) => panic!("Test 4 failed: Unexpected {}", e),
+    }
+
+    // Test case: No deadline
+    println!("\nTest 5: A campaign without a deadline should be rejected while capped");
+    match config.validate_deadline(0, NOW) {
+        Err("DeadlineRequired") => println!("✅ Test 5 passed: deadline 0 rejected with DeadlineRequired"),
+        other => panic!("Test 5 failed: Expected DeadlineRequired, got {:?}", other),
+    }
+
+    // Test case: Past and present deadlines
+    println!("\nTest 6: Deadlines at or before now should be rejected while capped");
+    for deadline in [NOW - 1, NOW] {
+        match config.validate_deadline(deadline, NOW) {
+            Err("DeadlineRequired") => {}
+            other => panic!("Test 6 failed: Expected DeadlineRequired for {}, got {:?}", deadline, other),
+        }
+    }
+    println!("✅ Test 6 passed: Expired deadlines rejected");
+
+    // Test case: Extreme cap
+    println!("\nTest 7: A cap of u64::MAX should not overflow");
+    let huge = MockGlobalConfig { max_campaign_duration: u64::MAX };
+    match huge.validate_deadline(i64::MAX, NOW) {
+        Ok(()) => println!("✅ Test 7 passed: No overflow at the extremes"),
+        Err(e) => panic!("Test 7 failed: Unexpected {}", e),
+    }
+
+    println!("\n✅✅✅ All Maximum Campaign Duration tests passed! ✅✅✅");
+}
+
+/// Mirror of the GlobalConfig max_campaign_duration field
+struct MockGlobalConfig {
+    max_campaign_duration: u64,
+}
+
+impl MockGlobalConfig {
+    /// Mirror of GlobalConfig::validate_deadline
+    fn validate_deadline(&self, deadline: i64, now: i64) -> Result<(), &'static str> {
+        if self.max_campaign_duration == 0 {
+            return Ok(());
+        }
+        if now >= deadline {
+            return Err("DeadlineRequired");
+        }
+        let max_deadline = i128::from(now) + i128::from(self.max_campaign_duration);
+        if i128::from(deadline) > max_deadline {
+            return Err("CampaignTooLong");
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,85 @@
+fn main() {
+    println!("Testing Maximum Single Donation");
+
+    let campaign = MockCampaign { require_whole_tokens: false, mint_decimals: 6, max_single_donation: 1_000 };
+
+    // Test case: Boundary
+    println!("\nTest 1: A donation of exactly max_single_donation should be accepted");
+    if campaign.validate_donation_amount(1_000).is_ok() && campaign.validate_donation_amount(1).is_ok() {
+        println!("✅ Test 1 passed: 1 and 1000 accepted");
+    } else {
+        panic!("Test 1 failed: Boundary amount rejected");
+    }
+
+    // Test case: Just above the boundary
+    println!("\nTest 2: A donation one unit above the maximum should be rejected");
+    match campaign.validate_donation_amount(1_001) {
+        Err("DonationTooLarge") => println!("✅ Test 2 passed: Rejected with DonationTooLarge"),
+        other => panic!("Test 2 failed: Expected DonationTooLarge, got {:?}", other),
+    }
+
+    // Test case: Zero still rejected first
+    println!("\nTest 3: Zero donations should still be rejected as ZeroDonation");
+    match campaign.validate_donation_amount(0) {
+        Err("ZeroDonation") => println!("✅ Test 3 passed: Rejected with ZeroDonation"),
+        other => panic!("Test 3 failed: Expected ZeroDonation, got {:?}", other),
+    }
+
+    // Test case: No maximum
+    println!("\nTest 4: A max_single_donation of 0 should accept any amount");
+    let uncapped = MockCampaign { max_single_donation: 0, ..campaign };
+    if uncapped.validate_donation_amount(u64::MAX).is_ok() {
+        println!("✅ Test 4 passed: u64::MAX accepted");
+    } else {
+        panic!("Test 4 failed: Uncapped campaign rejected u64::MAX");
+    }
+
+    // Test case: Combined with whole tokens
+    println!("\nTest 5: Whole-token and maximum checks should both apply");
+    let whole = MockCampaign { require_whole_tokens: true, mint_decimals: 6, max_single_donation: 2_000_000 };
+    let results = [
+        whole.validate_donation_amount(1_500_000),
+        whole.validate_donation_amount(2_000_000),
+        whole.validate_donation_amount(3_000_000),
+    ];
+    if results == [Err("NonWholeTokenDonation"), Ok(()), Err("DonationTooLarge")] {
+        println!("✅ Test 5 passed: Each amount got the expected result");
+    } else {
+        panic!("Test 5 failed: Got {:?}", results);
+    }
+
+    println!("\n✅✅✅ All Max Single Donation tests passed! ✅✅✅");
+}
+
+#[derive(Clone, Copy)]
+struct MockCampaign {
+    require_whole_tokens: bool,
+    mint_decimals: u8,
+    max_single_donation: u64,
+}
+
+impl MockCampaign {
+    /// Mirror of CampaignInfo::accepts_amount
+    fn accepts_amount(&self, amount: u64) -> bool {
+        if !self.require_whole_tokens {
+            return true;
+        }
+        10u64
+            .checked_pow(u32::from(self.mint_decimals))
+            .map_or(amount == 0, |one_token| amount.is_multiple_of(one_token))
+    }
+
+    /// Mirror of CampaignInfo::validate_donation_amount
+    fn validate_donation_amount(&self, amount: u64) -> Result<(), &'static str> {
+        if amount == 0 {
+            return Err("ZeroDonation");
+        }
+        if !self.accepts_amount(amount) {
+            return Err("NonWholeTokenDonation");
+        }
+        if self.max_single_donation != 0 && amount > self.max_single_donation {
+            return Err("DonationTooLarge");
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,240 @@
+fn main() {
+    println!("Testing Output Queue Routing by Tree Type");
+
+    // Test case: Batched tree with its queue
+    println!("\nTest 1: A batched tree with its queue should append through the queue");
+    let batched_tree = MockTree::batched(QUEUE);
+    let batched = MockCampaign::init(&batched_tree, Some(QUEUE)).unwrap();
+    match batched.donate_compressed(&batched_tree, Some(QUEUE)) {
+        Ok(Append { target: QUEUE, .. }) => println!("✅ Test 1 passed: Leaf appended to the output queue"),
+        other => panic!("Test 1 failed: Unexpected routing {:?}", other),
+    }
+
+    // Test case: Batched tree without a queue
+    println!("\nTest 2: A batched tree without a queue should fail with OutputQueueRequired");
+    match batched.donate_compressed(&batched_tree, None) {
+        Err("OutputQueueRequired") => println!("✅ Test 2 passed: Rejected with OutputQueueRequired"),
+        other => panic!("Test 2 failed: Expected OutputQueueRequired, got {:?}", other),
+    }
+
+    // Test case: Batched tree with another queue
+    println!("\nTest 3: A batched tree given a queue it is not associated with should fail with QueueMismatch");
+    let init_results = [
+        MockCampaign::init(&batched_tree, Some(OTHER_QUEUE)).err(),
+        batched.donate_compressed(&batched_tree, Some(OTHER_QUEUE)).err(),
+    ];
+    if init_results.iter().all(|result| *result == Some("QueueMismatch")) {
+        println!("✅ Test 3 passed: Rejected with QueueMismatch at init and donation");
+    } else {
+        panic!("Test 3 failed: Got {:?}", init_results);
+    }
+
+    // Test case: Concurrent tree without a queue
+    println!("\nTest 4: A concurrent tree without a queue should append its leaves directly");
+    let concurrent_tree = MockTree::concurrent();
+    let concurrent = MockCampaign::init(&concurrent_tree, None).unwrap();
+    match concurrent.donate_compressed(&concurrent_tree, None) {
+        Ok(Append { target: TREE, .. }) => println!("✅ Test 4 passed: Leaf appended to the tree directly"),
+        other => panic!("Test 4 failed: Unexpected routing {:?}", other),
+    }
+
+    // Test case: Concurrent tree given a queue
+    println!("\nTest 5: A concurrent tree given a queue should fail with OutputQueueUnexpected, at init too");
+    let results = [
+        MockCampaign::init(&concurrent_tree, Some(QUEUE)).err(),
+        concurrent.donate_compressed(&concurrent_tree, Some(QUEUE)).err(),
+    ];
+    if results.iter().all(|result| *result == Some("OutputQueueUnexpected")) {
+        println!("✅ Test 5 passed: A queue does not make a concurrent tree batched");
+    } else {
+        panic!("Test 5 failed: Got {:?}", results);
+    }
+
+    // Test case: Not a state tree
+    println!("\nTest 6: An account not owned by account-compression, or with an unknown discriminator, should be rejected");
+    let foreign_owner = MockTree { owner: OTHER_PROGRAM, ..MockTree::batched(QUEUE) };
+    let unknown_discriminator = MockTree { discriminator: *b"queueacc", ..MockTree::batched(QUEUE) };
+    let results = [
+        MockCampaign::init(&foreign_owner, Some(QUEUE)).err(),
+        MockCampaign::init(&unknown_discriminator, Some(QUEUE)).err(),
+    ];
+    if results.iter().all(|result| *result == Some("InvalidMerkleTree")) {
+        println!("✅ Test 6 passed: Rejected with InvalidMerkleTree");
+    } else {
+        panic!("Test 6 failed: Got {:?}", results);
+    }
+
+    // Test case: Recorded type disagrees with the tree
+    println!("\nTest 7: A campaign recorded as batched should not append to a concurrent tree");
+    let stale = MockCampaign { output_queue: DEFAULT_KEY, is_batched: true };
+    match stale.donate_compressed(&concurrent_tree, None) {
+        Err("TreeTypeMismatch") => println!("✅ Test 7 passed: Rejected with TreeTypeMismatch"),
+        other => panic!("Test 7 failed: Expected TreeTypeMismatch, got {:?}", other),
+    }
+
+    // Test case: Upgraded legacy campaigns
+    println!("\nTest 8: Upgrading a pre-flag campaign should derive is_batched from its tree account");
+    let mut legacy_batched = MockCampaign { output_queue: QUEUE, is_batched: false };
+    let mut legacy_concurrent = MockCampaign { output_queue: QUEUE, is_batched: false };
+    legacy_batched.upgrade(&batched_tree).unwrap();
+    legacy_concurrent.upgrade(&concurrent_tree).unwrap();
+    if legacy_batched.is_batched && !legacy_concurrent.is_batched {
+        println!("✅ Test 8 passed: Flag read from the tree, not the recorded queue");
+    } else {
+        panic!("Test 8 failed: Upgrade set the wrong flag");
+    }
+
+    // Test case: Batched arm inserts the leaf itself
+    println!("\nTest 9: A batched donation should insert the 32-byte leaf hash into the queue, not batch_append inputs");
+    match batched.donate_compressed(&batched_tree, Some(QUEUE)) {
+        Ok(Append { target: QUEUE, leaves }) if leaves == vec![(0, LEAF_HASH)] => {
+            println!("✅ Test 9 passed: One leaf hash queued for the forester to settle")
+        }
+        other => panic!("Test 9 failed: Unexpected append {:?}", other),
+    }
+
+    // Test case: Leaf index read from the append target
+    println!("\nTest 10: The leaf index should be the append target's next_index, read at its layout offset");
+    let mut tree_data = vec![0u8; 300];
+    tree_data[CONCURRENT_TREE_NEXT_INDEX_OFFSET..CONCURRENT_TREE_NEXT_INDEX_OFFSET + 8].copy_from_slice(&17u64.to_le_bytes());
+    let mut queue_data = vec![0u8; 300];
+    queue_data[OUTPUT_QUEUE_NEXT_INDEX_OFFSET..OUTPUT_QUEUE_NEXT_INDEX_OFFSET + 8].copy_from_slice(&42u64.to_le_bytes());
+    if CONCURRENT_TREE_NEXT_INDEX_OFFSET == 240
+        && OUTPUT_QUEUE_NEXT_INDEX_OFFSET == 280
+        && next_leaf_index(TreeType::Concurrent, &tree_data) == Ok(17)
+        && next_leaf_index(TreeType::Batched, &queue_data) == Ok(42)
+        && next_leaf_index(TreeType::Batched, &queue_data[..284]) == Err("InvalidMerkleTree")
+    {
+        println!("✅ Test 10 passed: Concurrent tree at 240, output queue at 280, short accounts rejected");
+    } else {
+        panic!("Test 10 failed: Unexpected leaf index reads");
+    }
+
+    println!("\n✅✅✅ All Output Queue Routing tests passed! ✅✅✅");
+}
+
+const DEFAULT_KEY: u8 = 0;
+const TREE: u8 = 50;
+const LEAF_HASH: [u8; 32] = [7u8; 32];
+const QUEUE: u8 = 1;
+const OTHER_QUEUE: u8 = 2;
+const ACCOUNT_COMPRESSION: u8 = 100;
+const OTHER_PROGRAM: u8 = 101;
+const STATE_TREE_DISCRIMINATOR: [u8; 8] = [1, 2, 3, 4, 5, 6, 7, 8];
+const BATCHED_TREE_DISCRIMINATOR: [u8; 8] = *b"BatchMta";
+
+/// Mirror of create_campaign_tree_account's layout offsets
+const TREE_ASSOCIATED_QUEUE_OFFSET: usize = 8 + 3 * 32 + 7 * 8;
+const CONCURRENT_TREE_NEXT_INDEX_OFFSET: usize = TREE_ASSOCIATED_QUEUE_OFFSET + 2 * 32 + 2 * 8;
+const OUTPUT_QUEUE_NEXT_INDEX_OFFSET: usize = TREE_ASSOCIATED_QUEUE_OFFSET + 2 * 32 + 8 + 6 * 8;
+
+/// Mirror of create_campaign_tree_account::next_leaf_index
+fn next_leaf_index(tree_type: TreeType, append_target: &[u8]) -> Result<u64, &'static str> {
+    let offset = match tree_type {
+        TreeType::Concurrent => CONCURRENT_TREE_NEXT_INDEX_OFFSET,
+        TreeType::Batched => OUTPUT_QUEUE_NEXT_INDEX_OFFSET,
+    };
+    append_target
+        .get(offset..offset + 8)
+        .and_then(|bytes| bytes.try_into().ok())
+        .map(u64::from_le_bytes)
+        .ok_or("InvalidMerkleTree")
+}
+
+/// append_leaves_to_merkle_trees CPI: the remaining account the leaves go into,
+/// and the `(remaining account index, leaf)` pairs
+#[derive(Debug)]
+struct Append {
+    target: u8,
+    leaves: Vec<(u8, [u8; 32])>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum TreeType {
+    Concurrent,
+    Batched,
+}
+
+/// The tree account fields validate_tree_queue reads
+struct MockTree {
+    owner: u8,
+    discriminator: [u8; 8],
+    associated_queue: u8,
+}
+
+impl MockTree {
+    fn batched(associated_queue: u8) -> Self {
+        Self { owner: ACCOUNT_COMPRESSION, discriminator: BATCHED_TREE_DISCRIMINATOR, associated_queue }
+    }
+
+    fn concurrent() -> Self {
+        Self { owner: ACCOUNT_COMPRESSION, discriminator: STATE_TREE_DISCRIMINATOR, associated_queue: OTHER_QUEUE }
+    }
+
+    /// Mirror of TreeType::of
+    fn tree_type(&self) -> Result<TreeType, &'static str> {
+        if self.owner != ACCOUNT_COMPRESSION {
+            return Err("InvalidMerkleTree");
+        }
+        match self.discriminator {
+            STATE_TREE_DISCRIMINATOR => Ok(TreeType::Concurrent),
+            BATCHED_TREE_DISCRIMINATOR => Ok(TreeType::Batched),
+            _ => Err("InvalidMerkleTree"),
+        }
+    }
+}
+
+/// Mirror of create_campaign_tree_account::validate_tree_queue
+fn validate_tree_queue(tree: &MockTree, output_queue: Option<u8>) -> Result<TreeType, &'static str> {
+    let tree_type = tree.tree_type()?;
+    match (tree_type, output_queue) {
+        (TreeType::Batched, Some(queue)) if queue != tree.associated_queue => Err("QueueMismatch"),
+        (TreeType::Batched, None) => Err("OutputQueueRequired"),
+        (TreeType::Concurrent, Some(_)) => Err("OutputQueueUnexpected"),
+        _ => Ok(tree_type),
+    }
+}
+
+struct MockCampaign {
+    output_queue: u8,
+    is_batched: bool,
+}
+
+impl MockCampaign {
+    /// Mirror of the tree fields init_campaign records
+    fn init(tree: &MockTree, output_queue: Option<u8>) -> Result<Self, &'static str> {
+        let tree_type = validate_tree_queue(tree, output_queue)?;
+        Ok(Self { output_queue: output_queue.unwrap_or(DEFAULT_KEY), is_batched: tree_type == TreeType::Batched })
+    }
+
+    /// Mirror of UpgradeCampaignAccount's is_batched default
+    fn upgrade(&mut self, tree: &MockTree) -> Result<(), &'static str> {
+        self.is_batched = tree.tree_type()? == TreeType::Batched;
+        Ok(())
+    }
+
+    /// Mirror of CampaignInfo::validate_output_queue
+    fn validate_output_queue(&self, output_queue: Option<u8>) -> Result<(), &'static str> {
+        match (self.is_batched, output_queue) {
+            (true, Some(queue)) if queue != self.output_queue => Err("QueueMismatch"),
+            (true, Some(_)) => Ok(()),
+            (true, None) => Err("OutputQueueRequired"),
+            (false, Some(_)) => Err("OutputQueueUnexpected"),
+            (false, None) => Ok(()),
+        }
+    }
+
+    /// Mirror of DonateCompressed's tree and queue validation and append target
+    fn donate_compressed(&self, tree: &MockTree, output_queue: Option<u8>) -> Result<Append, &'static str> {
+        let tree_type = validate_tree_queue(tree, output_queue)?;
+        if (tree_type == TreeType::Batched) != self.is_batched {
+            return Err("TreeTypeMismatch");
+        }
+        self.validate_output_queue(output_queue)?;
+        match (tree_type, output_queue) {
+            (TreeType::Batched, Some(queue)) => Ok(Append { target: queue, leaves: vec![(0, LEAF_HASH)] }),
+            (TreeType::Batched, None) => Err("OutputQueueRequired"),
+            (TreeType::Concurrent, _) => Ok(Append { target: TREE, leaves: vec![(0, LEAF_HASH)] }),
+        }
+    }
+}
@@ -0,0 +1,77 @@
+fn main() {
+    println!("Testing Output Queue Validation for Compressed Donations");
+
+    let merkle_tree = [1u8; 32];
+    let campaign_queue = [2u8; 32];
+    let foreign_queue = [3u8; 32];
+
+    // Campaign initialized with a tree and its paired queue
+    let campaign = MockCampaign::init(merkle_tree, Some(campaign_queue));
+
+    // Test case: Matching queue
+    println!("\nTest 1: The queue recorded at init should be accepted");
+    match validate_accounts(&campaign, merkle_tree, Some(campaign_queue)) {
+        Ok(_) => println!("✅ Test 1 passed: Campaign queue accepted"),
+        Err(e) => panic!("Test 1 failed: Campaign queue rejected with error: {}", e),
+    }
+
+    // Test case: Foreign queue
+    println!("\nTest 2: A foreign queue should be rejected");
+    match validate_accounts(&campaign, merkle_tree, Some(foreign_queue)) {
+        Ok(_) => panic!("Test 2 failed: Foreign queue was incorrectly accepted"),
+        Err(e) if e == "QueueMismatch" => println!("✅ Test 2 passed: Foreign queue rejected with {}", e),
+        Err(e) => panic!("Test 2 failed: Unexpected error {}", e),
+    }
+
+    // Test case: Omitted queue
+    println!("\nTest 3: Omitting the queue of a batched tree should be rejected");
+    match validate_accounts(&campaign, merkle_tree, None) {
+        Ok(_) => panic!("Test 3 failed: Missing queue was incorrectly accepted"),
+        Err(e) if e == "OutputQueueRequired" => println!("✅ Test 3 passed: Missing queue rejected with {}", e),
+        Err(e) => panic!("Test 3 failed: Unexpected error {}", e),
+    }
+
+    // Test case: Campaign created without a queue
+    println!("\nTest 4: Any queue should be rejected for a campaign created without one");
+    let queueless_campaign = MockCampaign::init(merkle_tree, None);
+    match validate_accounts(&queueless_campaign, merkle_tree, Some(campaign_queue)) {
+        Ok(_) => panic!("Test 4 failed: Queue accepted for a queueless campaign"),
+        Err(e) if e == "OutputQueueUnexpected" => println!("✅ Test 4 passed: Queue rejected with {}", e),
+        Err(e) => panic!("Test 4 failed: Unexpected error {}", e),
+    }
+
+    println!("\n✅✅✅ All Output Queue Validation tests passed! ✅✅✅");
+}
+
+/// Mock campaign holding the tree and queue recorded at init
+struct MockCampaign {
+    merkle_tree: [u8; 32],
+    output_queue: [u8; 32],
+    is_batched: bool,
+}
+
+impl MockCampaign {
+    /// Mirror of init_campaign: a missing queue is stored as the default pubkey
+    /// and marks the tree as concurrent
+    fn init(merkle_tree: [u8; 32], queue: Option<[u8; 32]>) -> Self {
+        Self { merkle_tree, output_queue: queue.unwrap_or_default(), is_batched: queue.is_some() }
+    }
+}
+
+/// Mirror of the DonateCompressed tree constraint and CampaignInfo::validate_output_queue
+fn validate_accounts(
+    campaign: &MockCampaign,
+    merkle_tree: [u8; 32],
+    output_queue: Option<[u8; 32]>,
+) -> Result<(), String> {
+    if merkle_tree != campaign.merkle_tree {
+        return Err("ConstraintRaw".into());
+    }
+    match (campaign.is_batched, output_queue) {
+        (true, Some(queue)) if queue != campaign.output_queue => Err("QueueMismatch".into()),
+        (true, Some(_)) => Ok(()),
+        (true, None) => Err("OutputQueueRequired".into()),
+        (false, Some(_)) => Err("OutputQueueUnexpected".into()),
+        (false, None) => Ok(()),
+    }
+}
@@ -0,0 +1,121 @@
+fn main() {
+    println!("Testing Platform Referrer Fee");
+
+    // Test case: Fee configuration
+    println!("\nTest 1: Fees above 10000 bps, or without a referrer, should be rejected at init");
+    if !valid_platform_fee(Some(1), 10_001) && !valid_platform_fee(None, 250) && valid_platform_fee(Some(1), 10_000) && valid_platform_fee(None, 0) {
+        println!("✅ Test 1 passed: Rejected with InvalidPlatformFee");
+    } else {
+        panic!("Test 1 failed: Fee configuration validated incorrectly");
+    }
+
+    // Test case: Split sums to the donation
+    println!("\nTest 2: Campaign and platform shares should sum to the donation");
+    let mut program = MockProgram::new(Some(1), 250);
+    let event = program.donate_amount(10_000, true).unwrap();
+    if event.platform_fee == 250
+        && program.campaign_balance == 9_750
+        && program.platform_balance == 250
+        && program.campaign_balance + program.platform_balance == 10_000
+    {
+        println!("✅ Test 2 passed: 9750 to the campaign + 250 to the platform = 10000");
+    } else {
+        panic!("Test 2 failed: Unexpected split {:?}", event);
+    }
+
+    // Test case: Totals count the full donation
+    println!("\nTest 3: Campaign totals should count the full donation");
+    if program.total_donation_received == 10_000 && event.amount == 10_000 {
+        println!("✅ Test 3 passed: total_donation_received is 10000");
+    } else {
+        panic!("Test 3 failed: Total is {}", program.total_donation_received);
+    }
+
+    // Test case: Rounding
+    println!("\nTest 4: Fees should round down, leaving the remainder with the campaign");
+    let mut program = MockProgram::new(Some(1), 333);
+    let event = program.donate_amount(7, true).unwrap();
+    if event.platform_fee == 0 && program.campaign_balance == 7 {
+        println!("✅ Test 4 passed: 7 * 333 / 10000 rounds down to 0");
+    } else {
+        panic!("Test 4 failed: Unexpected split {:?}", event);
+    }
+    let event = program.donate_amount(u64::MAX / 2, true).unwrap();
+    if event.platform_fee == ((u64::MAX / 2) as u128 * 333 / 10_000) as u64 {
+        println!("  Large donations split without overflow");
+    } else {
+        panic!("Test 4 failed: Large donation fee {}", event.platform_fee);
+    }
+
+    // Test case: Missing referrer account
+    println!("\nTest 5: A fee-charging donation without the referrer's account should fail");
+    let mut program = MockProgram::new(Some(1), 250);
+    match program.donate_amount(10_000, false) {
+        Err("PlatformAccountMismatch") if program.campaign_balance == 0 => {
+            println!("✅ Test 5 passed: Rejected before any transfer")
+        }
+        other => panic!("Test 5 failed: Expected PlatformAccountMismatch, got {:?}", other),
+    }
+
+    // Test case: No referrer
+    println!("\nTest 6: Campaigns without a referrer should receive the whole donation");
+    let mut program = MockProgram::new(None, 0);
+    let event = program.donate_amount(10_000, false).unwrap();
+    if event.platform_fee == 0 && program.campaign_balance == 10_000 {
+        println!("✅ Test 6 passed: No fee charged");
+    } else {
+        panic!("Test 6 failed: Unexpected split {:?}", event);
+    }
+
+    println!("\n✅✅✅ All Platform Fee tests passed! ✅✅✅");
+}
+
+const BPS_DENOMINATOR: u64 = 10_000;
+
+/// Mirror of CampaignInfo::valid_platform_fee
+fn valid_platform_fee(platform_referrer: Option<u8>, platform_fee_bps: u16) -> bool {
+    u64::from(platform_fee_bps) <= BPS_DENOMINATOR && (platform_fee_bps == 0 || platform_referrer.is_some())
+}
+
+/// Mock event struct representing DonationReceivedEvent
+#[derive(Debug)]
+struct DonationReceivedEvent {
+    amount: u64,
+    platform_fee: u64,
+}
+
+struct MockProgram {
+    platform_referrer: Option<u8>,
+    platform_fee_bps: u16,
+    campaign_balance: u64,
+    platform_balance: u64,
+    total_donation_received: u64,
+}
+
+impl MockProgram {
+    fn new(platform_referrer: Option<u8>, platform_fee_bps: u16) -> Self {
+        Self { platform_referrer, platform_fee_bps, campaign_balance: 0, platform_balance: 0, total_donation_received: 0 }
+    }
+
+    /// Mirror of CampaignInfo::platform_fee
+    fn platform_fee(&self, amount: u64) -> u64 {
+        if self.platform_referrer.is_none() {
+            return 0;
+        }
+        (u128::from(amount) * u128::from(self.platform_fee_bps) / u128::from(BPS_DENOMINATOR)) as u64
+    }
+
+    /// Mirror of TokenDonation::process's split
+    fn donate_amount(&mut self, amount: u64, with_platform_account: bool) -> Result<DonationReceivedEvent, &'static str> {
+        let platform_fee = self.platform_fee(amount);
+        let campaign_share = amount - platform_fee;
+        if platform_fee > 0 && !with_platform_account {
+            return Err("PlatformAccountMismatch");
+        }
+
+        self.campaign_balance += campaign_share;
+        self.platform_balance += platform_fee;
+        self.total_donation_received = self.total_donation_received.saturating_add(amount);
+        Ok(DonationReceivedEvent { amount, platform_fee })
+    }
+}
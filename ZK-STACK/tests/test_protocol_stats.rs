@@ -0,0 +1,77 @@
+fn main() {
+    println!("Testing Protocol-Wide Donation Statistics");
+
+    let mut stats = MockProtocolStats {
+        total_campaigns: 0,
+        total_donations: 0,
+        total_volume: 0,
+    };
+
+    // Test case: Campaign creation
+    println!("\nTest 1: Creating campaigns should increment total_campaigns");
+    record_campaign(&mut stats).unwrap();
+    let event = record_campaign(&mut stats).unwrap();
+    if stats.total_campaigns == 2 && event.total_campaigns == 2 {
+        println!("✅ Test 1 passed: total_campaigns incremented and emitted");
+    } else {
+        panic!("Test 1 failed: Expected 2 campaigns, got {}", stats.total_campaigns);
+    }
+
+    // Test case: Regular and compressed donations share the same counters
+    println!("\nTest 2: Donations should update total_donations and total_volume");
+    record_donation(&mut stats, 150).unwrap(); // donate_amount
+    let event = record_donation(&mut stats, 50).unwrap(); // donate_compressed
+    if stats.total_donations == 2 && stats.total_volume == 200 && event.total_volume == 200 {
+        println!("✅ Test 2 passed: Donation statistics updated correctly");
+    } else {
+        panic!(
+            "Test 2 failed: Expected (2, 200), got ({}, {})",
+            stats.total_donations, stats.total_volume
+        );
+    }
+
+    // Test case: Overflow protection
+    // On-chain the failing instruction aborts the whole transaction, so no partial update persists
+    println!("\nTest 3: Volume overflow should be rejected");
+    stats.total_volume = u64::MAX - 10;
+    match record_donation(&mut stats, 11) {
+        Ok(_) => panic!("Test 3 failed: Overflowing donation was accepted"),
+        Err(e) => println!("✅ Test 3 passed: Overflow correctly rejected with error: {}", e),
+    }
+
+    println!("\n✅✅✅ All Protocol Statistics tests passed! ✅✅✅");
+}
+
+/// Mock of the ProtocolStats PDA
+struct MockProtocolStats {
+    total_campaigns: u64,
+    total_donations: u64,
+    total_volume: u64,
+}
+
+/// Mock event struct representing ProtocolStatsEvent
+struct ProtocolStatsEvent {
+    total_campaigns: u64,
+    total_volume: u64,
+}
+
+fn record_campaign(stats: &mut MockProtocolStats) -> Result<ProtocolStatsEvent, String> {
+    stats.total_campaigns = stats.total_campaigns.checked_add(1)
+        .ok_or("Arithmetic overflow")?;
+    Ok(emit_update(stats))
+}
+
+fn record_donation(stats: &mut MockProtocolStats, amount: u64) -> Result<ProtocolStatsEvent, String> {
+    stats.total_donations = stats.total_donations.checked_add(1)
+        .ok_or("Arithmetic overflow")?;
+    stats.total_volume = stats.total_volume.checked_add(amount)
+        .ok_or("Arithmetic overflow")?;
+    Ok(emit_update(stats))
+}
+
+fn emit_update(stats: &MockProtocolStats) -> ProtocolStatsEvent {
+    ProtocolStatsEvent {
+        total_campaigns: stats.total_campaigns,
+        total_volume: stats.total_volume,
+    }
+}
@@ -0,0 +1,139 @@
+fn main() {
+    println!("Testing Donation Public Inputs");
+
+    let inputs = DonationPublicInputs {
+        amount: 0x0102_0304_0506_0708,
+        donor_commitment: [0xaa; 32],
+        nullifier: [0xbb; 32],
+        campaign_id: 12345,
+    };
+
+    // Test case: Field-element ordering matches the circuit fixture
+    println!("\nTest 1: to_field_elements should match the circuit's public-input fixture");
+    let elements = inputs.to_field_elements();
+    if elements == circuit_fixture() {
+        println!("✅ Test 1 passed: [amount, donor_commitment, nullifier, campaign_id] as 32-byte LE elements");
+    } else {
+        panic!("Test 1 failed: Field elements {:?} do not match the fixture", elements);
+    }
+
+    // Test case: Proof parsing round-trips into the same public inputs
+    println!("\nTest 2: Parsing a proof should yield the public inputs it was built from");
+    let proof = proof_data(&inputs, 1_700_000_000);
+    match extract_donation_data(&proof) {
+        Ok((parsed, timestamp)) if parsed == inputs && timestamp == 1_700_000_000 => {
+            println!("✅ Test 2 passed: Public inputs and timestamp round-trip through the proof layout");
+        }
+        other => panic!("Test 2 failed: Unexpected result {:?}", other),
+    }
+
+    // Test case: Leaf built from the same struct
+    println!("\nTest 3: The leaf should carry the verified amount, commitment and campaign id");
+    let leaf = serialize_leaf(1, &inputs, 1_700_000_000);
+    if leaf.len() == 57
+        && leaf[1..9] == inputs.amount.to_le_bytes()
+        && leaf[9..41] == inputs.donor_commitment
+        && leaf[49..57] == inputs.campaign_id.to_le_bytes()
+    {
+        println!("✅ Test 3 passed: Leaf fields taken from DonationPublicInputs");
+    } else {
+        panic!("Test 3 failed: Leaf does not match the public inputs: {:?}", leaf);
+    }
+
+    // Test case: Proof bound to another campaign
+    println!("\nTest 4: A proof whose campaign_id differs from the instruction's should be rejected");
+    match verify_campaign(&inputs, 54321) {
+        Err(e) if e == "ProofCampaignMismatch" => println!("✅ Test 4 passed: Cross-campaign proof rejected"),
+        other => panic!("Test 4 failed: Expected ProofCampaignMismatch, got {:?}", other),
+    }
+
+    println!("\n✅✅✅ All Public Inputs tests passed! ✅✅✅");
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct DonationPublicInputs {
+    amount: u64,
+    donor_commitment: [u8; 32],
+    nullifier: [u8; 32],
+    campaign_id: u64,
+}
+
+impl DonationPublicInputs {
+    /// Mirror of DonationPublicInputs::to_field_elements
+    fn to_field_elements(&self) -> [[u8; 32]; 4] {
+        [
+            u64_field_element(self.amount),
+            self.donor_commitment,
+            self.nullifier,
+            u64_field_element(self.campaign_id),
+        ]
+    }
+}
+
+fn u64_field_element(value: u64) -> [u8; 32] {
+    let mut element = [0u8; 32];
+    element[..8].copy_from_slice(&value.to_le_bytes());
+    element
+}
+
+/// Public inputs exactly as the circuit declares them, written out byte by byte
+fn circuit_fixture() -> [[u8; 32]; 4] {
+    let mut amount = [0u8; 32];
+    amount[..8].copy_from_slice(&[0x08, 0x07, 0x06, 0x05, 0x04, 0x03, 0x02, 0x01]);
+    let mut campaign_id = [0u8; 32];
+    campaign_id[..2].copy_from_slice(&[0x39, 0x30]);
+    [amount, [0xaa; 32], [0xbb; 32], campaign_id]
+}
+
+/// [leaf version (1) | amount (8) | commitment (32) | nullifier (32) | campaign id (8) | timestamp (8)]
+fn proof_data(inputs: &DonationPublicInputs, timestamp: i64) -> Vec<u8> {
+    let mut data = vec![1u8];
+    data.extend_from_slice(&inputs.amount.to_le_bytes());
+    data.extend_from_slice(&inputs.donor_commitment);
+    data.extend_from_slice(&inputs.nullifier);
+    data.extend_from_slice(&inputs.campaign_id.to_le_bytes());
+    data.extend_from_slice(&timestamp.to_le_bytes());
+    data
+}
+
+/// Mirror of DonateCompressed::extract_donation_data
+fn extract_donation_data(proof_data: &[u8]) -> Result<(DonationPublicInputs, i64), String> {
+    if proof_data.len() < 89 {
+        return Err("InvalidProofFormat".into());
+    }
+    let mut amount_bytes = [0u8; 8];
+    amount_bytes.copy_from_slice(&proof_data[1..9]);
+    let mut donor_commitment = [0u8; 32];
+    donor_commitment.copy_from_slice(&proof_data[9..41]);
+    let mut nullifier = [0u8; 32];
+    nullifier.copy_from_slice(&proof_data[41..73]);
+    let mut campaign_id_bytes = [0u8; 8];
+    campaign_id_bytes.copy_from_slice(&proof_data[73..81]);
+    let mut timestamp_bytes = [0u8; 8];
+    timestamp_bytes.copy_from_slice(&proof_data[81..89]);
+
+    let inputs = DonationPublicInputs {
+        amount: u64::from_le_bytes(amount_bytes),
+        donor_commitment,
+        nullifier,
+        campaign_id: u64::from_le_bytes(campaign_id_bytes),
+    };
+    Ok((inputs, i64::from_le_bytes(timestamp_bytes)))
+}
+
+/// Mirror of DonationLeaf::new followed by DonationLeaf::serialize
+fn serialize_leaf(leaf_version: u8, inputs: &DonationPublicInputs, timestamp: i64) -> Vec<u8> {
+    let mut leaf = vec![leaf_version];
+    leaf.extend_from_slice(&inputs.amount.to_le_bytes());
+    leaf.extend_from_slice(&inputs.donor_commitment);
+    leaf.extend_from_slice(&timestamp.to_le_bytes());
+    leaf.extend_from_slice(&inputs.campaign_id.to_le_bytes());
+    leaf
+}
+
+fn verify_campaign(inputs: &DonationPublicInputs, campaign_id: u64) -> Result<(), String> {
+    if inputs.campaign_id != campaign_id {
+        return Err("ProofCampaignMismatch".into());
+    }
+    Ok(())
+}
@@ -0,0 +1,71 @@
+fn main() {
+    println!("Testing Reentrancy Guard on donate_amount");
+
+    // Test case: Normal donation
+    println!("\nTest 1: A normal donation should succeed and release the lock");
+    let mut campaign = MockCampaign { locked: false, total_donation_received: 0 };
+    match donate_amount(&mut campaign, 100, &mut |_| Ok(())) {
+        Ok(_) if !campaign.locked && campaign.total_donation_received == 100 => {
+            println!("✅ Test 1 passed: Donation recorded and lock released");
+        }
+        Ok(_) => panic!("Test 1 failed: Lock still held or total not updated"),
+        Err(e) => panic!("Test 1 failed: Donation rejected with error: {}", e),
+    }
+
+    // Test case: Transfer hook re-entering donate_amount during the CPI
+    println!("\nTest 2: A reentrant call from the transfer CPI should be blocked");
+    let mut reentrant_result = None;
+    let outer = donate_amount(&mut campaign, 50, &mut |campaign| {
+        // The hook sees the persisted campaign state and tries to donate again
+        reentrant_result = Some(donate_amount(campaign, 1_000, &mut |_| Ok(())));
+        Ok(())
+    });
+    match reentrant_result {
+        Some(Err(ref e)) if e == "ReentrancyDetected" => {
+            println!("✅ Test 2 passed: Reentrant call rejected with {}", e)
+        }
+        _ => panic!("Test 2 failed: Reentrant call was not rejected"),
+    }
+    if outer.is_ok() && campaign.total_donation_received == 150 && !campaign.locked {
+        println!("  Outer donation completed, reentrant donation not counted");
+    } else {
+        panic!("Test 2 failed: Campaign state corrupted by reentrant call");
+    }
+
+    // Test case: Failed transfer
+    println!("\nTest 3: A failed transfer should not record the donation");
+    let before = campaign.total_donation_received;
+    let result = donate_amount(&mut campaign, 10, &mut |_| Err("Transfer failed".to_string()));
+    // On-chain the whole transaction reverts, including the persisted lock
+    if result.is_err() && campaign.total_donation_received == before {
+        println!("✅ Test 3 passed: Failed transfer left totals untouched");
+    } else {
+        panic!("Test 3 failed: Failed transfer changed campaign totals");
+    }
+
+    println!("\n✅✅✅ All Reentrancy Guard tests passed! ✅✅✅");
+}
+
+struct MockCampaign {
+    locked: bool,
+    total_donation_received: u64,
+}
+
+/// Mirror of donate_amount: `transfer` stands in for the transfer_checked CPI
+/// and receives the campaign so it can simulate a transfer hook re-entering
+fn donate_amount(
+    campaign: &mut MockCampaign,
+    amount: u64,
+    transfer: &mut dyn FnMut(&mut MockCampaign) -> Result<(), String>,
+) -> Result<(), String> {
+    if campaign.locked {
+        return Err("ReentrancyDetected".into());
+    }
+    campaign.locked = true;
+
+    transfer(campaign)?;
+
+    campaign.total_donation_received += amount;
+    campaign.locked = false;
+    Ok(())
+}
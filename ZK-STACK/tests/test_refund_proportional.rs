@@ -0,0 +1,192 @@
+use std::collections::HashMap;
+
+fn main() {
+    println!("Testing Proportional Refunds");
+
+    // Test case: Shares sum to the pool within rounding
+    println!("\nTest 1: Pro-rata shares should sum to the refund pool within rounding");
+    let donations = [333u64, 333, 334, 1, 7_777, 50_000, 12_345];
+    let total: u64 = donations.iter().sum();
+    let mut campaign = MockCampaign { total_donation_received: total, token_donation_total: total, refund_pool: 10_007, vault: 10_007, is_failed: true, claimed: HashMap::new() };
+    let mut paid = 0u64;
+    for (index, donated) in donations.iter().enumerate() {
+        match refund_proportional(&mut campaign, index as u8, *donated) {
+            Ok(amount) => paid += amount,
+            Err(e) if e == "NothingToRefund" => {}
+            Err(e) => panic!("Test 1 failed: Donor {} got {}", index, e),
+        }
+    }
+    let shortfall = campaign.refund_pool - paid;
+    if paid <= campaign.refund_pool && shortfall < donations.len() as u64 {
+        println!("✅ Test 1 passed: Paid {} of {} (rounding remainder {})", paid, campaign.refund_pool, shortfall);
+    } else {
+        panic!("Test 1 failed: Paid {} of pool {}", paid, campaign.refund_pool);
+    }
+
+    // Test case: Exact division
+    println!("\nTest 2: A donor who gave a quarter of the total should get a quarter of the pool");
+    let mut campaign = MockCampaign { total_donation_received: 4_000, token_donation_total: 4_000, refund_pool: 1_000, vault: 1_000, is_failed: true, claimed: HashMap::new() };
+    match refund_proportional(&mut campaign, 0, 1_000) {
+        Ok(250) => println!("✅ Test 2 passed: 1000 * 1000 / 4000 = 250"),
+        other => panic!("Test 2 failed: Expected 250, got {:?}", other),
+    }
+
+    // Test case: Double claim
+    println!("\nTest 3: A donor should not be paid twice for the same pool");
+    match refund_proportional(&mut campaign, 0, 1_000) {
+        Err(e) if e == "NothingToRefund" && campaign.vault == 750 => {
+            println!("✅ Test 3 passed: Second claim rejected, RefundClaim already covers the share");
+        }
+        other => panic!("Test 3 failed: Expected NothingToRefund, got {:?}", other),
+    }
+
+    // Test case: Claim before a top-up
+    println!("\nTest 4: A donor who claimed before a top-up should be paid only the difference");
+    fund_refund_pool(&mut campaign, 1_000).unwrap();
+    match refund_proportional(&mut campaign, 0, 1_000) {
+        Ok(250) if campaign.claimed[&0] == 500 && campaign.vault == 1_500 => {
+            println!("✅ Test 4 passed: Share grew from 250 to 500, paid the extra 250");
+        }
+        other => panic!("Test 4 failed: Expected 250, got {:?}", other),
+    }
+    match refund_proportional(&mut campaign, 1, 3_000) {
+        Ok(1_500) if campaign.vault == 0 => println!("  Late claimer paid the full 1500 share"),
+        other => panic!("Test 4 failed: Expected 1500 for the other donor, got {:?}", other),
+    }
+
+    // Test case: Empty pool
+    println!("\nTest 5: Claims should be rejected while the refund pool is zero");
+    let mut campaign = MockCampaign { total_donation_received: 4_000, token_donation_total: 4_000, refund_pool: 0, vault: 0, is_failed: true, claimed: HashMap::new() };
+    match refund_proportional(&mut campaign, 0, 1_000) {
+        Err(e) if e == "RefundPoolEmpty" && campaign.claimed.is_empty() => {
+            println!("✅ Test 5 passed: Empty pool rejected without recording a claim");
+        }
+        other => panic!("Test 5 failed: Expected RefundPoolEmpty, got {:?}", other),
+    }
+
+    // Test case: Large values
+    println!("\nTest 6: The share should be computed without overflow for large amounts");
+    let mut campaign = MockCampaign { total_donation_received: u64::MAX, token_donation_total: u64::MAX, refund_pool: u64::MAX / 2, vault: u64::MAX / 2, is_failed: true, claimed: HashMap::new() };
+    match refund_proportional(&mut campaign, 0, u64::MAX) {
+        Ok(amount) if amount == u64::MAX / 2 => println!("✅ Test 6 passed: u128 intermediate avoids overflow"),
+        other => panic!("Test 6 failed: Unexpected result {:?}", other),
+    }
+
+    // Test case: Campaign still running or successful
+    println!("\nTest 7: Claims should be rejected unless the campaign has failed");
+    let mut campaign = MockCampaign { total_donation_received: 4_000, token_donation_total: 4_000, refund_pool: 1_000, vault: 1_000, is_failed: false, claimed: HashMap::new() };
+    match refund_proportional(&mut campaign, 0, 1_000) {
+        Err(e) if e == "CampaignNotFailed" && campaign.vault == 1_000 => {
+            println!("✅ Test 7 passed: Rejected with CampaignNotFailed");
+        }
+        other => panic!("Test 7 failed: Expected CampaignNotFailed, got {:?}", other),
+    }
+
+    // Test case: Share larger than what is left
+    println!("\nTest 8: A share above the remaining vault balance should be capped, not fail");
+    let mut campaign = MockCampaign { total_donation_received: 4_000, token_donation_total: 4_000, refund_pool: 1_000, vault: 100, is_failed: true, claimed: HashMap::new() };
+    match refund_proportional(&mut campaign, 0, 1_000) {
+        Ok(100) if campaign.vault == 0 => println!("✅ Test 8 passed: Share of 250 capped at the 100 left"),
+        other => panic!("Test 8 failed: Expected 100, got {:?}", other),
+    }
+
+    // Test case: Funding a campaign that has not failed
+    println!("\nTest 9: Funding the refund pool should be rejected unless the campaign has failed");
+    let mut campaign = MockCampaign { total_donation_received: 4_000, token_donation_total: 4_000, refund_pool: 0, vault: 0, is_failed: false, claimed: HashMap::new() };
+    match fund_refund_pool(&mut campaign, 1_000) {
+        Err(e) if e == "CampaignNotFailed" && campaign.vault == 0 && campaign.refund_pool == 0 => {
+            println!("✅ Test 9 passed: Tokens not stranded in the vault of a live campaign");
+        }
+        other => panic!("Test 9 failed: Expected CampaignNotFailed, got {:?}", other),
+    }
+
+    // Test case: Zero funding
+    println!("\nTest 10: Funding the refund pool with zero should be rejected");
+    campaign.is_failed = true;
+    match fund_refund_pool(&mut campaign, 0) {
+        Err(e) if e == "ZeroRefundPoolFunding" => println!("✅ Test 10 passed: Rejected with {}", e),
+        other => panic!("Test 10 failed: Expected ZeroRefundPoolFunding, got {:?}", other),
+    }
+    match fund_refund_pool(&mut campaign, 1_000) {
+        Ok(()) if campaign.vault == 1_000 && campaign.refund_pool == 1_000 => println!("  Failed campaign funded"),
+        other => panic!("Test 10 failed: Funding a failed campaign returned {:?}", other),
+    }
+
+    // Test case: Mixed token and compressed campaign
+    println!("\nTest 11: Token donors should split the whole pool when part of the total was donated compressed");
+    let mut campaign = MockCampaign { total_donation_received: 0, token_donation_total: 0, refund_pool: 0, vault: 0, is_failed: true, claimed: HashMap::new() };
+    donate_tokens(&mut campaign, 1_000);
+    donate_tokens(&mut campaign, 2_000);
+    donate_compressed(&mut campaign, 1_000);
+    fund_refund_pool(&mut campaign, 900).unwrap();
+    let shares = [refund_proportional(&mut campaign, 1, 1_000), refund_proportional(&mut campaign, 2, 2_000)];
+    if shares == [Ok(300), Ok(600)] && campaign.vault == 0 && campaign.total_donation_received == 4_000 {
+        println!("✅ Test 11 passed: 300 and 600 paid, nothing stranded for compressed donors");
+    } else {
+        panic!("Test 11 failed: Shares {:?}, {} left in the vault", shares, campaign.vault);
+    }
+
+    println!("\n✅✅✅ All Proportional Refund tests passed! ✅✅✅");
+}
+
+struct MockCampaign {
+    total_donation_received: u64,
+    // Token donations only; compressed donors have no DonerInfo to claim with
+    token_donation_total: u64,
+    refund_pool: u64,
+    vault: u64,
+    is_failed: bool,
+    // RefundClaim.paid per donor
+    claimed: HashMap<u8, u64>,
+}
+
+/// Mirror of CampaignInfo::refund_share
+fn refund_share(campaign: &MockCampaign, donor_amount: u64) -> Result<u64, String> {
+    if campaign.token_donation_total == 0 {
+        return Ok(0);
+    }
+    let share = u128::from(donor_amount) * u128::from(campaign.refund_pool) / u128::from(campaign.token_donation_total);
+    u64::try_from(share).map_err(|_| "ArithmeticOverflow".to_string())
+}
+
+/// Mirror of the campaign totals TokenDonation::process updates
+fn donate_tokens(campaign: &mut MockCampaign, amount: u64) {
+    campaign.total_donation_received += amount;
+    campaign.token_donation_total += amount;
+}
+
+/// Mirror of the campaign totals donate_compressed updates through apply_donation
+fn donate_compressed(campaign: &mut MockCampaign, amount: u64) {
+    campaign.total_donation_received += amount;
+}
+
+/// Mirror of FundRefundPool::fund_refund_pool
+fn fund_refund_pool(campaign: &mut MockCampaign, amount: u64) -> Result<(), String> {
+    if amount == 0 {
+        return Err("ZeroRefundPoolFunding".into());
+    }
+    if !campaign.is_failed {
+        return Err("CampaignNotFailed".into());
+    }
+    campaign.vault += amount;
+    campaign.refund_pool = campaign.refund_pool.checked_add(amount).ok_or("ArithmeticOverflow")?;
+    Ok(())
+}
+
+/// Mirror of RefundProportional::refund_proportional, with `init_if_needed` on the claim PDA
+fn refund_proportional(campaign: &mut MockCampaign, donor: u8, donated: u64) -> Result<u64, String> {
+    let paid = campaign.claimed.get(&donor).copied().unwrap_or(0);
+    if !campaign.is_failed {
+        return Err("CampaignNotFailed".into());
+    }
+    if campaign.refund_pool == 0 {
+        return Err("RefundPoolEmpty".into());
+    }
+    let amount = refund_share(campaign, donated)?.saturating_sub(paid).min(campaign.vault);
+    if amount == 0 {
+        return Err("NothingToRefund".into());
+    }
+    campaign.vault = campaign.vault.checked_sub(amount).ok_or("InsufficientFunds")?;
+    campaign.claimed.insert(donor, paid.checked_add(amount).ok_or("ArithmeticOverflow")?);
+    Ok(amount)
+}
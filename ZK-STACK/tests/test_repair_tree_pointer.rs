@@ -0,0 +1,236 @@
+use std::collections::HashMap;
+
+fn main() {
+    println!("Testing Merkle Tree Pointer Repair");
+
+    let creator = [1u8; 32];
+    let campaign_key = [10u8; 32];
+    let old_tree = [20u8; 32];
+    let new_tree = MockTree::concurrent([21u8; 32], campaign_key);
+
+    // Test case: Valid repair
+    println!("\nTest 1: The creator should be able to point the campaign at a tree it controls");
+    let mut campaign = MockCampaign::new(campaign_key, creator, old_tree);
+    match repair_tree_pointer(&mut campaign, creator, &new_tree, None) {
+        Ok(event) if campaign.merkle_tree == new_tree.key && event.previous_tree == old_tree && !campaign.is_batched => {
+            println!("✅ Test 1 passed: Pointer updated and event emitted")
+        }
+        _ => panic!("Test 1 failed: Pointer not repaired"),
+    }
+
+    // Test case: Tree controlled by someone else
+    println!("\nTest 2: A tree not controlled by the campaign should be rejected");
+    let mut campaign = MockCampaign::new(campaign_key, creator, old_tree);
+    let foreign_tree = MockTree::concurrent([22u8; 32], [99u8; 32]);
+    match repair_tree_pointer(&mut campaign, creator, &foreign_tree, None) {
+        Err(e) if e == "TreeNotControlledByCampaign" && campaign.merkle_tree == old_tree => {
+            println!("✅ Test 2 passed: Rejected with {}", e)
+        }
+        _ => panic!("Test 2 failed: Foreign tree accepted"),
+    }
+
+    // Test case: Account not owned by Light Protocol
+    println!("\nTest 3: An account not owned by the Light program should be rejected");
+    let fake_tree = MockTree { program_owner: [7u8; 32], ..MockTree::concurrent([23u8; 32], campaign_key) };
+    match repair_tree_pointer(&mut campaign, creator, &fake_tree, None) {
+        Err(e) if e == "InvalidMerkleTree" => println!("✅ Test 3 passed: Rejected with {}", e),
+        _ => panic!("Test 3 failed: Non-Light account accepted"),
+    }
+
+    // Test case: Not the creator
+    println!("\nTest 4: Only the campaign creator should be able to repair the pointer");
+    match repair_tree_pointer(&mut campaign, [2u8; 32], &new_tree, None) {
+        Err(e) if e == "Unauthorized" => println!("✅ Test 4 passed: Rejected with {}", e),
+        _ => panic!("Test 4 failed: Non-creator repaired the pointer"),
+    }
+
+    // Test case: Batched replacement tree
+    println!("\nTest 5: A batched tree with its queue should switch the campaign to batched appends");
+    let batched_tree = MockTree::batched([24u8; 32], campaign_key, QUEUE);
+    let mut campaign = MockCampaign::new(campaign_key, creator, old_tree);
+    match repair_tree_pointer(&mut campaign, creator, &batched_tree, Some(QUEUE)) {
+        Ok(_) if campaign.merkle_tree == batched_tree.key && campaign.is_batched && campaign.output_queue == QUEUE => {
+            println!("✅ Test 5 passed: Pointer, is_batched and output_queue updated")
+        }
+        other => panic!("Test 5 failed: Unexpected result {:?}", other.err()),
+    }
+
+    // Test case: Batched tree with a wrong or missing queue
+    println!("\nTest 6: A batched tree should need the queue recorded in its metadata");
+    let mut campaign = MockCampaign::new(campaign_key, creator, old_tree);
+    let results = [
+        repair_tree_pointer(&mut campaign, creator, &batched_tree, Some([33u8; 32])).err(),
+        repair_tree_pointer(&mut campaign, creator, &batched_tree, None).err(),
+    ];
+    if results == [Some("QueueMismatch".to_string()), Some("OutputQueueRequired".to_string())]
+        && campaign.merkle_tree == old_tree
+    {
+        println!("✅ Test 6 passed: Rejected with QueueMismatch and OutputQueueRequired");
+    } else {
+        panic!("Test 6 failed: Got {:?}", results);
+    }
+
+    // Test case: Back from batched to concurrent
+    println!("\nTest 7: Repairing a batched campaign onto a concurrent tree should clear its queue");
+    let mut campaign = MockCampaign::new(campaign_key, creator, old_tree);
+    repair_tree_pointer(&mut campaign, creator, &batched_tree, Some(QUEUE)).unwrap();
+    repair_tree_pointer(&mut campaign, creator, &new_tree, None).unwrap();
+    if !campaign.is_batched && campaign.output_queue == [0u8; 32] {
+        println!("✅ Test 7 passed: is_batched cleared and output_queue reset");
+    } else {
+        panic!("Test 7 failed: Campaign still batched");
+    }
+
+    // Test case: Tree links follow the pointer
+    println!("\nTest 8: The replaced tree's link should be closed and the new tree's link created");
+    if !campaign.tree_links.contains_key(&old_tree)
+        && !campaign.tree_links.contains_key(&batched_tree.key)
+        && campaign.tree_links.get(&new_tree.key) == Some(&campaign_key)
+    {
+        println!("✅ Test 8 passed: Only the current tree resolves to the campaign");
+    } else {
+        panic!("Test 8 failed: Links {:?}", campaign.tree_links);
+    }
+
+    // Test case: Same tree
+    println!("\nTest 9: Repairing onto the tree the campaign already uses should be rejected");
+    match repair_tree_pointer(&mut campaign, creator, &new_tree, None) {
+        Err(e) if e == "TreeUnchanged" && campaign.tree_links.contains_key(&new_tree.key) => {
+            println!("✅ Test 9 passed: Rejected with {}", e)
+        }
+        _ => panic!("Test 9 failed: Same tree accepted"),
+    }
+
+    // Test case: Campaign from before tree links
+    println!("\nTest 10: A campaign whose tree never had a link should still be repairable");
+    let mut legacy = MockCampaign::new(campaign_key, creator, old_tree);
+    legacy.tree_links.clear();
+    match repair_tree_pointer(&mut legacy, creator, &new_tree, None) {
+        Ok(_) if legacy.tree_links.get(&new_tree.key) == Some(&campaign_key) && legacy.tree_links.len() == 1 => {
+            println!("✅ Test 10 passed: Empty previous link skipped, new link created")
+        }
+        other => panic!("Test 10 failed: Unexpected result {:?}", other.err()),
+    }
+
+    // Test case: Previous link resolving elsewhere
+    println!("\nTest 11: A previous link pointing at another campaign should be rejected, not closed");
+    let mut foreign_link = MockCampaign::new(campaign_key, creator, old_tree);
+    foreign_link.tree_links.insert(old_tree, [9u8; 32]);
+    match repair_tree_pointer(&mut foreign_link, creator, &new_tree, None) {
+        Err(e) if e == "TreeLinkMismatch" && foreign_link.merkle_tree == old_tree => {
+            println!("✅ Test 11 passed: Rejected with {}", e)
+        }
+        other => panic!("Test 11 failed: Unexpected result {:?}", other.err()),
+    }
+
+    println!("\n✅✅✅ All Tree Pointer Repair tests passed! ✅✅✅");
+}
+
+const LIGHT_PROGRAM: [u8; 32] = [0xC0; 32];
+const QUEUE: [u8; 32] = [30u8; 32];
+const STATE_TREE_DISCRIMINATOR: [u8; 8] = [1, 2, 3, 4, 5, 6, 7, 8];
+const BATCHED_TREE_DISCRIMINATOR: [u8; 8] = *b"BatchMta";
+
+struct MockCampaign {
+    key: [u8; 32],
+    creator: [u8; 32],
+    merkle_tree: [u8; 32],
+    output_queue: [u8; 32],
+    is_batched: bool,
+    // TreeCampaignLink accounts: tree -> campaign
+    tree_links: HashMap<[u8; 32], [u8; 32]>,
+}
+
+impl MockCampaign {
+    fn new(key: [u8; 32], creator: [u8; 32], merkle_tree: [u8; 32]) -> Self {
+        let mut tree_links = HashMap::new();
+        tree_links.insert(merkle_tree, key);
+        Self { key, creator, merkle_tree, output_queue: [0u8; 32], is_batched: false, tree_links }
+    }
+}
+
+struct MockTree {
+    key: [u8; 32],
+    // Program owning the account
+    program_owner: [u8; 32],
+    discriminator: [u8; 8],
+    // access_metadata.owner recorded in the tree
+    authority: [u8; 32],
+    associated_queue: [u8; 32],
+}
+
+impl MockTree {
+    fn concurrent(key: [u8; 32], authority: [u8; 32]) -> Self {
+        Self { key, program_owner: LIGHT_PROGRAM, discriminator: STATE_TREE_DISCRIMINATOR, authority, associated_queue: [0u8; 32] }
+    }
+
+    fn batched(key: [u8; 32], authority: [u8; 32], associated_queue: [u8; 32]) -> Self {
+        Self { key, program_owner: LIGHT_PROGRAM, discriminator: BATCHED_TREE_DISCRIMINATOR, authority, associated_queue }
+    }
+}
+
+/// Mock event struct representing TreePointerRepairedEvent
+struct TreePointerRepairedEvent {
+    previous_tree: [u8; 32],
+}
+
+/// Mirror of create_campaign_tree_account::validate_tree_queue; returns whether the tree is batched
+fn validate_tree_queue(tree: &MockTree, output_queue: Option<[u8; 32]>) -> Result<bool, String> {
+    if tree.program_owner != LIGHT_PROGRAM {
+        return Err("InvalidMerkleTree".into());
+    }
+    let is_batched = match tree.discriminator {
+        STATE_TREE_DISCRIMINATOR => false,
+        BATCHED_TREE_DISCRIMINATOR => true,
+        _ => return Err("InvalidMerkleTree".into()),
+    };
+    match (is_batched, output_queue) {
+        (true, Some(queue)) if queue != tree.associated_queue => Err("QueueMismatch".into()),
+        (true, None) => Err("OutputQueueRequired".into()),
+        (false, Some(_)) => Err("OutputQueueUnexpected".into()),
+        _ => Ok(is_batched),
+    }
+}
+
+/// Mirror of the repair_tree_pointer account constraints and handler
+fn repair_tree_pointer(
+    campaign: &mut MockCampaign,
+    signer: [u8; 32],
+    tree: &MockTree,
+    output_queue: Option<[u8; 32]>,
+) -> Result<TreePointerRepairedEvent, String> {
+    if campaign.creator != signer {
+        return Err("Unauthorized".into());
+    }
+    if tree.key == campaign.merkle_tree {
+        return Err("TreeUnchanged".into());
+    }
+    let is_batched = validate_tree_queue(tree, output_queue)?;
+    if tree.authority != campaign.key {
+        return Err("TreeNotControlledByCampaign".into());
+    }
+
+    close_previous_tree_link(campaign)?;
+
+    let previous_tree = campaign.merkle_tree;
+    campaign.merkle_tree = tree.key;
+    campaign.output_queue = output_queue.unwrap_or([0u8; 32]);
+    campaign.is_batched = is_batched;
+
+    // init_if_needed on the new link
+    campaign.tree_links.insert(tree.key, campaign.key);
+    Ok(TreePointerRepairedEvent { previous_tree })
+}
+
+/// Mirror of RepairTreePointer::close_previous_tree_link: the account at the
+/// derived address is always passed, and closed whenever it holds a link
+fn close_previous_tree_link(campaign: &mut MockCampaign) -> Result<(), String> {
+    match campaign.tree_links.get(&campaign.merkle_tree) {
+        None => Ok(()),
+        Some(linked) if *linked != campaign.key => Err("TreeLinkMismatch".into()),
+        Some(_) => {
+            campaign.tree_links.remove(&campaign.merkle_tree);
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,167 @@
+const RECENT_DONATIONS_LEN: usize = 10;
+
+fn main() {
+    println!("Testing Campaign Seasons");
+
+    // Test case: Donations tagged with the current season
+    println!("\nTest 1: Donations in the first season should carry season 0");
+    let mut program = MockProgram::new();
+    let first = program.donate(ALICE, 300);
+    program.donate(BOB, 200);
+    if first.season == 0 && program.recent_donations.count == 2 {
+        println!("✅ Test 1 passed: Season 0 feed holds 2 donations");
+    } else {
+        panic!("Test 1 failed: Unexpected season {} or feed count {}", first.season, program.recent_donations.count);
+    }
+
+    // Test case: Season boundary clears the feed
+    println!("\nTest 2: start_new_season should advance the season and clear the feed");
+    let event = program.start_new_season(CREATOR).unwrap();
+    if event.previous_season == 0
+        && event.season == 1
+        && program.campaign.season == 1
+        && program.recent_donations.newest_first().is_empty()
+    {
+        println!("✅ Test 2 passed: Season 1 started with an empty feed");
+    } else {
+        panic!("Test 2 failed: Feed not cleared or season not advanced");
+    }
+
+    // Test case: Lifetime totals persist
+    println!("\nTest 3: Lifetime totals should survive the season boundary");
+    if program.doner_amounts[&ALICE] == 300
+        && program.doner_amounts[&BOB] == 200
+        && program.campaign.total_donation_received == 500
+    {
+        println!("✅ Test 3 passed: Alice 300, Bob 200, campaign 500");
+    } else {
+        panic!("Test 3 failed: Lifetime totals changed across seasons");
+    }
+
+    // Test case: New season donations
+    println!("\nTest 4: Donations after the boundary should join season 1 and add to lifetime totals");
+    let event = program.donate(ALICE, 50);
+    let feed = program.recent_donations.newest_first();
+    if event.season == 1 && feed.len() == 1 && feed[0].amount == 50 && program.doner_amounts[&ALICE] == 350 {
+        println!("✅ Test 4 passed: Season 1 feed holds only the new donation, Alice lifetime 350");
+    } else {
+        panic!("Test 4 failed: Unexpected feed {:?}", feed);
+    }
+
+    // Test case: Creator only
+    println!("\nTest 5: Only the creator should start a new season");
+    match program.start_new_season(ALICE) {
+        Err("Unauthorized") if program.campaign.season == 1 && program.recent_donations.count == 1 => {
+            println!("✅ Test 5 passed: Rejected with Unauthorized, nothing changed")
+        }
+        other => panic!("Test 5 failed: Expected Unauthorized, got {:?}", other),
+    }
+
+    // Test case: Season before any token donation
+    println!("\nTest 6: A campaign without a feed should still advance its season");
+    let mut fresh = MockProgram::new();
+    fresh.feed_created = false;
+    match fresh.start_new_season(CREATOR) {
+        Ok(event) if event.season == 1 => println!("✅ Test 6 passed: Season advanced without a feed account"),
+        other => panic!("Test 6 failed: Unexpected result {:?}", other),
+    }
+
+    println!("\n✅✅✅ All Season tests passed! ✅✅✅");
+}
+
+const CREATOR: u8 = 9;
+const ALICE: u8 = 1;
+const BOB: u8 = 2;
+
+/// Mock event struct representing DonationReceivedEvent
+struct DonationReceivedEvent {
+    season: u16,
+}
+
+/// Mock event struct representing SeasonStartedEvent
+#[derive(Debug)]
+struct SeasonStartedEvent {
+    previous_season: u16,
+    season: u16,
+}
+
+/// Mirror of the CampaignInfo fields start_new_season touches
+struct MockCampaign {
+    creator: u8,
+    total_donation_received: u64,
+    season: u16,
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+struct RecentDonation {
+    amount: u64,
+}
+
+/// Mirror of state::RecentDonations
+#[derive(Default)]
+struct RecentDonations {
+    next_index: u8,
+    count: u8,
+    entries: [RecentDonation; RECENT_DONATIONS_LEN],
+}
+
+impl RecentDonations {
+    fn push(&mut self, entry: RecentDonation) {
+        self.entries[usize::from(self.next_index)] = entry;
+        self.next_index = ((usize::from(self.next_index) + 1) % RECENT_DONATIONS_LEN) as u8;
+        if usize::from(self.count) < RECENT_DONATIONS_LEN {
+            self.count += 1;
+        }
+    }
+
+    fn clear(&mut self) {
+        self.next_index = 0;
+        self.count = 0;
+        self.entries = [RecentDonation::default(); RECENT_DONATIONS_LEN];
+    }
+
+    fn newest_first(&self) -> Vec<RecentDonation> {
+        (1..=usize::from(self.count))
+            .map(|age| self.entries[(usize::from(self.next_index) + RECENT_DONATIONS_LEN - age) % RECENT_DONATIONS_LEN])
+            .collect()
+    }
+}
+
+struct MockProgram {
+    campaign: MockCampaign,
+    recent_donations: RecentDonations,
+    feed_created: bool,
+    doner_amounts: std::collections::HashMap<u8, u64>,
+}
+
+impl MockProgram {
+    fn new() -> Self {
+        Self {
+            campaign: MockCampaign { creator: CREATOR, total_donation_received: 0, season: 0 },
+            recent_donations: RecentDonations::default(),
+            feed_created: true,
+            doner_amounts: std::collections::HashMap::new(),
+        }
+    }
+
+    /// Mirror of the state updates in TokenDonation::process
+    fn donate(&mut self, donor: u8, amount: u64) -> DonationReceivedEvent {
+        *self.doner_amounts.entry(donor).or_insert(0) += amount;
+        self.campaign.total_donation_received += amount;
+        self.recent_donations.push(RecentDonation { amount });
+        DonationReceivedEvent { season: self.campaign.season }
+    }
+
+    /// Mirror of StartNewSeason::start_new_season
+    fn start_new_season(&mut self, signer: u8) -> Result<SeasonStartedEvent, &'static str> {
+        if signer != self.campaign.creator {
+            return Err("Unauthorized");
+        }
+        let previous_season = self.campaign.season;
+        self.campaign.season = previous_season.checked_add(1).ok_or("ArithmeticOverflow")?;
+        if self.feed_created {
+            self.recent_donations.clear();
+        }
+        Ok(SeasonStartedEvent { previous_season, season: self.campaign.season })
+    }
+}
@@ -0,0 +1,62 @@
+fn main() {
+    println!("Testing Self-Donation Handling");
+
+    let creator = [1u8; 32];
+    let donor = [2u8; 32];
+
+    // Test case: Self-donation allowed
+    println!("\nTest 1: With allow_self_donation, the creator's donation should be accepted and flagged");
+    let campaign = MockCampaign { creator, allow_self_donation: true };
+    match donate_amount(&campaign, creator, creator) {
+        Ok(event) if event.is_self_donation => println!("✅ Test 1 passed: Accepted and flagged as self"),
+        _ => panic!("Test 1 failed: Self-donation not accepted or not flagged"),
+    }
+    match donate_amount(&campaign, donor, donor) {
+        Ok(event) if !event.is_self_donation => println!("  Regular donation not flagged"),
+        _ => panic!("Test 1 failed: Regular donation flagged as self"),
+    }
+
+    // Test case: Self-donation disallowed
+    println!("\nTest 2: Without allow_self_donation, the creator's donation should be rejected");
+    let campaign = MockCampaign { creator, allow_self_donation: false };
+    match donate_amount(&campaign, creator, creator) {
+        Err(e) if e == "SelfDonationNotAllowed" => println!("✅ Test 2 passed: Rejected with {}", e),
+        _ => panic!("Test 2 failed: Self-donation accepted"),
+    }
+
+    // Test case: Crediting the creator from another wallet
+    println!("\nTest 3: Donating on the creator's behalf should also count as self-donation");
+    match donate_amount(&campaign, donor, creator) {
+        Err(e) if e == "SelfDonationNotAllowed" => println!("✅ Test 3 passed: Rejected with {}", e),
+        _ => panic!("Test 3 failed: Donation credited to the creator accepted"),
+    }
+
+    // Test case: Other donors unaffected
+    println!("\nTest 4: Other donors should be unaffected when self-donation is disallowed");
+    if donate_amount(&campaign, donor, donor).is_ok() {
+        println!("✅ Test 4 passed: Regular donation accepted");
+    } else {
+        panic!("Test 4 failed: Regular donation rejected");
+    }
+
+    println!("\n✅✅✅ All Self-Donation tests passed! ✅✅✅");
+}
+
+struct MockCampaign {
+    creator: [u8; 32],
+    allow_self_donation: bool,
+}
+
+/// Mock event struct representing DonationReceivedEvent
+struct DonationReceivedEvent {
+    is_self_donation: bool,
+}
+
+/// Mirror of the self-donation check in process_donation
+fn donate_amount(campaign: &MockCampaign, payer: [u8; 32], credited_donor: [u8; 32]) -> Result<DonationReceivedEvent, String> {
+    let is_self_donation = payer == campaign.creator || credited_donor == campaign.creator;
+    if is_self_donation && !campaign.allow_self_donation {
+        return Err("SelfDonationNotAllowed".into());
+    }
+    Ok(DonationReceivedEvent { is_self_donation })
+}
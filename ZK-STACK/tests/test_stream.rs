@@ -0,0 +1,127 @@
+fn main() {
+    println!("Testing Streaming Withdrawals");
+
+    // Test case: Invalid stream parameters
+    println!("\nTest 1: A zero amount or zero rate should be rejected");
+    if withdraw_to_stream(0, 10, 1_000) == Err("InvalidStreamParams")
+        && withdraw_to_stream(1_000, 0, 1_000) == Err("InvalidStreamParams")
+    {
+        println!("✅ Test 1 passed: Rejected with InvalidStreamParams");
+    } else {
+        panic!("Test 1 failed: Invalid parameters accepted");
+    }
+
+    // Test case: Nothing vested at start
+    println!("\nTest 2: Claiming at the start time should fail with NothingVested");
+    let mut stream = withdraw_to_stream(1_000, 10, 1_000).unwrap();
+    match stream.claim_stream(REAL_MINT, 1_000) {
+        Err("NothingVested") => println!("✅ Test 2 passed: Rejected with NothingVested"),
+        other => panic!("Test 2 failed: Expected NothingVested, got {:?}", other),
+    }
+
+    // Test case: Partial vesting
+    println!("\nTest 3: Advancing the clock 30s should vest 300");
+    let paid = stream.claim_stream(REAL_MINT, 1_030).unwrap();
+    if paid == 300 && stream.claimed == 300 {
+        println!("✅ Test 3 passed: Paid 300");
+    } else {
+        panic!("Test 3 failed: Paid {}, claimed {}", paid, stream.claimed);
+    }
+
+    // Test case: Repeat claim in the same second
+    println!("\nTest 4: A second claim without advancing the clock should fail");
+    match stream.claim_stream(REAL_MINT, 1_030) {
+        Err("NothingVested") => println!("✅ Test 4 passed: Already-claimed tokens not paid twice"),
+        other => panic!("Test 4 failed: Expected NothingVested, got {:?}", other),
+    }
+
+    // Test case: Incremental claim
+    println!("\nTest 5: Advancing another 45s should pay only the newly vested 450");
+    let paid = stream.claim_stream(REAL_MINT, 1_075).unwrap();
+    if paid == 450 && stream.claimed == 750 {
+        println!("✅ Test 5 passed: Paid 450, 750 claimed in total");
+    } else {
+        panic!("Test 5 failed: Paid {}, claimed {}", paid, stream.claimed);
+    }
+
+    // Test case: Vesting capped at the deposit
+    println!("\nTest 6: Vesting should stop at the deposited amount");
+    let paid = stream.claim_stream(REAL_MINT, 10_000).unwrap();
+    if paid == 250 && stream.claimed == 1_000 && stream.claim_stream(REAL_MINT, 20_000) == Err("NothingVested") {
+        println!("✅ Test 6 passed: Stream fully paid out at 1000");
+    } else {
+        panic!("Test 6 failed: Paid {}, claimed {}", paid, stream.claimed);
+    }
+
+    // Test case: Foreign mint
+    println!("\nTest 7: Claiming with a mint other than the stream's should be rejected");
+    let mut stream = withdraw_to_stream(1_000, 10, 1_000).unwrap();
+    match stream.claim_stream(FOREIGN_MINT, 1_050) {
+        Err("MintMismatch") if stream.claimed == 0 => {
+            println!("✅ Test 7 passed: Foreign mint rejected, claimed untouched");
+        }
+        other => panic!("Test 7 failed: Expected MintMismatch, got {:?}", other),
+    }
+    if stream.claim_stream(REAL_MINT, 1_050) != Ok(500) {
+        panic!("Test 7 failed: Real mint claim did not pay the vested 500");
+    }
+
+    // Test case: Large elapsed time
+    println!("\nTest 8: A huge rate and elapsed time should not overflow");
+    let stream = withdraw_to_stream(u64::MAX, u64::MAX, 0).unwrap();
+    if stream.vested_at(i64::MAX) == u64::MAX && stream.vested_at(-5) == 0 {
+        println!("✅ Test 8 passed: Vesting saturates at the deposit");
+    } else {
+        panic!("Test 8 failed: Unexpected vested amount");
+    }
+
+    println!("\n✅✅✅ All Stream tests passed! ✅✅✅");
+}
+
+const REAL_MINT: [u8; 32] = [1; 32];
+const FOREIGN_MINT: [u8; 32] = [2; 32];
+
+/// Mirror of state::Stream
+#[derive(Debug, PartialEq)]
+struct Stream {
+    mint: [u8; 32],
+    rate_per_second: u64,
+    start_time: i64,
+    deposited: u64,
+    claimed: u64,
+}
+
+impl Stream {
+    /// Mirror of Stream::vested_at
+    fn vested_at(&self, now: i64) -> u64 {
+        let elapsed = u64::try_from(now.saturating_sub(self.start_time)).unwrap_or(0);
+        self.rate_per_second.saturating_mul(elapsed).min(self.deposited)
+    }
+
+    /// Mirror of Stream::claimable_at
+    fn claimable_at(&self, now: i64) -> u64 {
+        self.vested_at(now).saturating_sub(self.claimed)
+    }
+
+    /// Mirror of ClaimStream's `address = stream.mint` constraint and
+    /// ClaimStream::claim_stream, returning the amount paid
+    fn claim_stream(&mut self, mint: [u8; 32], now: i64) -> Result<u64, &'static str> {
+        if mint != self.mint {
+            return Err("MintMismatch");
+        }
+        let amount = self.claimable_at(now);
+        if amount == 0 {
+            return Err("NothingVested");
+        }
+        self.claimed = self.claimed.checked_add(amount).ok_or("ArithmeticOverflow")?;
+        Ok(amount)
+    }
+}
+
+/// Mirror of WithdrawToStream::withdraw_to_stream
+fn withdraw_to_stream(amount: u64, rate_per_second: u64, now: i64) -> Result<Stream, &'static str> {
+    if amount == 0 || rate_per_second == 0 {
+        return Err("InvalidStreamParams");
+    }
+    Ok(Stream { mint: REAL_MINT, rate_per_second, start_time: now, deposited: amount, claimed: 0 })
+}
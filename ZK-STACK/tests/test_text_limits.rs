@@ -0,0 +1,80 @@
+fn main() {
+    println!("Testing Configurable Title and Description Limits");
+
+    let config = MockGlobalConfig { max_title_len: 30, max_desc_len: 120 };
+
+    // Test case: At the soft limits
+    println!("\nTest 1: A title and description exactly at the soft limits should be accepted");
+    if validate_campaign_text(&config, &"t".repeat(30), &"d".repeat(120)).is_ok() {
+        println!("✅ Test 1 passed: 30-byte title and 120-byte description accepted");
+    } else {
+        panic!("Test 1 failed: Text at the soft limits rejected");
+    }
+
+    // Test case: One over the soft limits
+    println!("\nTest 2: One byte over a soft limit should be rejected");
+    match validate_campaign_text(&config, &"t".repeat(31), "ok") {
+        Err(e) if e == "TitleTooLong" => {}
+        other => panic!("Test 2 failed: Expected TitleTooLong, got {:?}", other),
+    }
+    match validate_campaign_text(&config, "ok", &"d".repeat(121)) {
+        Err(e) if e == "DescriptionTooLong" => {}
+        other => panic!("Test 2 failed: Expected DescriptionTooLong, got {:?}", other),
+    }
+    println!("✅ Test 2 passed: 31-byte title and 121-byte description rejected");
+
+    // Test case: Unset limits fall back to the hard limits
+    println!("\nTest 3: Zero soft limits should fall back to the hard #[max_len] values");
+    let unset = MockGlobalConfig { max_title_len: 0, max_desc_len: 0 };
+    if validate_campaign_text(&unset, &"t".repeat(MAX_TITLE_LEN), &"d".repeat(MAX_DESCRIPTION_LEN)).is_ok()
+        && validate_campaign_text(&unset, &"t".repeat(MAX_TITLE_LEN + 1), "ok").is_err()
+    {
+        println!("✅ Test 3 passed: Hard limits of 50 and 200 apply");
+    } else {
+        panic!("Test 3 failed: Hard limits not applied");
+    }
+
+    // Test case: Soft limits above the hard limits
+    println!("\nTest 4: Soft limits above the hard limits should be rejected by update_global_config");
+    let cases = [(50u16, 200u16, true), (51, 200, false), (50, 201, false), (10, 10, true)];
+    for (max_title_len, max_desc_len, expected) in cases.iter() {
+        if valid_text_limits(*max_title_len, *max_desc_len) != *expected {
+            panic!("Test 4 failed: ({}, {}) expected valid={}", max_title_len, max_desc_len, expected);
+        }
+    }
+    println!("✅ Test 4 passed: Soft limits can only tighten the hard limits");
+
+    println!("\n✅✅✅ All Text Limit tests passed! ✅✅✅");
+}
+
+const MAX_TITLE_LEN: usize = 50;
+const MAX_DESCRIPTION_LEN: usize = 200;
+
+struct MockGlobalConfig {
+    max_title_len: u16,
+    max_desc_len: u16,
+}
+
+fn effective_limit(soft_limit: u16, hard_limit: usize) -> usize {
+    if soft_limit == 0 {
+        hard_limit
+    } else {
+        usize::from(soft_limit).min(hard_limit)
+    }
+}
+
+/// Mirror of GlobalConfig::validate_campaign_text
+fn validate_campaign_text(config: &MockGlobalConfig, title: &str, description: &str) -> Result<(), String> {
+    if title.len() > effective_limit(config.max_title_len, MAX_TITLE_LEN) {
+        return Err("TitleTooLong".into());
+    }
+    if description.len() > effective_limit(config.max_desc_len, MAX_DESCRIPTION_LEN) {
+        return Err("DescriptionTooLong".into());
+    }
+    Ok(())
+}
+
+/// Mirror of GlobalConfig::valid_text_limits
+fn valid_text_limits(max_title_len: u16, max_desc_len: u16) -> bool {
+    usize::from(max_title_len) <= MAX_TITLE_LEN && usize::from(max_desc_len) <= MAX_DESCRIPTION_LEN
+}
@@ -0,0 +1,86 @@
+fn main() {
+    println!("Testing Tree Creation Error Context in init_campaign");
+
+    // Test case: Undersized tree account
+    println!("\nTest 1: An undersized tree account should fail with TreeCreationFailed before the CPI");
+    let mut logs = Vec::new();
+    let tree = MockTreeAccount { data_len: 10_000, lamports: 1_000_000 };
+    let mut cpi_calls = 0;
+    match init_campaign_tree(&tree, 20, 64, &mut logs, &mut cpi_calls, Ok(())) {
+        Err(e) if e == "TreeCreationFailed" && cpi_calls == 0 && logs.iter().any(|l| l.contains("needs 44280")) => {
+            println!("✅ Test 1 passed: Rejected with {} and logged the required size", e)
+        }
+        other => panic!("Test 1 failed: Unexpected result {:?} / logs {:?}", other, logs),
+    }
+
+    // Test case: CPI failure mapped with context
+    println!("\nTest 2: A failing create_tree CPI should map to TreeCreationFailed with sizes logged");
+    let mut logs = Vec::new();
+    let tree = MockTreeAccount { data_len: 44_280, lamports: 0 };
+    let mut cpi_calls = 0;
+    match init_campaign_tree(&tree, 20, 64, &mut logs, &mut cpi_calls, Err("AccountNotRentExempt")) {
+        Err(e) if e == "TreeCreationFailed"
+            && cpi_calls == 1
+            && logs.iter().any(|l| l.contains("max_depth 20") && l.contains("AccountNotRentExempt")) =>
+        {
+            println!("✅ Test 2 passed: CPI error mapped and logged with context")
+        }
+        other => panic!("Test 2 failed: Unexpected result {:?} / logs {:?}", other, logs),
+    }
+
+    // Test case: Correctly sized tree
+    println!("\nTest 3: A correctly sized tree should be created");
+    let mut logs = Vec::new();
+    let mut cpi_calls = 0;
+    if init_campaign_tree(&tree, 20, 64, &mut logs, &mut cpi_calls, Ok(())).is_ok() && cpi_calls == 1 {
+        println!("✅ Test 3 passed: Tree created");
+    } else {
+        panic!("Test 3 failed: Correctly sized tree rejected");
+    }
+
+    println!("\n✅✅✅ All Tree Creation Error tests passed! ✅✅✅");
+}
+
+struct MockTreeAccount {
+    data_len: usize,
+    lamports: u64,
+}
+
+/// Stand-in for create_campaign_tree_account::tree_account_size; only the
+/// comparison against the tree account's length matters here, not the exact layout
+fn tree_account_size(max_depth: u32, max_buffer_size: u32) -> Result<usize, String> {
+    if ![14, 20, 24, 26, 30].contains(&max_depth) || ![64, 256, 1024, 2048].contains(&max_buffer_size) {
+        return Err("UnsupportedTreeConfig".into());
+    }
+    let path_size = 32 + 32 * max_depth as usize + 8;
+    Ok(56 + 3 * 8 + max_buffer_size as usize * path_size + path_size)
+}
+
+/// Mirror of the tree creation step in init_campaign; `cpi_result` stands in for create_tree
+fn init_campaign_tree(
+    tree: &MockTreeAccount,
+    max_depth: u32,
+    max_buffer_size: u32,
+    logs: &mut Vec<String>,
+    cpi_calls: &mut u32,
+    cpi_result: Result<(), &str>,
+) -> Result<(), String> {
+    if let Ok(required_len) = tree_account_size(max_depth, max_buffer_size) {
+        if tree.data_len < required_len {
+            logs.push(format!(
+                "Merkle tree is {} bytes, but max_depth {} / max_buffer_size {} needs {}",
+                tree.data_len, max_depth, max_buffer_size, required_len
+            ));
+            return Err("TreeCreationFailed".into());
+        }
+    }
+
+    *cpi_calls += 1;
+    cpi_result.map_err(|e| {
+        logs.push(format!(
+            "Error creating Merkle tree ({} bytes, {} lamports) with max_depth {} / max_buffer_size {}: {:?}",
+            tree.data_len, tree.lamports, max_depth, max_buffer_size, e
+        ));
+        "TreeCreationFailed".to_string()
+    })
+}
@@ -0,0 +1,61 @@
+fn main() {
+    println!("Testing Whole-Token Donation Setting");
+
+    let whole_only = MockCampaign { mint_decimals: 6, require_whole_tokens: true };
+    let any_amount = MockCampaign { mint_decimals: 6, require_whole_tokens: false };
+
+    // Test case: Whole amount
+    println!("\nTest 1: A whole-token amount should be accepted when whole tokens are required");
+    match donate_amount(&whole_only, 3_000_000) {
+        Ok(3_000_000) => println!("✅ Test 1 passed: 3 tokens accepted"),
+        other => panic!("Test 1 failed: Unexpected result {:?}", other),
+    }
+
+    // Test case: Fractional amount
+    println!("\nTest 2: A fractional amount should be rejected when whole tokens are required");
+    match donate_amount(&whole_only, 2_500_000) {
+        Err(e) if e == "NonWholeTokenDonation" => println!("✅ Test 2 passed: Rejected with {}", e),
+        other => panic!("Test 2 failed: Unexpected result {:?}", other),
+    }
+
+    // Test case: Setting disabled
+    println!("\nTest 3: Fractional amounts should be accepted when the setting is off");
+    match donate_amount(&any_amount, 2_500_001) {
+        Ok(_) => println!("✅ Test 3 passed: Arbitrary precision preserved"),
+        other => panic!("Test 3 failed: Unexpected result {:?}", other),
+    }
+
+    // Test case: Zero-decimal mint and oversized decimals
+    println!("\nTest 4: Edge-case decimals should not overflow");
+    let zero_decimals = MockCampaign { mint_decimals: 0, require_whole_tokens: true };
+    let huge_decimals = MockCampaign { mint_decimals: 20, require_whole_tokens: true };
+    if donate_amount(&zero_decimals, 7).is_ok() && donate_amount(&huge_decimals, u64::MAX).is_err() {
+        println!("✅ Test 4 passed: 0 and 20 decimals handled");
+    } else {
+        panic!("Test 4 failed: Edge-case decimals handled incorrectly");
+    }
+
+    println!("\n✅✅✅ All Whole-Token Donation tests passed! ✅✅✅");
+}
+
+struct MockCampaign {
+    mint_decimals: u8,
+    require_whole_tokens: bool,
+}
+
+/// Mirror of CampaignInfo::accepts_amount
+fn accepts_amount(campaign: &MockCampaign, amount: u64) -> bool {
+    if !campaign.require_whole_tokens {
+        return true;
+    }
+    10u64
+        .checked_pow(u32::from(campaign.mint_decimals))
+        .map_or(amount == 0, |one_token| amount.is_multiple_of(one_token))
+}
+
+fn donate_amount(campaign: &MockCampaign, amount: u64) -> Result<u64, String> {
+    if !accepts_amount(campaign, amount) {
+        return Err("NonWholeTokenDonation".into());
+    }
+    Ok(amount)
+}
@@ -0,0 +1,182 @@
+fn main() {
+    println!("Testing zk_donations Campaign Totals");
+
+    // Test case: Goal and deadline stored at initialization
+    println!("\nTest 1: initialize_campaign should store goal and deadline with nothing raised");
+    let mut campaign = Campaign::initialize(50_000, 1_800_000_000);
+    if campaign.goal_amount == 50_000 && campaign.deadline == 1_800_000_000 && campaign.total_raised == 0 {
+        println!("✅ Test 1 passed: Goal 50000, deadline stored, total 0");
+    } else {
+        panic!("Test 1 failed: Unexpected campaign {:?}", campaign);
+    }
+
+    // Test case: Totals accumulate across donations
+    println!("\nTest 2: total_raised should accumulate the amounts parsed from each leaf");
+    let mut donor_tokens = 10_000u64;
+    for amount in [1_000u64, 2_500, 750] {
+        campaign.donate_compressed_amount(&mut donor_tokens, &leaf(amount), NOW).unwrap();
+    }
+    if campaign.total_raised == 4_250 {
+        println!("✅ Test 2 passed: 1000 + 2500 + 750 = 4250");
+    } else {
+        panic!("Test 2 failed: Expected 4250, got {}", campaign.total_raised);
+    }
+
+    // Test case: Short leaves rejected
+    println!("\nTest 3: A leaf too short to hold an amount should be rejected");
+    match campaign.donate_compressed_amount(&mut donor_tokens, &[1, 2, 3], NOW) {
+        Err("InvalidLeafData") if campaign.total_raised == 4_250 => {
+            println!("✅ Test 3 passed: Rejected with InvalidLeafData, total unchanged")
+        }
+        other => panic!("Test 3 failed: Expected InvalidLeafData, got {:?}", other),
+    }
+
+    // Test case: Overflow
+    println!("\nTest 4: A total past u64::MAX should be rejected");
+    campaign.total_raised = u64::MAX - 10;
+    match campaign.donate_compressed_amount(&mut donor_tokens, &leaf(11), NOW) {
+        Err("ArithmeticOverflow") => println!("✅ Test 4 passed: Overflow rejected"),
+        other => panic!("Test 4 failed: Expected ArithmeticOverflow, got {:?}", other),
+    }
+
+    // Test case: Account size
+    println!("\nTest 5: Campaign::LEN should grow by the mint and the three new 8-byte fields");
+    if Campaign::LEN == 371 + 32 + 24 {
+        println!("✅ Test 5 passed: LEN is {}", Campaign::LEN);
+    } else {
+        panic!("Test 5 failed: LEN is {}", Campaign::LEN);
+    }
+
+    // Test case: Only queued leaves count
+    println!("\nTest 6: total_raised should equal the sum of the queued leaves and the tokens received");
+    let mut campaign = Campaign::initialize(0, 0);
+    let mut donor_tokens = 1_000u64;
+    campaign.donate_compressed_amount(&mut donor_tokens, &leaf(300), NOW).unwrap();
+    campaign.donate_compressed_amount(&mut donor_tokens, &leaf(200), NOW).unwrap();
+    let _ = campaign.donate_compressed_amount(&mut donor_tokens, &[9], NOW);
+    let queued_total: u64 = campaign.queued_leaves.iter().map(|leaf| leaf_amount(leaf).unwrap()).sum();
+    if campaign.queued_leaves.len() == 2 && campaign.total_raised == queued_total && campaign.vault_balance == queued_total {
+        println!("✅ Test 6 passed: 2 leaves queued, total {} matches them and the vault", queued_total);
+    } else {
+        panic!("Test 6 failed: {} leaves queued, total {}", campaign.queued_leaves.len(), campaign.total_raised);
+    }
+
+    // Test case: Any donor
+    println!("\nTest 7: A donor other than the campaign authority should be able to donate");
+    let mut other_donor_tokens = 1_000u64;
+    match campaign.donate_compressed_amount(&mut other_donor_tokens, &leaf(1_000), NOW) {
+        Ok(()) if campaign.total_raised == 1_500 && campaign.vault_balance == 1_500 && other_donor_tokens == 0 => {
+            println!("✅ Test 7 passed: Donation of 1000 transferred and counted")
+        }
+        other => panic!("Test 7 failed: Unexpected result {:?}, total {}", other, campaign.total_raised),
+    }
+
+    // Test case: Queued leaf hashes are field elements
+    println!("\nTest 8: Queued leaf hashes should be reduced into the BN254 field");
+    let raw_hash = [0xffu8; 32];
+    if !below_bn254_modulus(&raw_hash) && below_bn254_modulus(&to_bn254_field_size(raw_hash)) {
+        println!("✅ Test 8 passed: A raw hash above the modulus is truncated into the field");
+    } else {
+        panic!("Test 8 failed: Truncated hash is not a field element");
+    }
+
+    // Test case: Leaf claiming more than the donor holds
+    println!("\nTest 9: A leaf claiming more than the donor's balance should be rejected");
+    let mut donor_tokens = 999u64;
+    match campaign.donate_compressed_amount(&mut donor_tokens, &leaf(1_000_000), NOW) {
+        Err("InsufficientDonorBalance") if campaign.total_raised == 1_500 && campaign.queued_leaves.len() == 3 && donor_tokens == 999 => {
+            println!("✅ Test 9 passed: Rejected with InsufficientDonorBalance, counters untouched")
+        }
+        other => panic!("Test 9 failed: Expected InsufficientDonorBalance, got {:?}", other),
+    }
+
+    // Test case: Deadline
+    println!("\nTest 10: Donations should be accepted up to the deadline and rejected after it");
+    let mut campaign = Campaign::initialize(0, NOW);
+    let mut donor_tokens = 1_000u64;
+    let at_deadline = campaign.donate_compressed_amount(&mut donor_tokens, &leaf(100), NOW);
+    match campaign.donate_compressed_amount(&mut donor_tokens, &leaf(100), NOW + 1) {
+        Err("CampaignEnded") if at_deadline.is_ok() && campaign.total_raised == 100 && donor_tokens == 900 => {
+            println!("✅ Test 10 passed: Donation at the deadline counted, one after it rejected with CampaignEnded")
+        }
+        other => panic!("Test 10 failed: Expected CampaignEnded, got {:?}", other),
+    }
+
+    println!("\n✅✅✅ All zk_donations Total Raised tests passed! ✅✅✅");
+}
+
+const NOW: i64 = 1_700_000_000;
+
+/// Leaf in DonationLeaf layout: version, amount, commitment, timestamp, campaign_id
+fn leaf(amount: u64) -> Vec<u8> {
+    let mut data = vec![1u8];
+    data.extend_from_slice(&amount.to_le_bytes());
+    data.extend_from_slice(&[0u8; 32]);
+    data.extend_from_slice(&0i64.to_le_bytes());
+    data.extend_from_slice(&7u64.to_le_bytes());
+    data
+}
+
+/// BN254 scalar field modulus, big-endian
+const BN254_MODULUS: [u8; 32] = [
+    0x30, 0x64, 0x4e, 0x72, 0xe1, 0x31, 0xa0, 0x29, 0xb8, 0x50, 0x45, 0xb6, 0x81, 0x81, 0x58, 0x5d,
+    0x28, 0x33, 0xe8, 0x48, 0x79, 0xb9, 0x70, 0x91, 0x43, 0xe1, 0xf5, 0x93, 0xf0, 0x00, 0x00, 0x01,
+];
+
+fn below_bn254_modulus(value: &[u8; 32]) -> bool {
+    value < &BN254_MODULUS
+}
+
+/// Mirror of the truncation hashv_to_bn254_field_size_be applies to its keccak
+/// output: clearing the top byte keeps the value below 2^248, inside the field
+fn to_bn254_field_size(mut hash: [u8; 32]) -> [u8; 32] {
+    hash[0] = 0;
+    hash
+}
+
+/// Mirror of zk_donations::leaf_amount
+fn leaf_amount(leaf_data: &[u8]) -> Result<u64, &'static str> {
+    let amount_bytes = leaf_data.get(1..9).ok_or("InvalidLeafData")?;
+    let mut amount = [0u8; 8];
+    amount.copy_from_slice(amount_bytes);
+    Ok(u64::from_le_bytes(amount))
+}
+
+/// Mirror of the zk_donations Campaign fields touched here
+#[derive(Debug)]
+struct Campaign {
+    goal_amount: u64,
+    deadline: i64,
+    total_raised: u64,
+    // Balance of the campaign creator's token account
+    vault_balance: u64,
+    // Leaves appended to the output queue, in order
+    queued_leaves: Vec<Vec<u8>>,
+}
+
+impl Campaign {
+    const LEN: usize = 8 + 32 + 8 + 4 + 50 + 4 + 200 + 32 + 32 + 1 + 32 + 8 + 8 + 8;
+
+    fn initialize(goal_amount: u64, deadline: i64) -> Self {
+        Self { goal_amount, deadline, total_raised: 0, vault_balance: 0, queued_leaves: Vec::new() }
+    }
+
+    /// Mirror of donate_compressed_amount: check the deadline, transfer the leaf's
+    /// amount in (DonateCompressedAmount::lock_donated_tokens), queue the leaf,
+    /// then count it. Errors leave everything untouched, as a failed transaction would
+    fn donate_compressed_amount(&mut self, donor_tokens: &mut u64, leaf_data: &[u8], now: i64) -> Result<(), &'static str> {
+        if self.deadline != 0 && now > self.deadline {
+            return Err("CampaignEnded");
+        }
+        let amount = leaf_amount(leaf_data)?;
+        if *donor_tokens < amount {
+            return Err("InsufficientDonorBalance");
+        }
+        let total_raised = self.total_raised.checked_add(amount).ok_or("ArithmeticOverflow")?;
+        *donor_tokens -= amount;
+        self.vault_balance += amount;
+        self.queued_leaves.push(leaf_data.to_vec());
+        self.total_raised = total_raised;
+        Ok(())
+    }
+}
@@ -0,0 +1,413 @@
+//! Typed instruction builders and event decoders for off-chain Rust clients and integration tests
+//!
+//! Each `*_ix` function derives the PDAs an instruction expects, assembles the
+//! full `AccountMeta` list in the order Anchor validates it, and serializes the
+//! arguments behind the instruction's discriminator. The `parse_*` functions
+//! decode events back out of transaction log lines.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::{ed25519_program, sysvar};
+use anchor_lang::{system_program, Discriminator, InstructionData, ToAccountMetas};
+use anchor_spl::associated_token::{self, get_associated_token_address};
+use anchor_spl::token;
+use base64::{engine::general_purpose::STANDARD, Engine};
+
+use crate::instructions::{
+    DonateCompressed, DonateCompressedResult, DonationProcessedEvent, DonationReceivedEvent, InitCampaignParams,
+    UpdateCommitment,
+};
+use crate::util::canonical_title;
+
+mod light_programs {
+    use anchor_lang::declare_id;
+    declare_id!("compr6CUsB5m2jS4Y3831ztGSTnDpnKJTKS95d64XVq");
+}
+
+/// Campaign PDA for `(campaign_id, title)`
+pub fn campaign_pda(campaign_id: u64, title: &str) -> Pubkey {
+    Pubkey::find_program_address(&[campaign_id.to_le_bytes().as_ref(), canonical_title(title).as_bytes()], &crate::ID).0
+}
+
+/// Doner PDA for a donor's account on a campaign
+pub fn doner_pda(campaign: &Pubkey, doner: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"doner", campaign.as_ref(), doner.as_ref()], &crate::ID).0
+}
+
+pub fn gift_pda(campaign: &Pubkey, donor: &Pubkey, gift_id: u64) -> Pubkey {
+    Pubkey::find_program_address(&[b"gift", campaign.as_ref(), donor.as_ref(), &gift_id.to_le_bytes()], &crate::ID).0
+}
+
+pub fn commitment_owner_pda(donor_commitment: &[u8; 32]) -> Pubkey {
+    Pubkey::find_program_address(&[b"commitment", donor_commitment], &crate::ID).0
+}
+
+pub fn kyc_attestation_pda(donor: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"kyc_attestation", donor.as_ref()], &crate::ID).0
+}
+
+pub fn donor_stats_pda(donor: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"donor_stats", donor.as_ref()], &crate::ID).0
+}
+
+pub fn recent_donations_pda(campaign: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"recent", campaign.as_ref()], &crate::ID).0
+}
+
+pub fn protocol_stats_pda() -> Pubkey {
+    Pubkey::find_program_address(&[b"protocol_stats"], &crate::ID).0
+}
+
+pub fn global_config_pda() -> Pubkey {
+    Pubkey::find_program_address(&[b"global_config"], &crate::ID).0
+}
+
+pub fn creator_stats_pda(creator: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"creator_stats", creator.as_ref()], &crate::ID).0
+}
+
+pub fn creator_verification_pda(creator: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"creator_verification", creator.as_ref()], &crate::ID).0
+}
+
+/// Link to the creator's `index`-th campaign, for `index` in `0..CreatorStats::campaign_count`
+pub fn creator_campaign_link_pda(creator: &Pubkey, index: u64) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"creator_campaign", creator.as_ref(), index.to_le_bytes().as_ref()],
+        &crate::ID,
+    )
+    .0
+}
+
+/// Campaign lookup PDA for a Merkle tree, fetchable by event processors that only see the tree
+pub fn tree_campaign_link_pda(merkle_tree: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"tree_link", merkle_tree.as_ref()], &crate::ID).0
+}
+
+pub fn init_global_config_ix(admin: Pubkey, clock_skew_seconds: u64) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: crate::accounts::InitGlobalConfig {
+            admin,
+            global_config: global_config_pda(),
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        data: crate::instruction::InitGlobalConfig { clock_skew_seconds }.data(),
+    }
+}
+
+pub fn create_campaign_tree_account_ix(
+    payer: Pubkey,
+    merkle_tree: Pubkey,
+    max_depth: u32,
+    max_buffer_size: u32,
+) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: crate::accounts::CreateCampaignTreeAccount {
+            payer,
+            merkle_tree,
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        data: crate::instruction::CreateCampaignTreeAccount { max_depth, max_buffer_size }.data(),
+    }
+}
+
+/// `payer` funds rent for the accounts created and may be `creator` itself.
+/// `creator_campaign_count` is the creator's current CreatorStats::campaign_count
+/// (0 before their first campaign), which indexes the new CreatorCampaignLink.
+/// Set `creator_verified` when the creator holds a CreatorVerification, required while
+/// the global config's require_verified_creators is set
+pub fn init_campaign_ix(
+    creator: Pubkey,
+    payer: Pubkey,
+    mint: Pubkey,
+    merkle_tree: Pubkey,
+    output_queue: Option<Pubkey>,
+    creator_verified: bool,
+    creator_campaign_count: u64,
+    campaign_id: u64,
+    title: String,
+    params: InitCampaignParams,
+) -> Instruction {
+    let campaign = campaign_pda(campaign_id, &title);
+    Instruction {
+        program_id: crate::ID,
+        accounts: crate::accounts::InitializeCampaign {
+            creator,
+            payer,
+            mint,
+            campaign_account_info: campaign,
+            campaign_token_account: get_associated_token_address(&creator, &mint),
+            protocol_stats: protocol_stats_pda(),
+            global_config: global_config_pda(),
+            creator_stats: creator_stats_pda(&creator),
+            creator_campaign_link: creator_campaign_link_pda(&creator, creator_campaign_count),
+            tree_campaign_link: tree_campaign_link_pda(&merkle_tree),
+            creator_verification: creator_verified.then(|| creator_verification_pda(&creator)),
+            merkle_tree,
+            output_queue,
+            light_account_compression_program: light_programs::ID,
+            token_program: token::ID,
+            system_program: system_program::ID,
+            associated_token_program: associated_token::ID,
+        }
+        .to_account_metas(None),
+        data: crate::instruction::InitCampaign { campaign_id, title, params }.data(),
+    }
+}
+
+pub fn init_doner_ix(doner: Pubkey, campaign: Pubkey) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: crate::accounts::InitDoner {
+            doner,
+            campaign_account_info: campaign,
+            doner_account_info: doner_pda(&campaign, &doner),
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        data: crate::instruction::InitDoner { campaign }.data(),
+    }
+}
+
+/// `creator` is the campaign creator, whose associated token account receives the donation.
+/// Set `with_kyc_attestation` when the amount is above the campaign's kyc_threshold: the
+/// credited donor's attestation is passed, and the signer's too when donating on someone
+/// else's behalf. Pass `gate_token_account` for token-gated campaigns, and the campaign's
+/// `platform_referrer` when it charges a platform fee.
+pub fn donate_amount_ix(
+    doner: Pubkey,
+    creator: Pubkey,
+    mint: Pubkey,
+    campaign_id: u64,
+    title: String,
+    donation_amount: u64,
+    beneficiary_donor: Option<Pubkey>,
+    gift_id: Option<u64>,
+    with_kyc_attestation: bool,
+    gate_token_account: Option<Pubkey>,
+    platform_referrer: Option<Pubkey>,
+) -> Instruction {
+    let campaign = campaign_pda(campaign_id, &title);
+    let credited_donor = beneficiary_donor.unwrap_or(doner);
+    Instruction {
+        program_id: crate::ID,
+        accounts: crate::accounts::DonateAmount {
+            doner,
+            mint,
+            campaign_account_info: campaign,
+            doner_token_account: get_associated_token_address(&doner, &mint),
+            campaign_token_account: get_associated_token_address(&creator, &mint),
+            doner_account_info: doner_pda(&campaign, &credited_donor),
+            protocol_stats: protocol_stats_pda(),
+            global_config: global_config_pda(),
+            donor_stats: donor_stats_pda(&credited_donor),
+            recent_donations: recent_donations_pda(&campaign),
+            kyc_attestation: with_kyc_attestation.then(|| kyc_attestation_pda(&credited_donor)),
+            payer_kyc_attestation: (with_kyc_attestation && credited_donor != doner)
+                .then(|| kyc_attestation_pda(&doner)),
+            gate_token_account,
+            platform_token_account: platform_referrer.map(|referrer| get_associated_token_address(&referrer, &mint)),
+            gift: gift_id.map(|gift_id| gift_pda(&campaign, &credited_donor, gift_id)),
+            token_program: token::ID,
+            system_program: system_program::ID,
+            associated_token_program: associated_token::ID,
+        }
+        .to_account_metas(None),
+        data: crate::instruction::DonateAmount {
+            campaign_id,
+            title,
+            donation_amount,
+            beneficiary_donor,
+            gift_id,
+        }
+        .data(),
+    }
+}
+
+/// Single-instruction donation for a donor without a doner account on the campaign yet
+pub fn donate_first_time_ix(
+    doner: Pubkey,
+    creator: Pubkey,
+    mint: Pubkey,
+    campaign_id: u64,
+    title: String,
+    donation_amount: u64,
+    with_kyc_attestation: bool,
+    gate_token_account: Option<Pubkey>,
+    platform_referrer: Option<Pubkey>,
+) -> Instruction {
+    let campaign = campaign_pda(campaign_id, &title);
+    Instruction {
+        program_id: crate::ID,
+        accounts: crate::accounts::DonateFirstTime {
+            doner,
+            mint,
+            campaign_account_info: campaign,
+            doner_token_account: get_associated_token_address(&doner, &mint),
+            campaign_token_account: get_associated_token_address(&creator, &mint),
+            doner_account_info: doner_pda(&campaign, &doner),
+            protocol_stats: protocol_stats_pda(),
+            global_config: global_config_pda(),
+            donor_stats: donor_stats_pda(&doner),
+            recent_donations: recent_donations_pda(&campaign),
+            kyc_attestation: with_kyc_attestation.then(|| kyc_attestation_pda(&doner)),
+            gate_token_account,
+            platform_token_account: platform_referrer.map(|referrer| get_associated_token_address(&referrer, &mint)),
+            token_program: token::ID,
+            system_program: system_program::ID,
+            associated_token_program: associated_token::ID,
+        }
+        .to_account_metas(None),
+        data: crate::instruction::DonateFirstTime {
+            _campaign_id: campaign_id,
+            _title: title,
+            donation_amount,
+        }
+        .data(),
+    }
+}
+
+/// Ed25519 program instruction carrying the donor's `signature` over
+/// `DonateCompressed::commitment_binding_message`; place it immediately before `donate_compressed_ix`
+/// with the same `recent_slot`
+pub fn commitment_binding_ix(donor: Pubkey, signature: [u8; 64], donor_commitment: [u8; 32], recent_slot: u64) -> Instruction {
+    let message = DonateCompressed::commitment_binding_message(&donor, &donor_commitment, recent_slot);
+    ed25519_ix(donor, signature, &message)
+}
+
+/// Ed25519 program instruction carrying the donor's `signature` over
+/// `UpdateCommitment::rotation_message`; place it immediately before `update_commitment_ix`
+pub fn commitment_rotation_ix(
+    donor: Pubkey,
+    signature: [u8; 64],
+    old_commitment: [u8; 32],
+    new_commitment: [u8; 32],
+) -> Instruction {
+    let message = UpdateCommitment::rotation_message(&donor, &old_commitment, &new_commitment);
+    ed25519_ix(donor, signature, &message)
+}
+
+pub fn update_commitment_ix(donor: Pubkey, new_commitment: [u8; 32]) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: crate::accounts::UpdateCommitment {
+            donor,
+            donor_stats: donor_stats_pda(&donor),
+            new_commitment_owner: commitment_owner_pda(&new_commitment),
+            instructions_sysvar: sysvar::instructions::ID,
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        data: crate::instruction::UpdateCommitment { new_commitment }.data(),
+    }
+}
+
+/// Single-signature ed25519 program instruction by `signer` over `message`
+fn ed25519_ix(signer: Pubkey, signature: [u8; 64], message: &[u8]) -> Instruction {
+    const PUBLIC_KEY_OFFSET: u16 = 16;
+    const SIGNATURE_OFFSET: u16 = PUBLIC_KEY_OFFSET + 32;
+    const MESSAGE_OFFSET: u16 = SIGNATURE_OFFSET + 64;
+
+    // One signature, with every offset pointing into this instruction (index u16::MAX)
+    let mut data = vec![1u8, 0u8];
+    for field in [
+        SIGNATURE_OFFSET,
+        u16::MAX,
+        PUBLIC_KEY_OFFSET,
+        u16::MAX,
+        MESSAGE_OFFSET,
+        message.len() as u16,
+        u16::MAX,
+    ] {
+        data.extend_from_slice(&field.to_le_bytes());
+    }
+    data.extend_from_slice(signer.as_ref());
+    data.extend_from_slice(&signature);
+    data.extend_from_slice(message);
+
+    Instruction {
+        program_id: ed25519_program::ID,
+        accounts: vec![],
+        data,
+    }
+}
+
+/// `creator` and `mint` locate the token accounts the proof's claimed amount moves between;
+/// `donor_commitment` must be the commitment inside `proof_data`
+pub fn donate_compressed_ix(
+    donor: Pubkey,
+    creator: Pubkey,
+    mint: Pubkey,
+    merkle_tree: Pubkey,
+    output_queue: Option<Pubkey>,
+    campaign_id: u64,
+    title: String,
+    proof_data: Vec<u8>,
+    donor_commitment: [u8; 32],
+    recent_slot: u64,
+) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: crate::accounts::DonateCompressed {
+            donor,
+            campaign_account_info: campaign_pda(campaign_id, &title),
+            mint,
+            donor_token_account: get_associated_token_address(&donor, &mint),
+            campaign_token_account: get_associated_token_address(&creator, &mint),
+            merkle_tree,
+            output_queue,
+            protocol_stats: protocol_stats_pda(),
+            global_config: global_config_pda(),
+            donor_stats: donor_stats_pda(&donor),
+            commitment_owner: commitment_owner_pda(&donor_commitment),
+            instructions_sysvar: sysvar::instructions::ID,
+            light_account_compression_program: light_programs::ID,
+            token_program: token::ID,
+            system_program: system_program::ID,
+            associated_token_program: associated_token::ID,
+        }
+        .to_account_metas(None),
+        data: crate::instruction::DonateCompressed {
+            campaign_id,
+            title,
+            proof_data,
+            recent_slot,
+        }
+        .data(),
+    }
+}
+
+/// Decode a `DonationProcessedEvent` (compressed donation) from a `Program data:` log line
+///
+/// Returns `None` for any other log line, including other events.
+pub fn parse_donation_event(log: &str) -> Option<DonationProcessedEvent> {
+    parse_event(log)
+}
+
+/// Decode a `DonationReceivedEvent` (token donation) from a `Program data:` log line
+pub fn parse_donation_received_event(log: &str) -> Option<DonationReceivedEvent> {
+    parse_event(log)
+}
+
+/// Decode the `DonateCompressedResult` a (simulated) donate_compressed leaves in its return data
+///
+/// `program_id` and `data` are the transaction's `returnData`; data set by any
+/// other program yields `None`.
+pub fn parse_donate_compressed_result(program_id: &Pubkey, data: &[u8]) -> Option<DonateCompressedResult> {
+    if *program_id != crate::ID {
+        return None;
+    }
+    DonateCompressedResult::try_from_slice(data).ok()
+}
+
+/// Base64-decode the log payload, match the event discriminator, then Borsh-deserialize the rest
+fn parse_event<T: Discriminator + AnchorDeserialize>(log: &str) -> Option<T> {
+    let encoded = log.trim().strip_prefix("Program data: ")?;
+    let data = STANDARD.decode(encoded).ok()?;
+    let mut payload = data.strip_prefix(T::DISCRIMINATOR)?;
+    T::deserialize(&mut payload).ok()
+}
@@ -0,0 +1,58 @@
+/// Donor tiers, from no tier up to Gold
+pub const TIER_NONE: u8 = 0;
+pub const TIER_BRONZE: u8 = 1;
+pub const TIER_SILVER: u8 = 2;
+pub const TIER_GOLD: u8 = 3;
+
+/// Hard limits on campaign title and description length, matching their `#[max_len]`
+pub const MAX_TITLE_LEN: usize = 50;
+pub const MAX_DESCRIPTION_LEN: usize = 200;
+
+/// Maximum length of the off-chain metadata URI stored on a campaign
+pub const MAX_URI_LEN: usize = 200;
+
+/// Maximum length of the display symbol stored on a campaign
+pub const MAX_SYMBOL_LEN: usize = 10;
+
+/// Maximum number of search keywords on a campaign, and the length of each
+pub const MAX_KEYWORDS: usize = 5;
+pub const MAX_KEYWORD_LEN: usize = 20;
+
+/// Basis points in 100%
+pub const BPS_DENOMINATOR: u64 = 10_000;
+
+/// Reason codes recorded when the admin pauses the program
+pub const PAUSE_REASON_NONE: u8 = 0;
+pub const PAUSE_REASON_MAINTENANCE: u8 = 1;
+pub const PAUSE_REASON_SECURITY: u8 = 2;
+
+/// Reason codes reported by validate_proof, which checks proof format only
+pub const PROOF_REASON_WELL_FORMED: u8 = 0;
+pub const PROOF_REASON_EMPTY: u8 = 1;
+pub const PROOF_REASON_TOO_SHORT: u8 = 2;
+pub const PROOF_REASON_ZERO_AMOUNT: u8 = 3;
+pub const PROOF_REASON_UNSUPPORTED_VERSION: u8 = 4;
+
+/// Current donation leaf schema version, prepended to proofs and serialized leaves
+pub const LEAF_VERSION: u8 = 1;
+
+/// Length of the window over which a campaign's circuit breaker counts donations
+pub const CIRCUIT_BREAKER_WINDOW_SECONDS: i64 = 60;
+
+/// Maximum number of doner accounts init_doners_batch creates in one instruction
+pub const MAX_DONERS_BATCH: usize = 10;
+
+/// Number of donations kept in a campaign's RecentDonations feed
+pub const RECENT_DONATIONS_LEN: usize = 10;
+
+/// Slots a signed commitment binding stays valid after the recent_slot it covers, bounding replays
+pub const MAX_PROOF_SLOT_AGE: u64 = 150;
+
+/// Maximum number of sibling hashes accepted in a Merkle proof, bounding compute usage
+pub const MAX_MERKLE_PROOF_DEPTH: usize = 32;
+
+/// Merkle tree depths accepted when pre-allocating a campaign tree account
+pub const SUPPORTED_TREE_DEPTHS: [u32; 5] = [14, 20, 24, 26, 30];
+
+/// Changelog buffer sizes accepted when pre-allocating a campaign tree account
+pub const SUPPORTED_TREE_BUFFER_SIZES: [u32; 4] = [64, 256, 1024, 2048];
@@ -0,0 +1,254 @@
+use anchor_lang::prelude::*;
+
+/// Custom error codes for the heart_of_blockchain program
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Invalid proof data")]
+    InvalidProofData,
+    
+    #[msg("Invalid proof format")]
+    InvalidProofFormat,
+    
+    #[msg("Failed to update Merkle tree")]
+    MerkleTreeUpdateFailed,
+    
+    #[msg("Failed to update campaign state")]
+    CampaignUpdateFailed,
+    
+    #[msg("Arithmetic overflow")]
+    ArithmeticOverflow,
+
+    #[msg("Tier thresholds must be strictly increasing, with unused tiers set to zero at the end")]
+    InvalidTierThresholds,
+
+    #[msg("Output queue does not belong to the campaign's Merkle tree")]
+    QueueMismatch,
+
+    #[msg("Metadata URI must not be empty")]
+    EmptyUri,
+
+    #[msg("Metadata URI exceeds the maximum length")]
+    UriTooLong,
+
+    #[msg("Signer is not authorized to perform this action")]
+    Unauthorized,
+
+    #[msg("Reentrant call detected")]
+    ReentrancyDetected,
+
+    #[msg("Campaign has no goal amount configured")]
+    GoalNotConfigured,
+
+    #[msg("Percentage must be between 0 and 10000 basis points")]
+    InvalidPercentBps,
+
+    #[msg("Doner account does not belong to the credited donor")]
+    DonorAccountMismatch,
+
+    #[msg("Mint does not match the campaign's mint")]
+    MintMismatch,
+
+    #[msg("Proof timestamp is outside the allowed clock skew")]
+    ProofTimestampOutOfRange,
+
+    #[msg("Program is paused")]
+    ProgramPaused,
+
+    #[msg("Unsupported Merkle tree depth or buffer size")]
+    UnsupportedTreeConfig,
+
+    #[msg("Campaign only accepts donations in whole tokens")]
+    NonWholeTokenDonation,
+
+    #[msg("Goal and soft goal must not exceed the hard cap")]
+    InvalidGoalConfig,
+
+    #[msg("Donation would exceed the campaign hard cap")]
+    HardCapExceeded,
+
+    #[msg("Merkle tree is not controlled by the campaign")]
+    TreeNotControlledByCampaign,
+
+    #[msg("Donation above the campaign KYC threshold requires a valid attestation")]
+    KycRequired,
+
+    #[msg("Donation amount must be greater than zero")]
+    ZeroDonation,
+
+    #[msg("Campaign creator may not donate to their own campaign")]
+    SelfDonationNotAllowed,
+
+    #[msg("New deadline must be later than both the current deadline and now")]
+    InvalidDeadlineExtension,
+
+    #[msg("Campaign account already uses the current layout")]
+    AccountAlreadyUpToDate,
+
+    #[msg("Proof leaf version does not match the campaign's")]
+    LeafVersionMismatch,
+
+    #[msg("Failed to create the campaign Merkle tree")]
+    TreeCreationFailed,
+
+    #[msg("Donor does not hold the campaign's gating token")]
+    TokenGateNotSatisfied,
+
+    #[msg("Donor token balance is below the donation amount")]
+    InsufficientDonorBalance,
+
+    #[msg("Campaign has too many keywords")]
+    TooManyKeywords,
+
+    #[msg("Campaign keyword is too long")]
+    KeywordTooLong,
+
+    #[msg("Campaign token account still holds funds")]
+    FundsStillPresent,
+
+    #[msg("Proof was generated for a different campaign")]
+    ProofCampaignMismatch,
+
+    #[msg("Campaign refund pool is empty")]
+    RefundPoolEmpty,
+
+    #[msg("Donor has no refund share to claim")]
+    NothingToRefund,
+
+    #[msg("Campaign title exceeds the configured limit")]
+    TitleTooLong,
+
+    #[msg("Campaign description exceeds the configured limit")]
+    DescriptionTooLong,
+
+    #[msg("Text limits may not exceed the account's maximum lengths")]
+    InvalidTextLimits,
+
+    #[msg("Proof recent_slot is outside the allowed slot window")]
+    ProofExpired,
+
+    #[msg("Campaign creator is not verified")]
+    CreatorNotVerified,
+
+    #[msg("Campaign title is empty")]
+    EmptyTitle,
+
+    #[msg("Stream amount and rate must be nonzero")]
+    InvalidStreamParams,
+
+    #[msg("Nothing has vested on this stream")]
+    NothingVested,
+
+    #[msg("Campaign token balance did not increase by the donated amount")]
+    TransferNotConfirmed,
+
+    #[msg("Campaign has reached its maximum number of donors")]
+    MaxDonorsReached,
+
+    #[msg("Donor commitment is not bound to the signer by an ed25519 signature")]
+    CommitmentOwnershipUnverified,
+
+    #[msg("Platform fee exceeds 10000 bps or has no referrer")]
+    InvalidPlatformFee,
+
+    #[msg("Platform token account does not belong to the campaign's referrer")]
+    PlatformAccountMismatch,
+
+    #[msg("Campaign is paused")]
+    CampaignPaused,
+
+    #[msg("Campaign is not open for donations yet")]
+    CampaignNotStarted,
+
+    #[msg("Start time must be before the deadline")]
+    InvalidStartTime,
+
+    #[msg("Donation exceeds the campaign's maximum single donation")]
+    DonationTooLarge,
+
+    #[msg("Campaign mint can only be changed before any donations")]
+    MintChangeNotAllowed,
+
+    #[msg("Campaign account is not rent-exempt")]
+    CampaignNotRentExempt,
+
+    #[msg("Pledge due time must be in the future")]
+    InvalidPledge,
+
+    #[msg("Doner batch is empty, too large, or does not match its accounts")]
+    InvalidDonerBatch,
+
+    #[msg("Batched campaign trees require their output queue")]
+    OutputQueueRequired,
+
+    #[msg("Concurrent campaign trees do not take an output queue")]
+    OutputQueueUnexpected,
+
+    #[msg("Token account has not approved the caller for the donation amount")]
+    DelegateApprovalInsufficient,
+
+    #[msg("Mint or token account belongs to a different token program")]
+    TokenProgramMismatch,
+
+    #[msg("Campaign missed its goal by the deadline")]
+    CampaignFailed,
+
+    #[msg("Gift account is missing or is not the gift PDA for this donor and gift id")]
+    GiftAccountMismatch,
+
+    #[msg("Commitment rotation is not signed by the donor over the stored commitment")]
+    CommitmentRotationUnverified,
+
+    #[msg("Creator must wait for the creation cooldown before starting another campaign")]
+    CreationCooldownActive,
+
+    #[msg("Display symbol must not be empty")]
+    EmptySymbol,
+
+    #[msg("Display symbol exceeds the maximum length")]
+    SymbolTooLong,
+
+    #[msg("Deadline is beyond the maximum campaign duration")]
+    CampaignTooLong,
+
+    #[msg("A future deadline is required while a maximum campaign duration is set")]
+    DeadlineRequired,
+
+    #[msg("Proof commitment does not match the donor's stored commitment")]
+    DonorCommitmentMismatch,
+
+    #[msg("Refunds are only available for failed campaigns")]
+    CampaignNotFailed,
+
+    #[msg("Counters cannot be reset while the campaign has a refund pool")]
+    RefundPoolOutstanding,
+
+    #[msg("Merkle tree is not an account-compression state tree")]
+    InvalidMerkleTree,
+
+    #[msg("Merkle tree type does not match the campaign")]
+    TreeTypeMismatch,
+
+    #[msg("Counters cannot be reset on a campaign with a goal or deadline")]
+    ResetNotAllowedWithGoal,
+
+    #[msg("Campaign already points at this Merkle tree")]
+    TreeUnchanged,
+
+    #[msg("Tree link does not point at this campaign")]
+    TreeLinkMismatch,
+
+    #[msg("Campaign has no deadline to extend")]
+    NoDeadlineToExtend,
+
+    #[msg("Refund pool funding must be greater than zero")]
+    ZeroRefundPoolFunding,
+
+    #[msg("Pledge was made in a mint the campaign no longer accepts")]
+    PledgeMintChanged,
+
+    #[msg("New mint must have the same decimals as the campaign's current mint")]
+    MintDecimalsMismatch,
+
+    #[msg("KYC threshold requires a configured KYC verifier")]
+    KycVerifierNotConfigured,
+}
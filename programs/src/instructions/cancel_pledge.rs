@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{CampaignInfo, Pledge};
+
+#[derive(Accounts)]
+pub struct CancelPledge<'info> {
+    #[account(mut)]
+    pub donor: Signer<'info>,
+
+    #[account(mut, address = pledge.campaign)]
+    pub campaign_account_info: Account<'info, CampaignInfo>,
+
+    /// Closed back to the donor
+    #[account(
+        mut,
+        close = donor,
+        seeds = [b"pledge", pledge.campaign.as_ref(), donor.key().as_ref()],
+        bump,
+    )]
+    pub pledge: Account<'info, Pledge>,
+}
+
+impl<'info> CancelPledge<'info> {
+    /// Withdraw an unfulfilled pledge, releasing it from the campaign's pledged_total
+    ///
+    /// Pledges voided by a mint change were already dropped from pledged_total.
+    pub fn cancel_pledge(&mut self) -> Result<()> {
+        let campaign = &mut self.campaign_account_info;
+        if self.pledge.mint == campaign.mint {
+            campaign.pledged_total = campaign.pledged_total.saturating_sub(self.pledge.amount);
+        }
+
+        emit!(PledgeCancelledEvent {
+            campaign: campaign.key(),
+            donor: self.donor.key(),
+            amount: self.pledge.amount,
+            pledged_total: campaign.pledged_total,
+        });
+
+        msg!("{} cancelled a pledge of {}", self.donor.key(), self.pledge.amount);
+        Ok(())
+    }
+}
+
+/// Event emitted when a donor cancels an unfulfilled pledge
+#[event]
+pub struct PledgeCancelledEvent {
+    pub campaign: Pubkey,
+    pub donor: Pubkey,
+    pub amount: u64,
+    pub pledged_total: u64,
+}
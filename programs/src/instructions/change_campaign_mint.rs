@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{associated_token::AssociatedToken, token::*};
+
+use crate::error::ErrorCode;
+use crate::state::CampaignInfo;
+use crate::util::canonical_title;
+
+#[derive(Accounts)]
+#[instruction(campaign_id: u64, title: String)]
+pub struct ChangeCampaignMint<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(mint::token_program = token_program)]
+    pub new_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [campaign_id.to_le_bytes().as_ref(), canonical_title(&title).as_bytes()],
+        bump,
+        has_one = creator @ ErrorCode::Unauthorized,
+    )]
+    pub campaign_account_info: Account<'info, CampaignInfo>,
+
+    /// Creator's token account for the new mint, created if it does not exist yet
+    #[account(
+        init_if_needed,
+        payer = creator,
+        associated_token::mint = new_mint,
+        associated_token::authority = creator,
+    )]
+    pub campaign_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+impl<'info> ChangeCampaignMint<'info> {
+    /// Switch the campaign to accept `new_mint`, only if it has never received a
+    /// donation and holds no refund pool in the old mint
+    ///
+    /// Gated on `ever_received` rather than `total_donation_received`, which
+    /// reset_counters zeroes while donor records and stats keep old-mint amounts.
+    /// Open pledges do not block the change, since anyone can create one; they
+    /// are voided instead and can only be cancelled.
+    ///
+    /// The new mint must keep the old mint's decimals: goal, caps, thresholds
+    /// and tier boundaries are stored in base units and would otherwise be
+    /// silently rescaled.
+    pub fn change_campaign_mint(&mut self) -> Result<()> {
+        let campaign = &mut self.campaign_account_info;
+        require!(!campaign.ever_received && campaign.refund_pool == 0, ErrorCode::MintChangeNotAllowed);
+        require!(self.new_mint.decimals == campaign.mint_decimals, ErrorCode::MintDecimalsMismatch);
+
+        let old_mint = campaign.mint;
+        let voided_pledged_total = campaign.pledged_total;
+        campaign.pledged_total = 0;
+        campaign.mint = self.new_mint.key();
+        campaign.token_account = self.campaign_token_account.key();
+        campaign.last_update_time = Clock::get()?.unix_timestamp;
+
+        emit!(CampaignMintChangedEvent {
+            campaign: campaign.key(),
+            old_mint,
+            new_mint: campaign.mint,
+            decimals: campaign.mint_decimals,
+            voided_pledged_total,
+            timestamp: campaign.last_update_time,
+        });
+
+        msg!("Campaign mint changed from {} to {}", old_mint, campaign.mint);
+        Ok(())
+    }
+}
+
+/// Event emitted when a campaign switches its accepted mint
+#[event]
+pub struct CampaignMintChangedEvent {
+    pub campaign: Pubkey,
+    pub old_mint: Pubkey,
+    pub new_mint: Pubkey,
+    pub decimals: u8,
+    // Open pledges in the old mint, no longer fulfillable
+    pub voided_pledged_total: u64,
+    pub timestamp: i64,
+}
@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::*;
+
+use crate::error::ErrorCode;
+use crate::state::Stream;
+
+#[derive(Accounts)]
+pub struct ClaimStream<'info> {
+    /// Must be the stream's own mint, or a vault of junk tokens could be paid out
+    /// in place of the real vested tokens
+    #[account(
+        address = stream.mint @ ErrorCode::MintMismatch,
+        mint::token_program = token_program,
+    )]
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [b"stream", stream.campaign.as_ref(), stream.recipient.as_ref()],
+        bump
+    )]
+    pub stream: Account<'info, Stream>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = stream,
+    )]
+    pub stream_vault: Account<'info, TokenAccount>,
+
+    /// Payouts always go to the stream's recipient, so anyone may crank a claim
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = stream.recipient,
+    )]
+    pub recipient_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> ClaimStream<'info> {
+    /// Pay the recipient everything vested since the last claim
+    pub fn claim_stream(&mut self, bumps: &ClaimStreamBumps) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let amount = self.stream.claimable_at(now);
+        require!(amount > 0, ErrorCode::NothingVested);
+
+        let stream_seeds = &[
+            b"stream".as_ref(),
+            self.stream.campaign.as_ref(),
+            self.stream.recipient.as_ref(),
+            &[bumps.stream],
+        ];
+        let signer_seeds = &[&stream_seeds[..]];
+        let cpi_accounts = TransferChecked {
+            from: self.stream_vault.to_account_info(),
+            to: self.recipient_token_account.to_account_info(),
+            mint: self.mint.to_account_info(),
+            authority: self.stream.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(self.token_program.to_account_info(), cpi_accounts, signer_seeds);
+        transfer_checked(cpi_ctx, amount, self.mint.decimals)?;
+
+        let stream = &mut self.stream;
+        stream.claimed = stream.claimed.checked_add(amount)
+            .ok_or(error!(ErrorCode::ArithmeticOverflow))?;
+
+        emit!(StreamClaimedEvent {
+            campaign: stream.campaign,
+            recipient: stream.recipient,
+            amount,
+            claimed: stream.claimed,
+            deposited: stream.deposited,
+            timestamp: now,
+        });
+
+        msg!("Stream paid {}, {} of {} claimed", amount, stream.claimed, stream.deposited);
+        Ok(())
+    }
+}
+
+/// Event emitted when vested stream tokens are paid to the recipient
+#[event]
+pub struct StreamClaimedEvent {
+    pub campaign: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub claimed: u64,
+    pub deposited: u64,
+    pub timestamp: i64,
+}
@@ -0,0 +1,178 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{create_account, CreateAccount};
+use account_compression::StateMerkleTreeAccount;
+use anchor_lang::Discriminator;
+
+use crate::constants::{SUPPORTED_TREE_BUFFER_SIZES, SUPPORTED_TREE_DEPTHS};
+use crate::error::ErrorCode;
+
+mod light_programs {
+    use anchor_lang::declare_id;
+    declare_id!("compr6CUsB5m2jS4Y3831ztGSTnDpnKJTKS95d64XVq");
+}
+
+#[derive(Accounts)]
+pub struct CreateCampaignTreeAccount<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Fresh keypair for the Merkle tree account, allocated here and assigned
+    /// to the Light account compression program. Passed to init_campaign as
+    /// `merkle_tree`.
+    #[account(mut)]
+    pub merkle_tree: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreateCampaignTreeAccount<'info> {
+    /// Allocate a rent-exempt Merkle tree account sized for `max_depth` and
+    /// `max_buffer_size`, ready for init_campaign's create_tree CPI
+    pub fn create_campaign_tree_account(&mut self, max_depth: u32, max_buffer_size: u32) -> Result<()> {
+        let space = tree_account_size(max_depth, max_buffer_size)?;
+        let lamports = Rent::get()?.minimum_balance(space);
+
+        create_account(
+            CpiContext::new(
+                self.system_program.to_account_info(),
+                CreateAccount {
+                    from: self.payer.to_account_info(),
+                    to: self.merkle_tree.to_account_info(),
+                },
+            ),
+            lamports,
+            space as u64,
+            &light_programs::ID,
+        )?;
+
+        emit!(CampaignTreeAccountCreatedEvent {
+            merkle_tree: self.merkle_tree.key(),
+            payer: self.payer.key(),
+            max_depth,
+            max_buffer_size,
+            space: space as u64,
+        });
+
+        msg!("Merkle tree account {} allocated with {} bytes", self.merkle_tree.key(), space);
+        Ok(())
+    }
+}
+
+/// Size in bytes of a Light state Merkle tree account, as computed by
+/// account-compression itself
+///
+/// Campaign trees keep a root history as long as their changelog and no canopy,
+/// so `max_buffer_size` serves as both the changelog and the roots size.
+pub fn tree_account_size(max_depth: u32, max_buffer_size: u32) -> Result<usize> {
+    require!(
+        SUPPORTED_TREE_DEPTHS.contains(&max_depth) && SUPPORTED_TREE_BUFFER_SIZES.contains(&max_buffer_size),
+        ErrorCode::UnsupportedTreeConfig
+    );
+
+    let changelog_size = max_buffer_size as usize;
+    Ok(StateMerkleTreeAccount::size(max_depth as usize, changelog_size, changelog_size, 0))
+}
+
+/// Discriminator of account-compression's batched state Merkle tree accounts
+pub const BATCHED_TREE_DISCRIMINATOR: [u8; 8] = *b"BatchMta";
+
+/// Offset of `metadata.access_metadata.owner` in both state tree layouts, the
+/// first field after the discriminator
+const TREE_OWNER_OFFSET: usize = 8;
+
+/// Offset of `metadata.associated_queue` in both state tree layouts: the
+/// discriminator, access metadata (3 pubkeys) and rollover metadata (7 u64s)
+const TREE_ASSOCIATED_QUEUE_OFFSET: usize = 8 + 3 * 32 + 7 * 8;
+
+/// Offset of the concurrent tree's `next_index`: the discriminator and tree
+/// metadata (access and rollover metadata, associated queue and next tree),
+/// then the tree's `height` and `canopy_depth`
+const CONCURRENT_TREE_NEXT_INDEX_OFFSET: usize = TREE_ASSOCIATED_QUEUE_OFFSET + 2 * 32 + 2 * 8;
+
+/// Offset of the batched output queue's `batch_metadata.next_index`: the
+/// discriminator, queue metadata (access and rollover metadata, associated tree,
+/// next queue and queue type), then six u64 batch settings
+const OUTPUT_QUEUE_NEXT_INDEX_OFFSET: usize = TREE_ASSOCIATED_QUEUE_OFFSET + 2 * 32 + 8 + 6 * 8;
+
+/// Kind of state Merkle tree a campaign appends to, which decides the append CPI
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TreeType {
+    /// Concurrent tree, whose leaves are appended to the tree directly
+    Concurrent,
+    /// Batched tree, whose leaves are appended to its output queue and later
+    /// settled into the tree by the forester with batch_append
+    Batched,
+}
+
+impl TreeType {
+    /// Read the tree type from the account's owner and discriminator
+    pub fn of(merkle_tree: &AccountInfo) -> Result<Self> {
+        require_keys_eq!(*merkle_tree.owner, light_programs::ID, ErrorCode::InvalidMerkleTree);
+        let data = merkle_tree.try_borrow_data()?;
+        match data.get(..8) {
+            Some(discriminator) if discriminator == StateMerkleTreeAccount::DISCRIMINATOR => Ok(Self::Concurrent),
+            Some(discriminator) if discriminator == BATCHED_TREE_DISCRIMINATOR => Ok(Self::Batched),
+            _ => err!(ErrorCode::InvalidMerkleTree),
+        }
+    }
+
+    pub fn is_batched(self) -> bool {
+        self == Self::Batched
+    }
+}
+
+/// Authority recorded in a state tree's access metadata, for either tree type
+pub fn tree_owner(merkle_tree: &AccountInfo) -> Result<Pubkey> {
+    TreeType::of(merkle_tree)?;
+    let data = merkle_tree.try_borrow_data()?;
+    data.get(TREE_OWNER_OFFSET..TREE_OWNER_OFFSET + 32)
+        .and_then(|owner| Pubkey::try_from(owner).ok())
+        .ok_or(error!(ErrorCode::InvalidMerkleTree))
+}
+
+/// Index the next leaf appended to `append_target` will get, read before the append
+///
+/// Concurrent trees number leaves themselves; batched trees number them in the
+/// output queue they are appended to.
+pub fn next_leaf_index(tree_type: TreeType, append_target: &AccountInfo) -> Result<u64> {
+    let offset = match tree_type {
+        TreeType::Concurrent => CONCURRENT_TREE_NEXT_INDEX_OFFSET,
+        TreeType::Batched => OUTPUT_QUEUE_NEXT_INDEX_OFFSET,
+    };
+    let data = append_target.try_borrow_data()?;
+    data.get(offset..offset + 8)
+        .and_then(|bytes| bytes.try_into().ok())
+        .map(u64::from_le_bytes)
+        .ok_or(error!(ErrorCode::InvalidMerkleTree))
+}
+
+/// Check `output_queue` against the tree it is passed with and return the tree's type
+///
+/// A batched tree requires the queue recorded in its own metadata; a concurrent
+/// tree appends directly and must not be given a queue.
+pub fn validate_tree_queue(merkle_tree: &AccountInfo, output_queue: Option<Pubkey>) -> Result<TreeType> {
+    let tree_type = TreeType::of(merkle_tree)?;
+    match (tree_type, output_queue) {
+        (TreeType::Batched, Some(queue)) => {
+            let data = merkle_tree.try_borrow_data()?;
+            let associated_queue = data
+                .get(TREE_ASSOCIATED_QUEUE_OFFSET..TREE_ASSOCIATED_QUEUE_OFFSET + 32)
+                .ok_or(error!(ErrorCode::InvalidMerkleTree))?;
+            require!(associated_queue == queue.as_ref(), ErrorCode::QueueMismatch);
+        }
+        (TreeType::Batched, None) => return err!(ErrorCode::OutputQueueRequired),
+        (TreeType::Concurrent, Some(_)) => return err!(ErrorCode::OutputQueueUnexpected),
+        (TreeType::Concurrent, None) => {}
+    }
+    Ok(tree_type)
+}
+
+/// Event emitted when a Merkle tree account is pre-allocated for a campaign
+#[event]
+pub struct CampaignTreeAccountCreatedEvent {
+    pub merkle_tree: Pubkey,
+    pub payer: Pubkey,
+    pub max_depth: u32,
+    pub max_buffer_size: u32,
+    pub space: u64,
+}
@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ErrorCode;
+use crate::state::{CampaignInfo, Pledge};
+use crate::util::canonical_title;
+
+#[derive(Accounts)]
+#[instruction(campaign_id: u64, title: String)]
+pub struct CreatePledge<'info> {
+    #[account(mut)]
+    pub donor: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [campaign_id.to_le_bytes().as_ref(), canonical_title(&title).as_bytes()],
+        bump,
+    )]
+    pub campaign_account_info: Account<'info, CampaignInfo>,
+
+    #[account(
+        init,
+        payer = donor,
+        seeds = [b"pledge", campaign_account_info.key().as_ref(), donor.key().as_ref()],
+        bump,
+        space = 8 + Pledge::INIT_SPACE,
+    )]
+    pub pledge: Account<'info, Pledge>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreatePledge<'info> {
+    /// Commit to donating `amount` by `due_time` without moving any tokens yet
+    ///
+    /// The amount must be one the campaign would accept as a donation today.
+    /// Pledges are unauthenticated and only feed the informational
+    /// `pledged_total`, which nothing gates on.
+    pub fn pledge(&mut self, amount: u64, due_time: i64) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let campaign = &mut self.campaign_account_info;
+        campaign.validate_donation_amount(amount)?;
+        require!(due_time > now, ErrorCode::InvalidPledge);
+
+        campaign.pledged_total = campaign.pledged_total.checked_add(amount)
+            .ok_or(error!(ErrorCode::ArithmeticOverflow))?;
+
+        let pledge = &mut self.pledge;
+        pledge.campaign = campaign.key();
+        pledge.donor = self.donor.key();
+        pledge.amount = amount;
+        pledge.mint = campaign.mint;
+        pledge.due_time = due_time;
+        pledge.created_at = now;
+
+        emit!(PledgeCreatedEvent {
+            campaign: pledge.campaign,
+            donor: pledge.donor,
+            amount,
+            due_time,
+            pledged_total: campaign.pledged_total,
+        });
+
+        msg!("{} pledged {} due at {}", pledge.donor, amount, due_time);
+        Ok(())
+    }
+}
+
+/// Event emitted when a donor pledges to a campaign
+#[event]
+pub struct PledgeCreatedEvent {
+    pub campaign: Pubkey,
+    pub donor: Pubkey,
+    pub amount: u64,
+    pub due_time: i64,
+    pub pledged_total: u64,
+}
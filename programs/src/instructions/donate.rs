@@ -1,10 +1,17 @@
 use anchor_lang::prelude::*;
 use anchor_spl::{associated_token::AssociatedToken, token::*};
 
-use crate::state::{CampaignInfo, DonerInfo};
+use crate::constants::BPS_DENOMINATOR;
+use crate::error::ErrorCode;
+use crate::instructions::apply_donation;
+use crate::state::{
+    CampaignInfo, DonerInfo, DonorGlobalStats, Gift, GlobalConfig, KycAttestation, ProtocolStats, RecentDonation,
+    RecentDonations,
+};
+use crate::util::{canonical_title, create_pda_account, require_token_program};
 
 #[derive(Accounts)]
-#[instruction(campaign_id: u64, title: String, donation_amount: u64)]
+#[instruction(campaign_id: u64, title: String)]
 pub struct DonateAmount<'info> {
     #[account(mut)]
     pub doner: Signer<'info>,
@@ -14,8 +21,9 @@ pub struct DonateAmount<'info> {
 
     #[account(
         mut,
-        seeds = [campaign_id.to_le_bytes().as_ref(), title.as_bytes().as_ref()],
-        bump
+        seeds = [campaign_id.to_le_bytes().as_ref(), canonical_title(&title).as_bytes()],
+        bump,
+        has_one = mint @ ErrorCode::MintMismatch,
     )]
     pub campaign_account_info: Account<'info, CampaignInfo>,
 
@@ -33,13 +41,77 @@ pub struct DonateAmount<'info> {
     )]
     pub campaign_token_account: Account<'info, TokenAccount>,
 
+    /// Doner account credited with the donation: the signer's own, or the
+    /// beneficiary donor's when donating on someone else's behalf
     #[account(
         mut,
-        seeds = [b"doner", campaign_account_info.key().as_ref(), doner.key().as_ref()],
+        seeds = [b"doner", campaign_account_info.key().as_ref(), doner_account_info.doner.as_ref()],
         bump
     )]
     pub doner_account_info: Account<'info, DonerInfo>,
 
+    #[account(
+        mut,
+        seeds = [b"protocol_stats"],
+        bump
+    )]
+    pub protocol_stats: Account<'info, ProtocolStats>,
+
+    #[account(
+        seeds = [b"global_config"],
+        bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    /// Lifetime stats of the credited donor, created on their first donation
+    #[account(
+        init_if_needed,
+        payer = doner,
+        seeds = [b"donor_stats", doner_account_info.doner.as_ref()],
+        bump,
+        space = 8 + DonorGlobalStats::INIT_SPACE,
+    )]
+    pub donor_stats: Account<'info, DonorGlobalStats>,
+
+    /// Campaign's recent-activity feed, created by its first token donation
+    #[account(
+        init_if_needed,
+        payer = doner,
+        seeds = [b"recent", campaign_account_info.key().as_ref()],
+        bump,
+        space = 8 + RecentDonations::INIT_SPACE,
+    )]
+    pub recent_donations: Account<'info, RecentDonations>,
+
+    /// Credited donor's KYC attestation, only required above the campaign's kyc_threshold
+    #[account(
+        seeds = [b"kyc_attestation", doner_account_info.doner.as_ref()],
+        bump
+    )]
+    pub kyc_attestation: Option<Account<'info, KycAttestation>>,
+
+    /// Signer's KYC attestation, also required above the campaign's kyc_threshold
+    /// when donating on someone else's behalf
+    #[account(
+        seeds = [b"kyc_attestation", doner.key().as_ref()],
+        bump
+    )]
+    pub payer_kyc_attestation: Option<Account<'info, KycAttestation>>,
+
+    /// Credited donor's token account for the campaign's gate_mint, required on token-gated campaigns
+    pub gate_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// Referrer's token account for the campaign mint, required when the campaign charges a platform fee
+    #[account(mut)]
+    pub platform_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// Gift PDA `[b"gift", campaign, credited donor, gift_id]`, required when donating
+    /// with a gift_id and created by its first installment. Its address is checked in
+    /// the handler, since donate_percent_of_goal shares these accounts without a gift_id.
+    /// CHECK: address verified and data (de)serialized in record_gift_installment
+    #[account(mut)]
+    pub gift: Option<UncheckedAccount<'info>>,
+
     pub token_program: Program<'info, Token>,
 
     pub system_program: Program<'info, System>,
@@ -48,23 +120,383 @@ pub struct DonateAmount<'info> {
 }
 
 impl<'info> DonateAmount<'info> {
-    pub fn donate_amount(&mut self, campaign_id: u64, title: String, donation_amount: u64) -> Result<()> {
-        // Transfer tokens from doner to campaign
+    pub fn donate_amount(
+        &mut self,
+        campaign_id: u64,
+        title: String,
+        donation_amount: u64,
+        beneficiary_donor: Option<Pubkey>,
+        gift_id: Option<u64>,
+    ) -> Result<()> {
+        // The signer always pays; the beneficiary (if any) is credited instead
+        let credited_donor = beneficiary_donor.unwrap_or(self.doner.key());
+        self.process_donation(donation_amount, credited_donor, crate::instruction::DonateAmount::DISCRIMINATOR)?;
+        if let Some(gift_id) = gift_id {
+            self.record_gift_installment(credited_donor, gift_id, donation_amount)?;
+        }
+        Ok(())
+    }
+
+    /// Add a donation of `amount` to the credited donor's gift `gift_id`, creating
+    /// the Gift PDA (paid for by the signer) on its first installment
+    fn record_gift_installment(&mut self, credited_donor: Pubkey, gift_id: u64, amount: u64) -> Result<()> {
+        let gift_account = self.gift.as_ref()
+            .ok_or(error!(ErrorCode::GiftAccountMismatch))?
+            .to_account_info();
+        let campaign = self.campaign_account_info.key();
+        let gift_id_bytes = gift_id.to_le_bytes();
+        let (expected, bump) = Pubkey::find_program_address(
+            &[b"gift", campaign.as_ref(), credited_donor.as_ref(), &gift_id_bytes],
+            &crate::ID,
+        );
+        require_keys_eq!(gift_account.key(), expected, ErrorCode::GiftAccountMismatch);
+
+        let mut gift = if gift_account.owner == &crate::ID {
+            Gift::try_deserialize(&mut &gift_account.try_borrow_data()?[..])?
+        } else {
+            create_pda_account(
+                &self.doner.to_account_info(),
+                &gift_account,
+                &self.system_program.to_account_info(),
+                8 + Gift::INIT_SPACE,
+                &[b"gift", campaign.as_ref(), credited_donor.as_ref(), &gift_id_bytes, &[bump]],
+            )?;
+            Gift {
+                campaign,
+                donor: credited_donor,
+                gift_id,
+                total: 0,
+                installments: 0,
+                last_installment_at: 0,
+            }
+        };
+        gift.record_installment(amount, self.campaign_account_info.last_update_time)?;
+        gift.try_serialize(&mut &mut gift_account.try_borrow_mut_data()?[..])?;
+
+        if self.campaign_account_info.emit_events {
+            emit!(GiftInstallmentEvent {
+                campaign,
+                donor: credited_donor,
+                gift_id,
+                amount,
+                gift_total: gift.total,
+                installments: gift.installments,
+                timestamp: gift.last_installment_at,
+            });
+        }
+
+        msg!("Gift {} installment {} brings its total to {}", gift_id, gift.installments, gift.total);
+        Ok(())
+    }
+
+    /// Donate a percentage of the campaign goal, expressed in basis points
+    pub fn donate_percent_of_goal(&mut self, percent_bps: u16) -> Result<()> {
+        let goal_amount = self.campaign_account_info.goal_amount;
+        require!(goal_amount > 0, ErrorCode::GoalNotConfigured);
+        require!(u64::from(percent_bps) <= BPS_DENOMINATOR, ErrorCode::InvalidPercentBps);
+
+        // percent_bps <= 10000, so the result never exceeds goal_amount
+        let donation_amount = u64::try_from(
+            u128::from(goal_amount) * u128::from(percent_bps) / u128::from(BPS_DENOMINATOR),
+        )
+        .map_err(|_| error!(ErrorCode::ArithmeticOverflow))?;
+
+        msg!("{} bps of goal {} is {}", percent_bps, goal_amount, donation_amount);
+        self.process_donation(donation_amount, self.doner.key(), crate::instruction::DonatePercentOfGoal::DISCRIMINATOR)
+    }
+
+    /// Transfer `donation_amount` from the signer to the campaign and record it
+    /// against `credited_donor`
+    fn process_donation(
+        &mut self,
+        donation_amount: u64,
+        credited_donor: Pubkey,
+        instruction_discriminator: &[u8],
+    ) -> Result<()> {
+        TokenDonation {
+            doner: &self.doner,
+            mint: &self.mint,
+            campaign_account_info: &mut self.campaign_account_info,
+            doner_token_account: &self.doner_token_account,
+            campaign_token_account: &mut self.campaign_token_account,
+            doner_account_info: &mut self.doner_account_info,
+            protocol_stats: &mut self.protocol_stats,
+            global_config: &self.global_config,
+            donor_stats: &mut self.donor_stats,
+            recent_donations: &mut self.recent_donations,
+            kyc_attestation: self.kyc_attestation.as_ref(),
+            payer_kyc_attestation: self.payer_kyc_attestation.as_ref(),
+            gate_token_account: self.gate_token_account.as_ref(),
+            platform_token_account: self.platform_token_account.as_ref(),
+            token_program: &self.token_program,
+        }
+        .process(donation_amount, credited_donor, instruction_discriminator)
+    }
+}
+
+/// Accounts a token donation touches, borrowed from the instruction performing it
+/// so that donate_amount and donate_first_time run the same checks and updates
+pub struct TokenDonation<'a, 'info> {
+    pub doner: &'a Signer<'info>,
+    pub mint: &'a Account<'info, Mint>,
+    pub campaign_account_info: &'a mut Account<'info, CampaignInfo>,
+    pub doner_token_account: &'a Account<'info, TokenAccount>,
+    pub campaign_token_account: &'a mut Account<'info, TokenAccount>,
+    pub doner_account_info: &'a mut Account<'info, DonerInfo>,
+    pub protocol_stats: &'a mut Account<'info, ProtocolStats>,
+    pub global_config: &'a Account<'info, GlobalConfig>,
+    pub donor_stats: &'a mut Account<'info, DonorGlobalStats>,
+    pub recent_donations: &'a mut Account<'info, RecentDonations>,
+    pub kyc_attestation: Option<&'a Account<'info, KycAttestation>>,
+    // Attestation of the wallet funding the donation, when it is not the credited donor
+    pub payer_kyc_attestation: Option<&'a Account<'info, KycAttestation>>,
+    pub gate_token_account: Option<&'a Account<'info, TokenAccount>>,
+    pub platform_token_account: Option<&'a Account<'info, TokenAccount>>,
+    pub token_program: &'a Program<'info, Token>,
+}
+
+impl<'a, 'info> TokenDonation<'a, 'info> {
+    /// Donations above the campaign's kyc_threshold need an attestation for the
+    /// credited donor issued by the currently configured verifier, and one for
+    /// the owner of the funding token account when that is someone else
+    fn require_kyc(&self, donation_amount: u64, credited_donor: Pubkey) -> Result<()> {
+        let threshold = self.campaign_account_info.kyc_threshold;
+        if threshold == 0 || donation_amount <= threshold {
+            return Ok(());
+        }
+
+        require!(self.is_attested(self.kyc_attestation, credited_donor), ErrorCode::KycRequired);
+        let funder = self.doner_token_account.owner;
+        if funder != credited_donor {
+            require!(self.is_attested(self.payer_kyc_attestation, funder), ErrorCode::KycRequired);
+        }
+        Ok(())
+    }
+
+    /// Whether `attestation` vouches for `donor` and comes from the configured verifier
+    fn is_attested(&self, attestation: Option<&Account<'info, KycAttestation>>, donor: Pubkey) -> bool {
+        let kyc_verifier = self.global_config.kyc_verifier;
+        kyc_verifier != Pubkey::default()
+            && attestation.is_some_and(|attestation| attestation.verifier == kyc_verifier && attestation.donor == donor)
+    }
+
+    /// On token-gated campaigns the credited donor must hold a nonzero balance of the gate mint
+    fn require_token_gate(&self, credited_donor: Pubkey) -> Result<()> {
+        let Some(gate_mint) = self.campaign_account_info.gate_mint else {
+            return Ok(());
+        };
+
+        let satisfied = self.gate_token_account.is_some_and(|account| {
+            account.owner == credited_donor && account.mint == gate_mint && account.amount > 0
+        });
+        require!(satisfied, ErrorCode::TokenGateNotSatisfied);
+        Ok(())
+    }
+
+    /// The platform fee must go to the referrer's account for the campaign mint
+    fn platform_token_account(&self) -> Result<&'a Account<'info, TokenAccount>> {
+        let referrer = self.campaign_account_info.platform_referrer;
+        self.platform_token_account
+            .filter(|account| Some(account.owner) == referrer && account.mint == self.mint.key())
+            .ok_or(error!(ErrorCode::PlatformAccountMismatch))
+    }
+
+    /// Transfer `amount` from the signer's token account to `to`
+    fn transfer_from_doner(&self, to: &Account<'info, TokenAccount>, amount: u64) -> Result<()> {
         let cpi_accounts = TransferChecked {
             from: self.doner_token_account.to_account_info(),
-            to: self.campaign_token_account.to_account_info(),
+            to: to.to_account_info(),
             mint: self.mint.to_account_info(),
             authority: self.doner.to_account_info(),
         };
-        let cpi_program = self.token_program.to_account_info();
-        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
-        transfer_checked(cpi_ctx, donation_amount, self.mint.decimals)?;
+        let cpi_ctx = CpiContext::new(self.token_program.to_account_info(), cpi_accounts);
+        transfer_checked(cpi_ctx, amount, self.mint.decimals)
+    }
+
+    /// Transfer `donation_amount` from the signer to the campaign, less any platform
+    /// fee sent to the referrer, and record the full amount against `credited_donor`
+    ///
+    /// `instruction_discriminator` identifies the calling instruction in the
+    /// campaign's audit hash chain.
+    pub fn process(self, donation_amount: u64, credited_donor: Pubkey, instruction_discriminator: &[u8]) -> Result<()> {
+        self.global_config.require_not_paused()?;
+        self.campaign_account_info.validate_donation_amount(donation_amount)?;
+        require_token_program(
+            &self.mint.to_account_info(),
+            &self.token_program.key(),
+            &self.campaign_token_account.key(),
+            &self.campaign_account_info.creator,
+        )?;
+        require_keys_eq!(
+            *self.doner_token_account.to_account_info().owner,
+            self.token_program.key(),
+            ErrorCode::TokenProgramMismatch
+        );
+        require_keys_eq!(
+            self.doner_account_info.doner,
+            credited_donor,
+            ErrorCode::DonorAccountMismatch
+        );
+        // Compliance checks follow the credited donor, whoever signs; KYC also
+        // vets the wallet the funds come from
+        self.require_kyc(donation_amount, credited_donor)?;
+        self.require_token_gate(credited_donor)?;
+
+        // The creator either pays or is credited
+        let creator = self.campaign_account_info.creator;
+        let is_self_donation = self.doner.key() == creator || credited_donor == creator;
+        require!(
+            !is_self_donation || self.campaign_account_info.allow_self_donation,
+            ErrorCode::SelfDonationNotAllowed
+        );
+
+        // Donors count towards max_donors from their first donation, however their
+        // doner account was created
+        if self.doner_account_info.amount == 0 {
+            self.campaign_account_info.admit_donor()?;
+        }
+
+        // Donor and campaign totals count the full donation; only the tokens are split
+        let platform_fee = self.campaign_account_info.platform_fee(donation_amount);
+        let campaign_share = donation_amount - platform_fee;
+        let platform_token_account = match platform_fee {
+            0 => None,
+            _ => Some(self.platform_token_account()?),
+        };
+
+        // Lock the campaign and persist the flag before the CPI so a transfer hook
+        // calling back into the program sees the lock
+        require!(!self.campaign_account_info.locked, ErrorCode::ReentrancyDetected);
+        self.campaign_account_info.locked = true;
+        self.campaign_account_info.exit(&crate::ID)?;
+
+        // Transfer tokens from doner to campaign, and the platform fee to the referrer
+        let balance_before = self.campaign_token_account.amount;
+        self.transfer_from_doner(self.campaign_token_account, campaign_share)?;
+        if let Some(platform_token_account) = platform_token_account {
+            self.transfer_from_doner(platform_token_account, platform_fee)?;
+        }
+
+        // Only count the donation once the campaign balance has actually grown by
+        // its full share, rather than trusting the CPI's success alone. A creator
+        // paying from their own ATA transfers to the same account, so the balance
+        // cannot move and there is no delta to confirm.
+        let is_self_transfer = self.doner_token_account.key() == self.campaign_token_account.key();
+        if !is_self_transfer {
+            self.campaign_token_account.reload()?;
+            let received = self.campaign_token_account.amount.checked_sub(balance_before);
+            require!(received == Some(campaign_share), ErrorCode::TransferNotConfirmed);
+        }
 
         // Update state
-        self.doner_account_info.amount += donation_amount;
-        self.campaign_account_info.total_donation_received += donation_amount;
+        let first_to_campaign = self.doner_account_info.amount == 0;
+        self.doner_account_info.amount = self.doner_account_info.amount.checked_add(donation_amount)
+            .ok_or(error!(ErrorCode::ArithmeticOverflow))?;
+        self.donor_stats.record_donation(credited_donor, donation_amount, first_to_campaign)?;
+        apply_donation(self.campaign_account_info, credited_donor, donation_amount)?;
+        self.campaign_account_info.token_donation_total = self.campaign_account_info.token_donation_total
+            .checked_add(donation_amount)
+            .ok_or(error!(ErrorCode::ArithmeticOverflow))?;
+        self.protocol_stats.record_donation(donation_amount)?;
+        self.recent_donations.push(self.campaign_account_info.key(), RecentDonation {
+            donor: credited_donor,
+            amount: donation_amount,
+            timestamp: self.campaign_account_info.last_update_time,
+        });
+
+        // Recompute the donor tier from the cumulative amount; tiers only ever go up
+        let new_tier = self.campaign_account_info.tier_for(self.doner_account_info.amount);
+        if new_tier > self.doner_account_info.tier {
+            let previous_tier = self.doner_account_info.tier;
+            self.doner_account_info.tier = new_tier;
 
-        msg!("{} donated {}", self.doner.key(), donation_amount);
+            if self.campaign_account_info.emit_events {
+                emit!(TierUpgradedEvent {
+                    campaign: self.campaign_account_info.key(),
+                    doner: credited_donor,
+                    previous_tier,
+                    new_tier,
+                    total_donated: self.doner_account_info.amount,
+                });
+            }
+        }
+
+        self.campaign_account_info.locked = false;
+
+        let total_donation_received = self.campaign_account_info.total_donation_received;
+        let audit_hash = self.campaign_account_info.chain_audit(
+            instruction_discriminator,
+            &[
+                credited_donor.as_ref(),
+                &donation_amount.to_le_bytes(),
+                &platform_fee.to_le_bytes(),
+                &total_donation_received.to_le_bytes(),
+            ],
+        );
+
+        if self.campaign_account_info.emit_events {
+            emit!(DonationReceivedEvent {
+                campaign: self.campaign_account_info.key(),
+                payer: self.doner.key(),
+                credited_donor,
+                amount: donation_amount,
+                platform_fee,
+                total_donation_received: self.campaign_account_info.total_donation_received,
+                timestamp: self.campaign_account_info.last_update_time,
+                is_self_donation,
+                audit_hash,
+                season: self.campaign_account_info.season,
+                symbol: self.campaign_account_info.symbol.clone(),
+            });
+        }
+
+        msg!("{} donated {} credited to {}", self.doner.key(), donation_amount, credited_donor);
         Ok(())
     }
 }
+
+/// Event emitted for every token donation
+#[event]
+pub struct DonationReceivedEvent {
+    pub campaign: Pubkey,
+    // Wallet the tokens were transferred from
+    pub payer: Pubkey,
+    // Donor credited with the donation (equal to payer unless donating on someone's behalf)
+    pub credited_donor: Pubkey,
+    pub amount: u64,
+    // Part of amount routed to the campaign's platform referrer
+    pub platform_fee: u64,
+    pub total_donation_received: u64,
+    pub timestamp: i64,
+    // Set when the campaign creator paid for or was credited with the donation
+    pub is_self_donation: bool,
+    // Campaign's audit hash after this donation
+    pub audit_hash: [u8; 32],
+    // Campaign season the donation counts towards
+    pub season: u16,
+    // Display symbol of the campaign mint, empty if unset
+    pub symbol: String,
+}
+
+/// Event emitted for each installment donated towards a gift
+#[event]
+pub struct GiftInstallmentEvent {
+    pub campaign: Pubkey,
+    pub donor: Pubkey,
+    pub gift_id: u64,
+    pub amount: u64,
+    // Running total of the gift including this installment
+    pub gift_total: u64,
+    pub installments: u32,
+    pub timestamp: i64,
+}
+
+/// Event emitted when a donor crosses into a higher tier
+#[event]
+pub struct TierUpgradedEvent {
+    pub campaign: Pubkey,
+    pub doner: Pubkey,
+    pub previous_tier: u8,
+    pub new_tier: u8,
+    pub total_donated: u64,
+}
@@ -1,24 +1,64 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::*;
+use anchor_lang::solana_program::ed25519_program;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::keccak;
+use anchor_lang::solana_program::program::set_return_data;
+use anchor_lang::solana_program::sysvar::instructions as sysvar_instructions;
+use anchor_spl::{associated_token::AssociatedToken, token::*};
 use account_compression::program::AccountCompression;
-use account_compression::cpi::accounts::BatchAppend;
-use account_compression::cpi::batch_append;
+use account_compression::cpi::accounts::AppendLeaves;
+use account_compression::cpi::append_leaves_to_merkle_trees;
 use std::io::Write;
 
-use crate::state::CampaignInfo;
+use crate::constants::{LEAF_VERSION, MAX_PROOF_SLOT_AGE};
+use crate::error::ErrorCode;
+use crate::instructions::{apply_donation, next_leaf_index, validate_tree_queue, TreeType};
+use crate::state::{CampaignInfo, CommitmentOwner, DonorGlobalStats, GlobalConfig, ProtocolStats};
+use crate::util::{canonical_title, create_pda_account};
 
 mod light_programs {
     use anchor_lang::declare_id;
     declare_id!("compr6CUsB5m2jS4Y3831ztGSTnDpnKJTKS95d64XVq");
 }
 
+/// Public inputs of the donation circuit, in the order the circuit declares them
+///
+/// Proof parsing and leaf construction both go through this struct, so the
+/// values a proof is checked against are exactly the values that get stored.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DonationPublicInputs {
+    pub amount: u64,
+    pub donor_commitment: [u8; 32], // A commitment hiding the donor's identity
+    pub nullifier: [u8; 32],
+    pub campaign_id: u64,
+}
+
+impl DonationPublicInputs {
+    /// Canonical Groth16 public-input encoding: one 32-byte little-endian field
+    /// element per input, in declaration order, with integers zero-extended
+    pub fn to_field_elements(&self) -> [[u8; 32]; 4] {
+        [
+            Self::u64_field_element(self.amount),
+            self.donor_commitment,
+            self.nullifier,
+            Self::u64_field_element(self.campaign_id),
+        ]
+    }
+
+    fn u64_field_element(value: u64) -> [u8; 32] {
+        let mut element = [0u8; 32];
+        element[..8].copy_from_slice(&value.to_le_bytes());
+        element
+    }
+}
+
 /// Structure to represent donation data embedded in the ZK proof
 /// This is a simplified example; in a real implementation, 
 /// this would be based on the circuit's public inputs
 #[derive(Debug)]
 pub struct DonationData {
-    pub amount: u64,
-    pub donor_commitment: [u8; 32], // A commitment hiding the donor's identity
+    pub leaf_version: u8,
+    pub public_inputs: DonationPublicInputs,
     pub timestamp: i64,
 }
 
@@ -26,6 +66,7 @@ pub struct DonationData {
 /// This is the data structure that will be serialized and appended to the Merkle tree
 #[derive(Debug)]
 pub struct DonationLeaf {
+    pub leaf_version: u8,
     pub amount: u64,
     pub donor_commitment: [u8; 32],
     pub timestamp: i64,
@@ -35,35 +76,50 @@ pub struct DonationLeaf {
 
 impl DonationLeaf {
     /// Create a new leaf from donation data
-    pub fn new(donation: &DonationData, campaign_id: u64) -> Self {
+    pub fn new(donation: &DonationData) -> Self {
         Self {
-            amount: donation.amount,
-            donor_commitment: donation.donor_commitment,
+            leaf_version: donation.leaf_version,
+            amount: donation.public_inputs.amount,
+            donor_commitment: donation.public_inputs.donor_commitment,
             timestamp: donation.timestamp,
-            campaign_id,
+            campaign_id: donation.public_inputs.campaign_id,
         }
     }
     
-    /// Serialize the leaf into bytes for inclusion in the Merkle tree
-    /// Format adheres to Light Protocol's expectations for leaf data
+    /// Serialize the leaf into the bytes DonationLeaf::hash commits to
     pub fn serialize(&self) -> Result<Vec<u8>> {
         let mut leaf_data = Vec::new();
         
-        // Serialize in a consistent, deterministic order
+        // Serialize in a consistent, deterministic order, prefixed by the schema version
+        leaf_data.push(self.leaf_version);
         leaf_data.extend_from_slice(&self.amount.to_le_bytes());
         leaf_data.extend_from_slice(&self.donor_commitment);
         leaf_data.extend_from_slice(&self.timestamp.to_le_bytes());
         leaf_data.extend_from_slice(&self.campaign_id.to_le_bytes());
         
-        // Hash the leaf data to get final leaf value if required
-        // For simplicity, we're not including additional hashing here
-        // In a real implementation, you might want to hash this data with Poseidon or another hash function
+        // The tree leaf is DonationLeaf::hash of these bytes; a circuit-friendly
+        // hash such as Poseidon would replace keccak once proofs are verified
         
         Ok(leaf_data)
     }
+
+    /// Keccak-256 hash of serialized leaf bytes, the value appended to the tree
+    /// and the leaf clients insert into their local copy
+    pub fn hash(leaf_data: &[u8]) -> [u8; 32] {
+        keccak::hash(leaf_data).to_bytes()
+    }
 }
 
-/// Struct to represent the response data from batch_append CPI
+/// Return data set by donate_compressed, so a simulated transaction reports the
+/// parsed amount and appended leaf without log parsing
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, PartialEq)]
+pub struct DonateCompressedResult {
+    pub amount: u64,
+    pub leaf_index: u64,
+    pub leaf_hash: [u8; 32],
+}
+
+/// Struct to represent the response data from the append CPI
 /// This is used to track the updated Merkle root and other relevant data
 #[derive(Debug)]
 pub struct MerkleTreeUpdate {
@@ -80,11 +136,30 @@ pub struct DonateCompressed<'info> {
 
     #[account(
         mut,
-        seeds = [campaign_id.to_le_bytes().as_ref(), title.as_bytes().as_ref()],
-        bump
+        seeds = [campaign_id.to_le_bytes().as_ref(), canonical_title(&title).as_bytes()],
+        bump,
+        has_one = mint @ ErrorCode::MintMismatch,
     )]
     pub campaign_account_info: Account<'info, CampaignInfo>,
 
+    #[account(mint::token_program = token_program)]
+    pub mint: Account<'info, Mint>,
+
+    /// Donor's token account the claimed amount is locked from
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = donor,
+    )]
+    pub donor_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = campaign_account_info.creator,
+    )]
+    pub campaign_token_account: Account<'info, TokenAccount>,
+
     /// CHECK: The Merkle tree account associated with the campaign,
     /// validated through has_one constraint against campaign_account_info.merkle_tree
     #[account(
@@ -93,30 +168,77 @@ pub struct DonateCompressed<'info> {
     )]
     pub merkle_tree: UncheckedAccount<'info>,
 
-    /// CHECK: Optional output queue for the Merkle tree
-    /// If provided, will be used in the Light Protocol CPI
+    /// CHECK: Output queue for the Merkle tree, required for batched campaign
+    /// trees and rejected for concurrent ones. Checked against the tree's
+    /// metadata by validate_tree_queue and against the queue recorded on the
+    /// campaign at init by validate_output_queue
     #[account(mut)]
     pub output_queue: Option<UncheckedAccount<'info>>,
 
+    #[account(
+        mut,
+        seeds = [b"protocol_stats"],
+        bump
+    )]
+    pub protocol_stats: Account<'info, ProtocolStats>,
+
+    #[account(
+        seeds = [b"global_config"],
+        bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    /// Signer's lifetime stats, created on their first donation
+    #[account(
+        init_if_needed,
+        payer = donor,
+        seeds = [b"donor_stats", donor.key().as_ref()],
+        bump,
+        space = 8 + DonorGlobalStats::INIT_SPACE,
+    )]
+    pub donor_stats: Account<'info, DonorGlobalStats>,
+
+    /// CHECK: `[b"commitment", donor_commitment]` PDA for the proof's commitment,
+    /// verified and created on first use by claim_commitment
+    #[account(mut)]
+    pub commitment_owner: UncheckedAccount<'info>,
+
+    /// CHECK: Instructions sysvar, read to find the donor's ed25519 commitment binding
+    #[account(address = sysvar_instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
     /// The Light Protocol account compression program.
     #[account(address = light_programs::ID)]
     pub light_account_compression_program: Program<'info, AccountCompression>,
 
+    pub token_program: Program<'info, Token>,
+
     pub system_program: Program<'info, System>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
 }
 
 impl<'info> DonateCompressed<'info> {
     /// Verify ZK proof and process a compressed donation
     ///
     /// The function extracts and verifies a ZK proof submitted with the donation instruction.
-    /// It then formats the donation data into a leaf and updates the Merkle tree 
-    /// through a CPI call to Light Protocol's batch_append function.
+    /// The donor must bind the proof's commitment and `recent_slot` to themselves
+    /// by signing `commitment_binding_message` in an ed25519 program instruction
+    /// placed immediately before this one. The first donor to use a commitment
+    /// becomes its owner, and no other signer can donate under it afterwards.
+    /// The amount claimed by the proof is transferred from the donor to the campaign
+    /// before anything is appended, so a proof cannot claim tokens that never moved.
+    /// It then formats the donation data into a leaf and inserts its hash through
+    /// Light Protocol's append_leaves_to_merkle_trees: into the output queue for
+    /// batched trees, where the forester settles it with batch_append, or
+    /// directly into concurrent trees.
     /// Finally, it updates the campaign state with the new Merkle root.
     ///
     /// # Arguments
     /// * `campaign_id` - The unique identifier of the campaign
     /// * `title` - The title of the campaign (used for PDA derivation)
     /// * `proof_data` - ZK proof data that contains the donation details
+    /// * `recent_slot` - Slot the donor signed into the binding; rejected once older than `MAX_PROOF_SLOT_AGE`
     ///
     /// # Returns
     /// * `Result<()>` - Success or error
@@ -125,7 +247,24 @@ impl<'info> DonateCompressed<'info> {
         campaign_id: u64,
         title: String,
         proof_data: Vec<u8>,
+        recent_slot: u64,
     ) -> Result<()> {
+        self.global_config.require_not_paused()?;
+        let output_queue = self.output_queue.as_ref().map(|q| q.key());
+        let tree_type = validate_tree_queue(&self.merkle_tree.to_account_info(), output_queue)?;
+        require!(
+            tree_type.is_batched() == self.campaign_account_info.is_batched,
+            ErrorCode::TreeTypeMismatch
+        );
+        self.campaign_account_info.validate_output_queue(output_queue)?;
+
+        // Limit the replay window of a signed binding to a few recent slots; the
+        // slot is part of the signed message, so it cannot be refreshed by the caller
+        require!(
+            Self::proof_slot_valid(recent_slot, Clock::get()?.slot),
+            ErrorCode::ProofExpired
+        );
+
         // STEP 1: Verify the proof data is not empty
         msg!("Verifying ZK proof for donation...");
         if proof_data.is_empty() {
@@ -135,43 +274,71 @@ impl<'info> DonateCompressed<'info> {
         // STEP 2: Extract donation data from the proof
         // In a real implementation, this would involve more sophisticated parsing
         // based on the ZK circuit's public inputs structure
-        let donation_data = self.extract_donation_data(&proof_data)?;
-        
-        msg!("Donation amount extracted from proof: {}", donation_data.amount);
+        let donation_data = Self::extract_donation_data(&proof_data)?;
+        let public_inputs = &donation_data.public_inputs;
+        
+        msg!("Donation amount extracted from proof: {}", public_inputs.amount);
+        self.campaign_account_info.validate_donation_amount(public_inputs.amount)?;
+        require!(public_inputs.campaign_id == campaign_id, ErrorCode::ProofCampaignMismatch);
+        self.verify_commitment_binding(&public_inputs.donor_commitment, recent_slot)?;
+        self.claim_commitment(&public_inputs.donor_commitment)?;
+        require!(
+            donation_data.leaf_version == self.campaign_account_info.leaf_version,
+            ErrorCode::LeafVersionMismatch
+        );
+
+        // Reject proofs carrying a timestamp too far from the on-chain clock
+        self.global_config.validate_proof_timestamp(donation_data.timestamp, Clock::get()?.unix_timestamp)?;
+
+        // Lock the claimed amount in real tokens before the leaf is appended
+        self.lock_donated_tokens(public_inputs.amount)?;
         
         // STEP 3: Format the donation data as a leaf for the Merkle tree
-        let donation_leaf = DonationLeaf::new(&donation_data, campaign_id);
+        let donation_leaf = DonationLeaf::new(&donation_data);
         let leaf_data = donation_leaf.serialize()?;
+        // The tree stores 32-byte leaves, so the hash is both what gets appended
+        // below and what clients insert into their local copy
+        let leaf_hash = DonationLeaf::hash(&leaf_data);
         
         msg!("Donation leaf formatted for Merkle tree insertion");
         
-        // STEP 4: Prepare the CPI to Light Protocol's batch_append
+        // STEP 4: Prepare the CPI to Light Protocol's append instruction for this tree type
         let campaign = &mut self.campaign_account_info;
-        
         let cpi_program = self.light_account_compression_program.to_account_info();
-        let cpi_accounts = BatchAppend {
-            authority: campaign.to_account_info(), // Campaign is the authority
-            merkle_tree: self.merkle_tree.to_account_info(),
-            log_wrapper: campaign.to_account_info(), // Using campaign as log wrapper
-            queue: self.output_queue.as_ref().map(|q| q.to_account_info()),
-            registered_program_pda: None, // Not using registered program
-        };
         
         // Derive PDA signer seeds for the campaign account
         let campaign_seeds = &[
             campaign_id.to_le_bytes().as_ref(),
-            title.as_bytes().as_ref(),
+            canonical_title(&title).as_bytes(),
             &[*self.ctx.bumps.get("campaign_account_info").unwrap()]
         ];
         let signer_seeds = &[&campaign_seeds[..]];
         
-        // STEP 5: Call batch_append to append the donation data to the Merkle tree
+        // STEP 5: Append the leaf hash, the same value emitted as leaf_hash
         msg!("Appending donation data to Merkle tree...");
         
-        // We now use our formatted leaf_data instead of the raw proof_data
-        batch_append(
-            CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds),
-            leaf_data // The formatted leaf data is passed to batch_append
+        // Batched trees take the leaf into their output queue, which the forester later
+        // settles with batch_append; concurrent trees take it directly
+        let append_target = match tree_type {
+            TreeType::Batched => self
+                .output_queue
+                .as_ref()
+                .ok_or(error!(ErrorCode::OutputQueueRequired))?
+                .to_account_info(),
+            TreeType::Concurrent => self.merkle_tree.to_account_info(),
+        };
+        // The append gives the leaf the target's current next_index
+        let leaf_index = next_leaf_index(tree_type, &append_target)?;
+        let cpi_accounts = AppendLeaves {
+            fee_payer: self.donor.to_account_info(),
+            authority: campaign.to_account_info(), // Campaign is the authority
+            registered_program_pda: None, // Not using registered program
+            system_program: self.system_program.to_account_info(),
+        };
+        append_leaves_to_merkle_trees(
+            CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds)
+                .with_remaining_accounts(vec![append_target]),
+            vec![(0, leaf_hash)]
         ).map_err(|e| {
             msg!("Error appending to Merkle tree: {:?}", e);
             ErrorCode::MerkleTreeUpdateFailed
@@ -179,7 +346,7 @@ impl<'info> DonateCompressed<'info> {
         
         // STEP 6: Get updated Merkle root from Light Protocol
         // In a full implementation, we would extract the new Merkle root from the event logs
-        // or from batch_append return data. Since this requires more advanced event parsing,
+        // or from the append CPI's return data. Since this requires more advanced event parsing,
         // for this implementation we'll assume the Merkle tree account itself has been
         // updated with the new root by Light Protocol.
         
@@ -187,85 +354,244 @@ impl<'info> DonateCompressed<'info> {
         
         // For a real implementation, this would get the actual root
         // Instead, we're just acknowledging that Light Protocol has updated
-        // the merkle_tree account that we passed to batch_append
-        let updated_merkle_tree_info = self.extract_merkle_tree_update()?;
+        // the merkle_tree account that we passed to the append CPI
+        let updated_merkle_tree_info = self.extract_merkle_tree_update(leaf_index)?;
         
         msg!("New Merkle root retrieved. Leaf index: {}", updated_merkle_tree_info.leaf_index);
         
         // STEP 7: Update campaign state with new Merkle root and donation information
         self.update_campaign_state(&updated_merkle_tree_info, &donation_data)?;
-        
-        // STEP 8: Emit an event for successful donation (useful for clients tracking donations)
-        emit!(DonationProcessedEvent {
-            campaign_id,
-            donor: self.donor.key(),
-            amount: donation_data.amount,
-            timestamp: donation_data.timestamp,
-            leaf_index: updated_merkle_tree_info.leaf_index,
-            merkle_root: updated_merkle_tree_info.new_merkle_root,
-        });
+        self.protocol_stats.record_donation(donation_data.public_inputs.amount)?;
+        // Compressed donations keep no per-campaign donor record, so they add to
+        // lifetime_donated without counting towards campaigns_supported
+        self.donor_stats.record_donation(self.donor.key(), donation_data.public_inputs.amount, false)?;
+        self.donor_stats.record_commitment(donation_data.public_inputs.donor_commitment)?;
+        
+        // STEP 8: Emit an event for successful donation (useful for clients tracking donations),
+        // unless the campaign has opted out of per-donation events
+        if self.campaign_account_info.emit_events {
+            emit!(DonationProcessedEvent {
+                campaign_id,
+                donor: self.donor.key(),
+                amount: donation_data.public_inputs.amount,
+                timestamp: donation_data.timestamp,
+                leaf_index: updated_merkle_tree_info.leaf_index,
+                merkle_root: updated_merkle_tree_info.new_merkle_root,
+                leaf_hash,
+                symbol: self.campaign_account_info.symbol.clone(),
+            });
+        }
         
         msg!("Compressed donation successfully processed for campaign: {}", title);
         msg!("Updated total donations: {}", campaign.total_donation_received);
         msg!("Updated donation count: {}", campaign.donation_count);
+
+        // Echo the parsed amount and appended leaf for simulateTransaction callers
+        let result = DonateCompressedResult {
+            amount: donation_data.public_inputs.amount,
+            leaf_index: updated_merkle_tree_info.leaf_index,
+            leaf_hash,
+        };
+        set_return_data(&result.try_to_vec()?);
         
         Ok(())
     }
     
+    /// A proof's recent_slot must not be in the future nor more than MAX_PROOF_SLOT_AGE slots old
+    fn proof_slot_valid(recent_slot: u64, current_slot: u64) -> bool {
+        current_slot
+            .checked_sub(recent_slot)
+            .is_some_and(|age| age <= MAX_PROOF_SLOT_AGE)
+    }
+
+    /// Message the donor signs to claim `donor_commitment` at `recent_slot`:
+    /// `donor || donor_commitment || recent_slot (u64 LE)`
+    pub fn commitment_binding_message(donor: &Pubkey, donor_commitment: &[u8; 32], recent_slot: u64) -> [u8; 72] {
+        let mut message = [0u8; 72];
+        message[..32].copy_from_slice(donor.as_ref());
+        message[32..64].copy_from_slice(donor_commitment);
+        message[64..].copy_from_slice(&recent_slot.to_le_bytes());
+        message
+    }
+
+    /// Require the instruction before this one to be an ed25519 signature by the
+    /// signer over `commitment_binding_message`, so no one can donate under
+    /// another donor's commitment or reuse a binding past its slot window
+    fn verify_commitment_binding(&self, donor_commitment: &[u8; 32], recent_slot: u64) -> Result<()> {
+        let sysvar = self.instructions_sysvar.to_account_info();
+        let current_index = sysvar_instructions::load_current_index_checked(&sysvar)?;
+        let binding_index = current_index
+            .checked_sub(1)
+            .ok_or(error!(ErrorCode::CommitmentOwnershipUnverified))?;
+        let binding_ix = sysvar_instructions::load_instruction_at_checked(usize::from(binding_index), &sysvar)?;
+
+        require!(
+            Self::commitment_binding_valid(&binding_ix, &self.donor.key(), donor_commitment, recent_slot),
+            ErrorCode::CommitmentOwnershipUnverified
+        );
+        Ok(())
+    }
+
+    /// Record the signer as the owner of `donor_commitment` on its first use, and
+    /// reject it for every other signer afterwards
+    ///
+    /// A signature over `donor || commitment` alone only shows the signer chose
+    /// to claim the commitment, not that they can open it; without this record
+    /// anyone could bind a commitment already seen on-chain to themselves.
+    fn claim_commitment(&self, donor_commitment: &[u8; 32]) -> Result<()> {
+        let owner_account = self.commitment_owner.to_account_info();
+        let (expected, bump) = Pubkey::find_program_address(&[b"commitment", donor_commitment], &crate::ID);
+        require_keys_eq!(owner_account.key(), expected, ErrorCode::CommitmentOwnershipUnverified);
+
+        if owner_account.owner == &crate::ID {
+            let record = CommitmentOwner::try_deserialize(&mut &owner_account.try_borrow_data()?[..])?;
+            require_keys_eq!(record.owner, self.donor.key(), ErrorCode::CommitmentOwnershipUnverified);
+            return Ok(());
+        }
+
+        create_pda_account(
+            &self.donor.to_account_info(),
+            &owner_account,
+            &self.system_program.to_account_info(),
+            8 + CommitmentOwner::INIT_SPACE,
+            &[b"commitment".as_ref(), donor_commitment.as_ref(), &[bump]],
+        )?;
+        let record = CommitmentOwner {
+            commitment: *donor_commitment,
+            owner: self.donor.key(),
+            first_used_at: Clock::get()?.unix_timestamp,
+        };
+        record.try_serialize(&mut &mut owner_account.try_borrow_mut_data()?[..])?;
+        Ok(())
+    }
+
+    /// Whether `ix` is an ed25519 instruction in which `donor` signed
+    /// `commitment_binding_message`
+    fn commitment_binding_valid(ix: &Instruction, donor: &Pubkey, donor_commitment: &[u8; 32], recent_slot: u64) -> bool {
+        let expected_message = Self::commitment_binding_message(donor, donor_commitment, recent_slot);
+        Self::ed25519_signature_valid(ix, donor, &expected_message)
+    }
+
+    /// Whether `ix` is a single-signature ed25519 program instruction in which
+    /// `signer` signed `expected_message`, with every offset pointing into the
+    /// instruction itself
+    ///
+    /// The ed25519 program has already checked the signature when this runs;
+    /// only the signed public key and message need matching here.
+    pub(crate) fn ed25519_signature_valid(ix: &Instruction, signer: &Pubkey, expected_message: &[u8]) -> bool {
+        // [num_signatures u8 | padding u8 | signature_offset, signature_ix_index,
+        //  public_key_offset, public_key_ix_index, message_offset, message_size,
+        //  message_ix_index: u16 each]
+        let data = &ix.data;
+        if ix.program_id != ed25519_program::ID || data.len() < 16 || data[0] != 1 {
+            return false;
+        }
+        let read_u16 = |at: usize| usize::from(u16::from_le_bytes([data[at], data[at + 1]]));
+
+        // u16::MAX instruction indices refer to the ed25519 instruction itself
+        let self_referencing = [4, 8, 14].iter().all(|&at| read_u16(at) == usize::from(u16::MAX));
+        let public_key_offset = read_u16(6);
+        let message_offset = read_u16(10);
+        let message_size = read_u16(12);
+
+        self_referencing
+            && data.get(public_key_offset..public_key_offset + 32) == Some(signer.as_ref())
+            && message_size == expected_message.len()
+            && data.get(message_offset..message_offset + message_size) == Some(expected_message)
+    }
+
+    /// Transfer `amount` from the donor's token account into the campaign's,
+    /// rejecting proofs that claim more than the donor holds
+    fn lock_donated_tokens(&self, amount: u64) -> Result<()> {
+        require!(self.donor_token_account.amount >= amount, ErrorCode::InsufficientDonorBalance);
+
+        let cpi_accounts = TransferChecked {
+            from: self.donor_token_account.to_account_info(),
+            to: self.campaign_token_account.to_account_info(),
+            mint: self.mint.to_account_info(),
+            authority: self.donor.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(self.token_program.to_account_info(), cpi_accounts);
+        transfer_checked(cpi_ctx, amount, self.mint.decimals)
+    }
+    
     /// Extract donation data from the proof
     /// 
     /// In a real implementation, this would parse the proof according to
     /// the circuit's public inputs format. For this example, we use a simple
     /// encoding format for demonstration.
-    fn extract_donation_data(&self, proof_data: &[u8]) -> Result<DonationData> {
+    pub fn extract_donation_data(proof_data: &[u8]) -> Result<DonationData> {
         // For this example, we assume a simplified encoding:
-        // - First 8 bytes: donation amount (u64)
+        // - First byte: leaf schema version (u8)
+        // - Next 8 bytes: donation amount (u64)
         // - Next 32 bytes: donor commitment (32-byte array)
+        // - Next 32 bytes: nullifier (32-byte array)
+        // - Next 8 bytes: campaign id (u64)
         // - Next 8 bytes: timestamp (i64)
         // 
         // In a real implementation, this would involve proper deserialization
         // of the proof's public inputs according to the circuit's structure
         
-        if proof_data.len() < 48 { // 8 + 32 + 8 = 48 bytes minimum
+        if proof_data.len() < 89 { // 1 + 8 + 32 + 32 + 8 + 8 = 89 bytes minimum
             return err!(ErrorCode::InvalidProofFormat);
         }
         
-        // Extract donation amount (first 8 bytes)
-        let mut amount_bytes = [0u8; 8];
-        amount_bytes.copy_from_slice(&proof_data[0..8]);
-        let amount = u64::from_le_bytes(amount_bytes);
+        // Every field is read through proof_field, so if the length check above and
+        // the offsets below ever disagree the proof is rejected instead of panicking
+
+        // Extract leaf schema version (first byte)
+        let [leaf_version] = Self::proof_field::<1>(proof_data, 0)?;
+        
+        // Extract donation amount (next 8 bytes)
+        let amount = u64::from_le_bytes(Self::proof_field(proof_data, 1)?);
         
         // Extract donor commitment (next 32 bytes)
-        let mut donor_commitment = [0u8; 32];
-        donor_commitment.copy_from_slice(&proof_data[8..40]);
+        let donor_commitment = Self::proof_field(proof_data, 9)?;
+        
+        // Extract nullifier (next 32 bytes)
+        let nullifier = Self::proof_field(proof_data, 41)?;
+        
+        // Extract campaign id (next 8 bytes)
+        let campaign_id = u64::from_le_bytes(Self::proof_field(proof_data, 73)?);
         
         // Extract timestamp (next 8 bytes)
-        let mut timestamp_bytes = [0u8; 8];
-        timestamp_bytes.copy_from_slice(&proof_data[40..48]);
-        let timestamp = i64::from_le_bytes(timestamp_bytes);
+        let timestamp = i64::from_le_bytes(Self::proof_field(proof_data, 81)?);
         
         Ok(DonationData {
-            amount,
-            donor_commitment,
+            leaf_version,
+            public_inputs: DonationPublicInputs {
+                amount,
+                donor_commitment,
+                nullifier,
+                campaign_id,
+            },
             timestamp,
         })
     }
     
-    /// Extract the updated Merkle tree information after a successful batch_append
+    /// The `N` bytes of `proof_data` starting at `offset`, or InvalidProofFormat
+    /// if they run past the end of the proof
+    fn proof_field<const N: usize>(proof_data: &[u8], offset: usize) -> Result<[u8; N]> {
+        proof_data
+            .get(offset..offset.saturating_add(N))
+            .and_then(|bytes| <[u8; N]>::try_from(bytes).ok())
+            .ok_or(error!(ErrorCode::InvalidProofFormat))
+    }
+
+    /// Extract the updated Merkle tree information after a successful append
     /// In a real implementation, this would parse event logs or return data
-    /// from the batch_append CPI to get the updated root and leaf index
-    fn extract_merkle_tree_update(&self) -> Result<MerkleTreeUpdate> {
+    /// from the append CPI to get the updated root; `leaf_index` is the
+    /// next_index read from the append target before the CPI
+    fn extract_merkle_tree_update(&self, leaf_index: u64) -> Result<MerkleTreeUpdate> {
         // For this implementation, we'll use a mock Merkle root
         // In a real implementation, you would:
-        // 1. Parse the event logs from batch_append
+        // 1. Parse the event logs from the append CPI
         // 2. Extract the new Merkle root
         // 3. Update the campaign state
         
-        // Mock values for demonstration
-        let leaf_index = 0; // Would typically be the index of the newly added leaf
         let timestamp = Clock::get()?.unix_timestamp;
         
-        // Mock new Merkle root - in reality, this would come from the batch_append result
+        // Mock new Merkle root - in reality, this would come from the append result
         let new_merkle_root = [42u8; 32]; // Mock root for demonstration
         
         Ok(MerkleTreeUpdate {
@@ -282,15 +608,9 @@ impl<'info> DonateCompressed<'info> {
         // Update campaign state with new Merkle root
         campaign.latest_merkle_root = merkle_update.new_merkle_root;
         
-        // Update donation statistics
-        campaign.total_donation_received = campaign.total_donation_received.checked_add(donation_data.amount)
-            .ok_or(error!(ErrorCode::ArithmeticOverflow))?;
-        
-        campaign.donation_count = campaign.donation_count.checked_add(1)
-            .ok_or(error!(ErrorCode::ArithmeticOverflow))?;
-        
-        // Update timestamp
-        campaign.last_update_time = merkle_update.timestamp;
+        // Update donation statistics; last_update_time comes from the Clock sysvar,
+        // never from the proof or tree, so callers cannot manipulate it
+        apply_donation(campaign, self.donor.key(), donation_data.public_inputs.amount)?;
         
         msg!("Campaign state updated with new Merkle root and donation information");
         Ok(())
@@ -303,26 +623,12 @@ pub struct DonationProcessedEvent {
     pub campaign_id: u64,
     pub donor: Pubkey,
     pub amount: u64,
+    // Timestamp claimed by the proof, informational only
     pub timestamp: i64,
     pub leaf_index: u64,
     pub merkle_root: [u8; 32],
+    // Leaf appended at leaf_index: the hash of the serialized leaf, see DonationLeaf::hash
+    pub leaf_hash: [u8; 32],
+    // Display symbol of the campaign mint, empty if unset
+    pub symbol: String,
 }
-
-/// Custom error codes for the donate_compressed instruction
-#[error_code]
-pub enum ErrorCode {
-    #[msg("Invalid proof data")]
-    InvalidProofData,
-    
-    #[msg("Invalid proof format")]
-    InvalidProofFormat,
-    
-    #[msg("Failed to update Merkle tree")]
-    MerkleTreeUpdateFailed,
-    
-    #[msg("Failed to update campaign state")]
-    CampaignUpdateFailed,
-    
-    #[msg("Arithmetic overflow")]
-    ArithmeticOverflow,
-} 
\ No newline at end of file
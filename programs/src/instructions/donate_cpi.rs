@@ -0,0 +1,138 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program_option::COption;
+use anchor_spl::{associated_token::AssociatedToken, token::*};
+
+use crate::error::ErrorCode;
+use crate::instructions::TokenDonation;
+use crate::state::{
+    CampaignInfo, DonerInfo, DonorGlobalStats, GlobalConfig, KycAttestation, ProtocolStats, RecentDonations,
+};
+use crate::util::canonical_title;
+
+#[derive(Accounts)]
+#[instruction(campaign_id: u64, title: String)]
+pub struct DonateCpi<'info> {
+    /// Approved delegate of the donor's token account, typically a PDA of the
+    /// calling program signing through invoke_signed; pays nothing unless also
+    /// passed as `payer`
+    pub delegate: Signer<'info>,
+
+    /// Pays rent for the donor stats and recent donations accounts when they
+    /// are first created, e.g. the user or the calling program's fee payer
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [campaign_id.to_le_bytes().as_ref(), canonical_title(&title).as_bytes()],
+        bump,
+        has_one = mint @ ErrorCode::MintMismatch,
+    )]
+    pub campaign_account_info: Account<'info, CampaignInfo>,
+
+    /// Donor's token account the delegate was approved on; its owner is credited
+    #[account(mut, token::mint = mint)]
+    pub doner_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = campaign_account_info.creator,
+    )]
+    pub campaign_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"doner", campaign_account_info.key().as_ref(), doner_token_account.owner.as_ref()],
+        bump
+    )]
+    pub doner_account_info: Account<'info, DonerInfo>,
+
+    #[account(
+        mut,
+        seeds = [b"protocol_stats"],
+        bump
+    )]
+    pub protocol_stats: Account<'info, ProtocolStats>,
+
+    #[account(
+        seeds = [b"global_config"],
+        bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        seeds = [b"donor_stats", doner_token_account.owner.as_ref()],
+        bump,
+        space = 8 + DonorGlobalStats::INIT_SPACE,
+    )]
+    pub donor_stats: Account<'info, DonorGlobalStats>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        seeds = [b"recent", campaign_account_info.key().as_ref()],
+        bump,
+        space = 8 + RecentDonations::INIT_SPACE,
+    )]
+    pub recent_donations: Account<'info, RecentDonations>,
+
+    /// Credited donor's KYC attestation, only required above the campaign's kyc_threshold
+    #[account(
+        seeds = [b"kyc_attestation", doner_token_account.owner.as_ref()],
+        bump
+    )]
+    pub kyc_attestation: Option<Account<'info, KycAttestation>>,
+
+    /// Credited donor's token account for the campaign's gate_mint, required on token-gated campaigns
+    pub gate_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// Referrer's token account for the campaign mint, required when the campaign charges a platform fee
+    #[account(mut)]
+    pub platform_token_account: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+
+    pub system_program: Program<'info, System>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+impl<'info> DonateCpi<'info> {
+    /// Donate from a token account that approved the signer as its delegate,
+    /// crediting the account's owner
+    ///
+    /// Lets another program (e.g. a DEX routing part of a swap) donate on a
+    /// user's behalf: the user approves the program's PDA with `approve`, and
+    /// the program invokes this instruction with that PDA as `delegate`.
+    pub fn donate_cpi(&mut self, donation_amount: u64) -> Result<()> {
+        let approved = self.doner_token_account.delegate == COption::Some(self.delegate.key())
+            && self.doner_token_account.delegated_amount >= donation_amount;
+        require!(approved, ErrorCode::DelegateApprovalInsufficient);
+
+        let credited_donor = self.doner_token_account.owner;
+        TokenDonation {
+            doner: &self.delegate,
+            mint: &self.mint,
+            campaign_account_info: &mut self.campaign_account_info,
+            doner_token_account: &self.doner_token_account,
+            campaign_token_account: &mut self.campaign_token_account,
+            doner_account_info: &mut self.doner_account_info,
+            protocol_stats: &mut self.protocol_stats,
+            global_config: &self.global_config,
+            donor_stats: &mut self.donor_stats,
+            recent_donations: &mut self.recent_donations,
+            kyc_attestation: self.kyc_attestation.as_ref(),
+            payer_kyc_attestation: None,
+            gate_token_account: self.gate_token_account.as_ref(),
+            platform_token_account: self.platform_token_account.as_ref(),
+            token_program: &self.token_program,
+        }
+        .process(donation_amount, credited_donor, crate::instruction::DonateCpi::DISCRIMINATOR)
+    }
+}
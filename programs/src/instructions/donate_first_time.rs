@@ -0,0 +1,136 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{associated_token::AssociatedToken, token::*};
+
+use crate::constants::TIER_NONE;
+use crate::error::ErrorCode;
+use crate::instructions::TokenDonation;
+use crate::state::{
+    CampaignInfo, DonerInfo, DonorGlobalStats, GlobalConfig, KycAttestation, ProtocolStats, RecentDonations,
+};
+use crate::util::canonical_title;
+
+#[derive(Accounts)]
+#[instruction(campaign_id: u64, title: String)]
+pub struct DonateFirstTime<'info> {
+    #[account(mut)]
+    pub doner: Signer<'info>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [campaign_id.to_le_bytes().as_ref(), canonical_title(&title).as_bytes()],
+        bump,
+        has_one = mint @ ErrorCode::MintMismatch,
+    )]
+    pub campaign_account_info: Account<'info, CampaignInfo>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = doner,
+    )]
+    pub doner_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = campaign_account_info.creator,
+    )]
+    pub campaign_token_account: Account<'info, TokenAccount>,
+
+    /// Signer's doner account, created here. `init` rather than `init_if_needed`,
+    /// so an existing donor cannot have their amount and tier reset; they use donate_amount.
+    #[account(
+        init,
+        payer = doner,
+        seeds = [b"doner", campaign_account_info.key().as_ref(), doner.key().as_ref()],
+        bump,
+        space = 8 + DonerInfo::INIT_SPACE
+    )]
+    pub doner_account_info: Account<'info, DonerInfo>,
+
+    #[account(
+        mut,
+        seeds = [b"protocol_stats"],
+        bump
+    )]
+    pub protocol_stats: Account<'info, ProtocolStats>,
+
+    #[account(
+        seeds = [b"global_config"],
+        bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    /// Signer's lifetime stats, created on their first donation
+    #[account(
+        init_if_needed,
+        payer = doner,
+        seeds = [b"donor_stats", doner.key().as_ref()],
+        bump,
+        space = 8 + DonorGlobalStats::INIT_SPACE,
+    )]
+    pub donor_stats: Account<'info, DonorGlobalStats>,
+
+    /// Campaign's recent-activity feed, created by its first token donation
+    #[account(
+        init_if_needed,
+        payer = doner,
+        seeds = [b"recent", campaign_account_info.key().as_ref()],
+        bump,
+        space = 8 + RecentDonations::INIT_SPACE,
+    )]
+    pub recent_donations: Account<'info, RecentDonations>,
+
+    /// Signer's KYC attestation, only required above the campaign's kyc_threshold
+    #[account(
+        seeds = [b"kyc_attestation", doner.key().as_ref()],
+        bump
+    )]
+    pub kyc_attestation: Option<Account<'info, KycAttestation>>,
+
+    /// Signer's token account for the campaign's gate_mint, required on token-gated campaigns
+    pub gate_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// Referrer's token account for the campaign mint, required when the campaign charges a platform fee
+    #[account(mut)]
+    pub platform_token_account: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+
+    pub system_program: Program<'info, System>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+impl<'info> DonateFirstTime<'info> {
+    /// Create the signer's doner account and donate `donation_amount` in one instruction
+    pub fn donate_first_time(&mut self, donation_amount: u64) -> Result<()> {
+        let doner_info = &mut self.doner_account_info;
+        doner_info.doner = self.doner.key();
+        doner_info.amount = 0;
+        doner_info.campaign = self.campaign_account_info.key();
+        doner_info.tier = TIER_NONE;
+
+        TokenDonation {
+            doner: &self.doner,
+            mint: &self.mint,
+            campaign_account_info: &mut self.campaign_account_info,
+            doner_token_account: &self.doner_token_account,
+            campaign_token_account: &mut self.campaign_token_account,
+            doner_account_info: &mut self.doner_account_info,
+            protocol_stats: &mut self.protocol_stats,
+            global_config: &self.global_config,
+            donor_stats: &mut self.donor_stats,
+            recent_donations: &mut self.recent_donations,
+            kyc_attestation: self.kyc_attestation.as_ref(),
+            payer_kyc_attestation: None,
+            gate_token_account: self.gate_token_account.as_ref(),
+            platform_token_account: self.platform_token_account.as_ref(),
+            token_program: &self.token_program,
+        }
+        .process(donation_amount, self.doner.key(), crate::instruction::DonateFirstTime::DISCRIMINATOR)
+    }
+}
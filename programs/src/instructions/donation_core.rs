@@ -0,0 +1,127 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ErrorCode;
+use crate::state::CampaignInfo;
+
+/// Apply a donation of `amount` from `donor` to the campaign state
+///
+/// Shared by every donation instruction so that totals, counters and
+/// timestamps are updated the same way regardless of how the funds arrived.
+/// Rejects donations to a paused or not yet started campaign, or that would take
+/// the total past the hard cap, and emits a `GoalReachedEvent` when this donation
+/// takes the campaign from below its soft goal (or goal, if no soft goal is set)
+/// to at or above it.
+/// A donation that exceeds the circuit breaker's rate still lands, but pauses the
+/// campaign for every later one until the creator or admin unpauses it.
+/// GoalReachedEvent and CircuitBreakerTrippedEvent mark one-off state changes
+/// rather than individual donations, so they fire whatever `emit_events` says.
+/// Donations to a failed campaign, or one past its deadline below goal, are rejected.
+pub fn apply_donation(campaign: &mut Account<'_, CampaignInfo>, donor: Pubkey, amount: u64) -> Result<()> {
+    require!(!campaign.paused, ErrorCode::CampaignPaused);
+    let now = Clock::get()?.unix_timestamp;
+    require!(campaign.has_started(now), ErrorCode::CampaignNotStarted);
+    require!(!campaign.is_failed && !campaign.goal_missed(now), ErrorCode::CampaignFailed);
+    let previous_total = campaign.total_donation_received;
+
+    let new_total = previous_total.checked_add(amount)
+        .ok_or(error!(ErrorCode::ArithmeticOverflow))?;
+    require!(
+        campaign.hard_cap == 0 || new_total <= campaign.hard_cap,
+        ErrorCode::HardCapExceeded
+    );
+    campaign.total_donation_received = new_total;
+    campaign.ever_received = true;
+
+    campaign.donation_count = campaign.donation_count.checked_add(1)
+        .ok_or(error!(ErrorCode::ArithmeticOverflow))?;
+
+    campaign.last_update_time = now;
+
+    if campaign.record_donation_rate(campaign.last_update_time) {
+        campaign.paused = true;
+        emit!(CircuitBreakerTrippedEvent {
+            campaign: campaign.key(),
+            threshold: campaign.circuit_breaker_threshold,
+            window_count: campaign.breaker_window_count,
+            window_start: campaign.breaker_window_start,
+            timestamp: campaign.last_update_time,
+        });
+        msg!(
+            "Circuit breaker tripped: {} donations since {}",
+            campaign.breaker_window_count, campaign.breaker_window_start
+        );
+    }
+
+    let goal_amount = campaign.celebrated_goal();
+    if goal_amount > 0 && previous_total < goal_amount && campaign.total_donation_received >= goal_amount {
+        emit!(GoalReachedEvent {
+            creator: campaign.creator,
+            title: campaign.title.clone(),
+            goal_amount,
+            total_donation_received: campaign.total_donation_received,
+            decimals: campaign.mint_decimals,
+            donor,
+            timestamp: campaign.last_update_time,
+        });
+    }
+
+    Ok(())
+}
+
+/// Latch `is_failed` once the deadline has passed with the goal missed
+///
+/// Called lazily by instructions that succeed on a failed campaign, so the flag
+/// is set (and CampaignFailedEvent emitted) exactly once and never cleared.
+/// Returns whether the campaign is failed.
+pub fn mark_failed_if_expired(campaign: &mut Account<'_, CampaignInfo>, now: i64) -> bool {
+    if !campaign.is_failed && campaign.goal_missed(now) {
+        campaign.is_failed = true;
+        emit!(CampaignFailedEvent {
+            campaign: campaign.key(),
+            goal_amount: campaign.goal_amount,
+            total_donation_received: campaign.total_donation_received,
+            deadline: campaign.deadline,
+            timestamp: now,
+        });
+        msg!(
+            "Campaign failed: {} of {} raised by deadline {}",
+            campaign.total_donation_received, campaign.goal_amount, campaign.deadline
+        );
+    }
+    campaign.is_failed
+}
+
+/// Event emitted when a donation takes a campaign to its soft goal (or goal)
+#[event]
+pub struct GoalReachedEvent {
+    pub creator: Pubkey,
+    pub title: String,
+    // Threshold that was crossed: the soft goal if set, otherwise goal_amount
+    pub goal_amount: u64,
+    pub total_donation_received: u64,
+    // Decimals of the campaign mint, for formatting the base-unit amounts above
+    pub decimals: u8,
+    pub donor: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Event emitted when a burst of donations auto-pauses a campaign
+#[event]
+pub struct CircuitBreakerTrippedEvent {
+    pub campaign: Pubkey,
+    pub threshold: u32,
+    // Donations counted in the window, including the one that tripped the breaker
+    pub window_count: u32,
+    pub window_start: i64,
+    pub timestamp: i64,
+}
+
+/// Event emitted once when a campaign is found past its deadline below goal
+#[event]
+pub struct CampaignFailedEvent {
+    pub campaign: Pubkey,
+    pub goal_amount: u64,
+    pub total_donation_received: u64,
+    pub deadline: i64,
+    pub timestamp: i64,
+}
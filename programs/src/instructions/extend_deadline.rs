@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ErrorCode;
+use crate::state::{CampaignInfo, GlobalConfig};
+use crate::util::canonical_title;
+
+#[derive(Accounts)]
+#[instruction(campaign_id: u64, title: String)]
+pub struct ExtendDeadline<'info> {
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [campaign_id.to_le_bytes().as_ref(), canonical_title(&title).as_bytes()],
+        bump,
+        has_one = creator @ ErrorCode::Unauthorized,
+    )]
+    pub campaign_account_info: Account<'info, CampaignInfo>,
+
+    #[account(
+        seeds = [b"global_config"],
+        bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+}
+
+impl<'info> ExtendDeadline<'info> {
+    /// Move the campaign deadline later; it can never be shortened or set in the past
+    ///
+    /// Campaigns created without a deadline (deadline == 0) are open-ended and
+    /// have nothing to extend.
+    pub fn extend_deadline(&mut self, new_deadline: i64) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let campaign = &mut self.campaign_account_info;
+        require!(campaign.deadline != 0, ErrorCode::NoDeadlineToExtend);
+        // A missed goal is final; extending the deadline cannot revive the campaign
+        require!(!campaign.is_failed && !campaign.goal_missed(now), ErrorCode::CampaignFailed);
+        require!(
+            new_deadline > campaign.deadline && new_deadline > now,
+            ErrorCode::InvalidDeadlineExtension
+        );
+        self.global_config.validate_deadline(new_deadline, now)?;
+
+        let old_deadline = campaign.deadline;
+        campaign.deadline = new_deadline;
+        campaign.last_update_time = now;
+
+        emit!(DeadlineExtendedEvent {
+            campaign: campaign.key(),
+            old_deadline,
+            new_deadline,
+            timestamp: now,
+        });
+
+        msg!("Campaign deadline extended from {} to {}", old_deadline, new_deadline);
+        Ok(())
+    }
+}
+
+/// Event emitted when a campaign's deadline is extended
+#[event]
+pub struct DeadlineExtendedEvent {
+    pub campaign: Pubkey,
+    pub old_deadline: i64,
+    pub new_deadline: i64,
+    pub timestamp: i64,
+}
@@ -0,0 +1,158 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{associated_token::AssociatedToken, token::*};
+
+use crate::error::ErrorCode;
+use crate::instructions::TokenDonation;
+use crate::state::{
+    CampaignInfo, DonerInfo, DonorGlobalStats, GlobalConfig, KycAttestation, Pledge, ProtocolStats, RecentDonations,
+};
+use crate::util::canonical_title;
+
+#[derive(Accounts)]
+#[instruction(campaign_id: u64, title: String)]
+pub struct FulfillPledge<'info> {
+    #[account(mut)]
+    pub doner: Signer<'info>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [campaign_id.to_le_bytes().as_ref(), canonical_title(&title).as_bytes()],
+        bump,
+        has_one = mint @ ErrorCode::MintMismatch,
+    )]
+    pub campaign_account_info: Account<'info, CampaignInfo>,
+
+    /// Signer's pledge to this campaign, closed back to them once paid
+    #[account(
+        mut,
+        close = doner,
+        seeds = [b"pledge", campaign_account_info.key().as_ref(), doner.key().as_ref()],
+        bump,
+        constraint = pledge.mint == mint.key() @ ErrorCode::PledgeMintChanged,
+    )]
+    pub pledge: Account<'info, Pledge>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = doner,
+    )]
+    pub doner_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = campaign_account_info.creator,
+    )]
+    pub campaign_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"doner", campaign_account_info.key().as_ref(), doner.key().as_ref()],
+        bump
+    )]
+    pub doner_account_info: Account<'info, DonerInfo>,
+
+    #[account(
+        mut,
+        seeds = [b"protocol_stats"],
+        bump
+    )]
+    pub protocol_stats: Account<'info, ProtocolStats>,
+
+    #[account(
+        seeds = [b"global_config"],
+        bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = doner,
+        seeds = [b"donor_stats", doner.key().as_ref()],
+        bump,
+        space = 8 + DonorGlobalStats::INIT_SPACE,
+    )]
+    pub donor_stats: Account<'info, DonorGlobalStats>,
+
+    #[account(
+        init_if_needed,
+        payer = doner,
+        seeds = [b"recent", campaign_account_info.key().as_ref()],
+        bump,
+        space = 8 + RecentDonations::INIT_SPACE,
+    )]
+    pub recent_donations: Account<'info, RecentDonations>,
+
+    #[account(
+        seeds = [b"kyc_attestation", doner.key().as_ref()],
+        bump
+    )]
+    pub kyc_attestation: Option<Account<'info, KycAttestation>>,
+
+    pub gate_token_account: Option<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub platform_token_account: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+
+    pub system_program: Program<'info, System>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+impl<'info> FulfillPledge<'info> {
+    /// Pay the pledged amount as a regular token donation and close the pledge
+    ///
+    /// The donation goes through the same checks as donate_amount at the time
+    /// of payment, so a campaign that has since closed or capped rejects it.
+    pub fn fulfill_pledge(&mut self) -> Result<()> {
+        let amount = self.pledge.amount;
+        TokenDonation {
+            doner: &self.doner,
+            mint: &self.mint,
+            campaign_account_info: &mut self.campaign_account_info,
+            doner_token_account: &self.doner_token_account,
+            campaign_token_account: &mut self.campaign_token_account,
+            doner_account_info: &mut self.doner_account_info,
+            protocol_stats: &mut self.protocol_stats,
+            global_config: &self.global_config,
+            donor_stats: &mut self.donor_stats,
+            recent_donations: &mut self.recent_donations,
+            kyc_attestation: self.kyc_attestation.as_ref(),
+            payer_kyc_attestation: None,
+            gate_token_account: self.gate_token_account.as_ref(),
+            platform_token_account: self.platform_token_account.as_ref(),
+            token_program: &self.token_program,
+        }
+        .process(amount, self.doner.key(), crate::instruction::FulfillPledge::DISCRIMINATOR)?;
+
+        let campaign = &mut self.campaign_account_info;
+        campaign.pledged_total = campaign.pledged_total.saturating_sub(amount);
+
+        emit!(PledgeFulfilledEvent {
+            campaign: campaign.key(),
+            donor: self.doner.key(),
+            amount,
+            due_time: self.pledge.due_time,
+            pledged_total: campaign.pledged_total,
+            timestamp: campaign.last_update_time,
+        });
+        Ok(())
+    }
+}
+
+/// Event emitted when a pledge is paid in full
+#[event]
+pub struct PledgeFulfilledEvent {
+    pub campaign: Pubkey,
+    pub donor: Pubkey,
+    pub amount: u64,
+    pub due_time: i64,
+    pub pledged_total: u64,
+    pub timestamp: i64,
+}
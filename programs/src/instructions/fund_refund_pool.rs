@@ -0,0 +1,93 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{associated_token::AssociatedToken, token::*};
+
+use crate::error::ErrorCode;
+use crate::instructions::mark_failed_if_expired;
+use crate::state::CampaignInfo;
+use crate::util::canonical_title;
+
+#[derive(Accounts)]
+#[instruction(campaign_id: u64, title: String)]
+pub struct FundRefundPool<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [campaign_id.to_le_bytes().as_ref(), canonical_title(&title).as_bytes()],
+        bump,
+        has_one = creator @ ErrorCode::Unauthorized,
+        has_one = mint @ ErrorCode::MintMismatch,
+    )]
+    pub campaign_account_info: Account<'info, CampaignInfo>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = creator,
+    )]
+    pub creator_token_account: Account<'info, TokenAccount>,
+
+    /// Vault holding the refund pool, owned by the campaign PDA
+    #[account(
+        init_if_needed,
+        payer = creator,
+        associated_token::mint = mint,
+        associated_token::authority = campaign_account_info,
+    )]
+    pub refund_vault: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+impl<'info> FundRefundPool<'info> {
+    /// Move `amount` from the creator into the refund vault, growing the pool
+    /// donors can claim pro-rata shares of
+    ///
+    /// Only failed campaigns can be funded: the vault is only ever drained by
+    /// `refund_proportional`, so tokens sent to a campaign that goes on to
+    /// succeed would be stranded.
+    pub fn fund_refund_pool(&mut self, amount: u64) -> Result<()> {
+        require!(amount > 0, ErrorCode::ZeroRefundPoolFunding);
+        let now = Clock::get()?.unix_timestamp;
+        mark_failed_if_expired(&mut self.campaign_account_info, now);
+        require!(self.campaign_account_info.is_failed, ErrorCode::CampaignNotFailed);
+
+        let cpi_accounts = TransferChecked {
+            from: self.creator_token_account.to_account_info(),
+            to: self.refund_vault.to_account_info(),
+            mint: self.mint.to_account_info(),
+            authority: self.creator.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(self.token_program.to_account_info(), cpi_accounts);
+        transfer_checked(cpi_ctx, amount, self.mint.decimals)?;
+
+        let campaign = &mut self.campaign_account_info;
+        campaign.refund_pool = campaign.refund_pool.checked_add(amount)
+            .ok_or(error!(ErrorCode::ArithmeticOverflow))?;
+
+        emit!(RefundPoolFundedEvent {
+            campaign: campaign.key(),
+            amount,
+            refund_pool: campaign.refund_pool,
+            timestamp: now,
+        });
+
+        msg!("Refund pool funded with {}, now {}", amount, campaign.refund_pool);
+        Ok(())
+    }
+}
+
+/// Event emitted when the creator adds to a campaign's refund pool
+#[event]
+pub struct RefundPoolFundedEvent {
+    pub campaign: Pubkey,
+    pub amount: u64,
+    pub refund_pool: u64,
+    pub timestamp: i64,
+}
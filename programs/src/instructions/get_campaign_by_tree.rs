@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::state::TreeCampaignLink;
+
+#[derive(Accounts)]
+#[instruction(merkle_tree: Pubkey)]
+pub struct GetCampaignByTree<'info> {
+    #[account(
+        seeds = [b"tree_link", merkle_tree.as_ref()],
+        bump
+    )]
+    pub tree_campaign_link: Account<'info, TreeCampaignLink>,
+}
+
+impl<'info> GetCampaignByTree<'info> {
+    /// Emit the campaign owning `merkle_tree` without modifying any state
+    pub fn get_campaign_by_tree(&self) -> Result<()> {
+        let link = &self.tree_campaign_link;
+        emit!(TreeCampaignEvent {
+            merkle_tree: link.merkle_tree,
+            campaign: link.campaign,
+        });
+        Ok(())
+    }
+}
+
+/// Event carrying the campaign a Merkle tree belongs to
+#[event]
+pub struct TreeCampaignEvent {
+    pub merkle_tree: Pubkey,
+    pub campaign: Pubkey,
+}
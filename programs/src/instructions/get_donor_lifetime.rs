@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::state::DonorGlobalStats;
+
+#[derive(Accounts)]
+#[instruction(donor: Pubkey)]
+pub struct GetDonorLifetime<'info> {
+    #[account(
+        seeds = [b"donor_stats", donor.as_ref()],
+        bump
+    )]
+    pub donor_stats: Account<'info, DonorGlobalStats>,
+}
+
+impl<'info> GetDonorLifetime<'info> {
+    /// Emit the donor's lifetime giving without modifying any state
+    pub fn get_donor_lifetime(&self) -> Result<()> {
+        let stats = &self.donor_stats;
+
+        emit!(DonorLifetimeEvent {
+            donor: stats.donor,
+            lifetime_donated: stats.lifetime_donated,
+            campaigns_supported: stats.campaigns_supported,
+        });
+        Ok(())
+    }
+}
+
+/// Event carrying a donor's lifetime giving across all campaigns
+#[event]
+pub struct DonorLifetimeEvent {
+    pub donor: Pubkey,
+    pub lifetime_donated: u64,
+    pub campaigns_supported: u64,
+}
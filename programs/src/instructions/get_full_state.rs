@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+
+use crate::state::CampaignInfo;
+
+#[derive(Accounts)]
+pub struct GetFullState<'info> {
+    pub campaign_account_info: Account<'info, CampaignInfo>,
+}
+
+impl<'info> GetFullState<'info> {
+    /// Emit every campaign counter and status flag in one event without modifying any state
+    pub fn get_full_state(&self) -> Result<()> {
+        let campaign = &self.campaign_account_info;
+
+        emit!(FullStateEvent {
+            campaign: campaign.key(),
+            total_donation_received: campaign.total_donation_received,
+            pledged_total: campaign.pledged_total,
+            refund_pool: campaign.refund_pool,
+            donation_count: campaign.donation_count,
+            unique_donor_count: campaign.unique_donor_count,
+            snapshot_count: campaign.snapshot_count,
+            goal_amount: campaign.goal_amount,
+            soft_goal: campaign.soft_goal,
+            hard_cap: campaign.hard_cap,
+            progress_bps: campaign.progress_bps(),
+            start_time: campaign.start_time,
+            deadline: campaign.deadline,
+            paused: campaign.paused,
+            is_failed: campaign.is_failed,
+            merkle_root: campaign.latest_merkle_root,
+            season: campaign.season,
+            last_update_time: campaign.last_update_time,
+            decimals: campaign.mint_decimals,
+        });
+        Ok(())
+    }
+}
+
+/// Event carrying a complete view of a campaign's counters and status
+#[event]
+pub struct FullStateEvent {
+    pub campaign: Pubkey,
+    pub total_donation_received: u64,
+    // Outstanding pledges, not yet part of total_donation_received
+    pub pledged_total: u64,
+    pub refund_pool: u64,
+    pub donation_count: u64,
+    pub unique_donor_count: u64,
+    pub snapshot_count: u64,
+    pub goal_amount: u64,
+    pub soft_goal: u64,
+    pub hard_cap: u64,
+    // min(10000, total * 10000 / goal), 0 when no goal is configured
+    pub progress_bps: u64,
+    pub start_time: i64,
+    pub deadline: i64,
+    pub paused: bool,
+    // Latched flag; a campaign past its deadline below goal may not be marked yet
+    pub is_failed: bool,
+    pub merkle_root: [u8; 32],
+    pub season: u16,
+    pub last_update_time: i64,
+    // Decimals of the campaign mint, for formatting the amounts above
+    pub decimals: u8,
+}
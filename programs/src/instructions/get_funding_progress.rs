@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::state::CampaignInfo;
+
+#[derive(Accounts)]
+pub struct GetFundingProgress<'info> {
+    pub campaign_account_info: Account<'info, CampaignInfo>,
+}
+
+impl<'info> GetFundingProgress<'info> {
+    /// Emit the campaign's funding progress without modifying any state
+    pub fn get_funding_progress(&self) -> Result<()> {
+        let campaign = &self.campaign_account_info;
+
+        emit!(FundingProgressEvent {
+            campaign: campaign.key(),
+            total: campaign.total_donation_received,
+            goal: campaign.goal_amount,
+            progress_bps: campaign.progress_bps(),
+            goal_configured: campaign.goal_amount > 0,
+            decimals: campaign.mint_decimals,
+        });
+        Ok(())
+    }
+}
+
+/// Event carrying a campaign's funding progress
+#[event]
+pub struct FundingProgressEvent {
+    pub campaign: Pubkey,
+    pub total: u64,
+    pub goal: u64,
+    // min(10000, total * 10000 / goal), 0 when no goal is configured
+    pub progress_bps: u64,
+    pub goal_configured: bool,
+    // Decimals of the campaign mint, for formatting total and goal without a mint fetch
+    pub decimals: u8,
+}
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{RecentDonation, RecentDonations};
+
+#[derive(Accounts)]
+#[instruction(campaign: Pubkey)]
+pub struct GetRecentDonations<'info> {
+    #[account(
+        seeds = [b"recent", campaign.as_ref()],
+        bump
+    )]
+    pub recent_donations: Account<'info, RecentDonations>,
+}
+
+impl<'info> GetRecentDonations<'info> {
+    /// Emit the campaign's recent donations, newest first, without modifying any state
+    pub fn get_recent_donations(&self, campaign: Pubkey) -> Result<()> {
+        emit!(RecentDonationsEvent {
+            campaign,
+            entries: self.recent_donations.newest_first(),
+        });
+        Ok(())
+    }
+}
+
+/// Event carrying a campaign's recent-activity feed
+#[event]
+pub struct RecentDonationsEvent {
+    pub campaign: Pubkey,
+    // Newest first, at most RECENT_DONATIONS_LEN entries
+    pub entries: Vec<RecentDonation>,
+}
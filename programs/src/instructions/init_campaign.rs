@@ -5,26 +5,65 @@ use account_compression::program::AccountCompression;
 use account_compression::cpi::accounts::CreateTree;
 use account_compression::cpi::create_tree;
 
-use crate::state::CampaignInfo;
+use crate::constants::LEAF_VERSION;
+use crate::error::ErrorCode;
+use crate::instructions::{tree_account_size, validate_tree_queue};
+use crate::state::{
+    CampaignInfo, CreatorCampaignLink, CreatorStats, CreatorVerification, GlobalConfig, ProtocolStats, TreeCampaignLink,
+};
+use crate::util::canonical_title;
 
 mod light_programs {
     use anchor_lang::declare_id;
     declare_id!("compr6CUsB5m2jS4Y3831ztGSTnDpnKJTKS95d64XVq");
 }
 
+/// Campaign settings for init_campaign; the campaign id and title are passed
+/// separately because the campaign PDA is derived from them
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct InitCampaignParams {
+    pub description: String,
+    pub max_depth: u32,
+    pub max_buffer_size: u32,
+    pub tier_thresholds: [u64; 3],
+    pub uri: Option<String>,
+    pub goal_amount: u64,
+    pub require_whole_tokens: bool,
+    pub soft_goal: u64,
+    pub hard_cap: u64,
+    pub kyc_threshold: u64,
+    pub allow_self_donation: bool,
+    pub deadline: i64,
+    pub gate_mint: Option<Pubkey>,
+    pub keywords: Vec<String>,
+    pub max_donors: u64,
+    pub platform_referrer: Option<Pubkey>,
+    pub platform_fee_bps: u16,
+    pub circuit_breaker_threshold: u32,
+    pub start_time: i64,
+    pub max_single_donation: u64,
+    pub emit_events: bool,
+    pub symbol: Option<String>,
+}
+
 #[derive(Accounts)]
-#[instruction(campaign_id: u64, title: String, description: String, max_depth: u32, max_buffer_size: u32)]
+#[instruction(campaign_id: u64, title: String)]
 pub struct InitializeCampaign<'info> {
-    #[account(mut)]
+    /// Campaign authority; pays nothing unless also passed as `payer`
     pub creator: Signer<'info>,
 
+    /// Pays rent for every account created here, e.g. a sponsor; pass the
+    /// creator again for a self-funded campaign
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
     #[account(mint::token_program = token_program)]
     pub mint: Account<'info, Mint>,
 
     #[account(
         init,
-        payer = creator,
-        seeds = [campaign_id.to_le_bytes().as_ref(), title.as_bytes().as_ref()],
+        payer = payer,
+        seeds = [campaign_id.to_le_bytes().as_ref(), canonical_title(&title).as_bytes()],
         bump,
         space = 8 + CampaignInfo::INIT_SPACE,
     )]
@@ -32,18 +71,74 @@ pub struct InitializeCampaign<'info> {
 
     #[account(
         init_if_needed,
-        payer = creator,
+        payer = payer,
         associated_token::mint = mint,
         associated_token::authority = creator,
     )]
     pub campaign_token_account: Account<'info, TokenAccount>,
 
+    #[account(
+        init_if_needed,
+        payer = payer,
+        seeds = [b"protocol_stats"],
+        bump,
+        space = 8 + ProtocolStats::INIT_SPACE,
+    )]
+    pub protocol_stats: Account<'info, ProtocolStats>,
+
+    #[account(
+        seeds = [b"global_config"],
+        bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        seeds = [b"creator_stats", creator.key().as_ref()],
+        bump,
+        space = 8 + CreatorStats::INIT_SPACE,
+    )]
+    pub creator_stats: Account<'info, CreatorStats>,
+
+    #[account(
+        init,
+        payer = payer,
+        // Keyed by the creator's campaign index, so indexes 0..campaign_count enumerate every link
+        seeds = [b"creator_campaign", creator.key().as_ref(), creator_stats.campaign_count.to_le_bytes().as_ref()],
+        bump,
+        space = 8 + CreatorCampaignLink::INIT_SPACE,
+    )]
+    pub creator_campaign_link: Account<'info, CreatorCampaignLink>,
+
+    /// Reverse lookup from the new Merkle tree to this campaign
+    #[account(
+        init,
+        payer = payer,
+        seeds = [b"tree_link", merkle_tree.key().as_ref()],
+        bump,
+        space = 8 + TreeCampaignLink::INIT_SPACE,
+    )]
+    pub tree_campaign_link: Account<'info, TreeCampaignLink>,
+
+    /// Creator's verification, only required while GlobalConfig::require_verified_creators is set
+    #[account(
+        seeds = [b"creator_verification", creator.key().as_ref()],
+        bump
+    )]
+    pub creator_verification: Option<Account<'info, CreatorVerification>>,
+
     /// CHECK: The Merkle tree account (tree_config) to be created via CPI.
     /// Authority is the campaign_account_info PDA.
-    /// Payer is the creator.
+    /// Rent is paid by `payer`.
     #[account(mut)]
     pub merkle_tree: UncheckedAccount<'info>,
 
+    /// CHECK: Output queue paired with the Merkle tree, required for batched trees.
+    /// Checked against the tree's metadata by validate_tree_queue and recorded on
+    /// the campaign so donations can only append through this queue.
+    pub output_queue: Option<UncheckedAccount<'info>>,
+
     #[account(address = light_programs::ID)]
     pub light_account_compression_program: Program<'info, AccountCompression>,
 
@@ -53,52 +148,217 @@ pub struct InitializeCampaign<'info> {
 }
 
 impl<'info> InitializeCampaign<'info> {
-    pub fn init_campaign(
-        &mut self,
-        campaign_id: u64,
-        title: String,
-        description: String,
-        max_depth: u32,
-        max_buffer_size: u32,
-    ) -> Result<()> {
+    pub fn init_campaign(&mut self, campaign_id: u64, title: String, params: InitCampaignParams) -> Result<()> {
+        let InitCampaignParams {
+            description,
+            max_depth,
+            max_buffer_size,
+            tier_thresholds,
+            uri,
+            goal_amount,
+            require_whole_tokens,
+            soft_goal,
+            hard_cap,
+            kyc_threshold,
+            allow_self_donation,
+            deadline,
+            gate_mint,
+            keywords,
+            max_donors,
+            platform_referrer,
+            platform_fee_bps,
+            circuit_breaker_threshold,
+            start_time,
+            max_single_donation,
+            emit_events,
+            symbol,
+        } = params;
+        self.global_config.require_not_paused()?;
+        self.global_config.require_verified_creator(self.creator_verification.is_some())?;
+        // Store the same trimmed title the campaign PDA was derived from
+        let title = canonical_title(&title);
+        require!(!title.is_empty(), ErrorCode::EmptyTitle);
+        self.global_config.validate_campaign_text(title, &description)?;
+        require!(
+            CampaignInfo::valid_tier_thresholds(&tier_thresholds),
+            ErrorCode::InvalidTierThresholds
+        );
+        require!(
+            CampaignInfo::valid_goal_config(goal_amount, soft_goal, hard_cap),
+            ErrorCode::InvalidGoalConfig
+        );
+        self.global_config.require_kyc_verifier(kyc_threshold)?;
+        if let Some(uri) = &uri {
+            CampaignInfo::validate_uri(uri)?;
+        }
+        if let Some(symbol) = &symbol {
+            CampaignInfo::validate_symbol(symbol)?;
+        }
+        CampaignInfo::validate_keywords(&keywords)?;
+        require!(
+            CampaignInfo::valid_platform_fee(platform_referrer, platform_fee_bps),
+            ErrorCode::InvalidPlatformFee
+        );
+        require!(
+            CampaignInfo::valid_schedule(start_time, deadline),
+            ErrorCode::InvalidStartTime
+        );
+        let now = Clock::get()?.unix_timestamp;
+        self.global_config.validate_deadline(deadline, now)?;
+        self.creator_stats.require_cooldown_elapsed(self.global_config.creation_cooldown_seconds, now)?;
+
         let campaign = &mut self.campaign_account_info;
         campaign.creator = self.creator.key();
-        campaign.title = title.clone();
+        campaign.title = title.to_string();
         campaign.description = description;
         campaign.mint = self.mint.key();
         campaign.token_account = self.campaign_token_account.key();
         campaign.total_donation_received = 0;
+        campaign.token_donation_total = 0;
         
         // Initialize the new fields
         campaign.latest_merkle_root = [0u8; 32]; // Initial empty root
         campaign.donation_count = 0;
-        campaign.last_update_time = Clock::get()?.unix_timestamp;
+        campaign.last_update_time = now;
+        campaign.tier_thresholds = tier_thresholds;
+        campaign.uri = uri.unwrap_or_default();
+        campaign.goal_amount = goal_amount;
+        campaign.mint_decimals = self.mint.decimals;
+        campaign.require_whole_tokens = require_whole_tokens;
+        campaign.soft_goal = soft_goal;
+        campaign.hard_cap = hard_cap;
+        campaign.kyc_threshold = kyc_threshold;
+        campaign.allow_self_donation = allow_self_donation;
+        campaign.deadline = deadline;
+        campaign.leaf_version = LEAF_VERSION;
+        campaign.gate_mint = gate_mint;
+        campaign.keywords = keywords;
+        campaign.unique_donor_count = 0;
+        campaign.max_donors = max_donors;
+        campaign.platform_referrer = platform_referrer;
+        campaign.platform_fee_bps = platform_fee_bps;
+        campaign.paused = false;
+        campaign.circuit_breaker_threshold = circuit_breaker_threshold;
+        campaign.breaker_window_start = campaign.last_update_time;
+        campaign.breaker_window_count = 0;
+        campaign.start_time = start_time;
+        campaign.max_single_donation = max_single_donation;
+        campaign.pledged_total = 0;
+        campaign.emit_events = emit_events;
+        campaign.symbol = symbol.unwrap_or_default();
 
         let cpi_program = self.light_account_compression_program.to_account_info();
         let cpi_accounts = CreateTree {
             tree_config: self.merkle_tree.to_account_info(),
             authority: campaign.to_account_info(),
-            payer: self.creator.to_account_info(),
+            payer: self.payer.to_account_info(),
             system_program: self.system_program.to_account_info(),
         };
 
         let campaign_seeds = &[
             campaign_id.to_le_bytes().as_ref(),
-            title.as_bytes().as_ref(),
+            title.as_bytes(),
             &[*self.ctx.bumps.get("campaign_account_info").unwrap()]
         ];
         let signer_seeds = &[&campaign_seeds[..]];
         
+        // The campaign signs the tree CPI, so fail clearly if its rent was under-computed
+        let campaign_info = campaign.to_account_info();
+        let campaign_rent_exempt = Rent::get()?.is_exempt(campaign_info.lamports(), campaign_info.data_len());
+        if !campaign_rent_exempt {
+            msg!(
+                "Campaign {} holds {} lamports for {} bytes",
+                campaign_info.key(), campaign_info.lamports(), campaign_info.data_len()
+            );
+            return err!(ErrorCode::CampaignNotRentExempt);
+        }
+
+        // Catch the common "tree account too small" case before the CPI, with the sizes involved
+        let tree_key = self.merkle_tree.key();
+        let tree_len = self.merkle_tree.data_len();
+        let tree_lamports = self.merkle_tree.lamports();
+        if let Ok(required_len) = tree_account_size(max_depth, max_buffer_size) {
+            if tree_len < required_len {
+                msg!(
+                    "Merkle tree {} is {} bytes, but max_depth {} / max_buffer_size {} needs {}",
+                    tree_key, tree_len, max_depth, max_buffer_size, required_len
+                );
+                return err!(ErrorCode::TreeCreationFailed);
+            }
+        }
+
         msg!("Creating Merkle tree via CPI...");
         create_tree(
             CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds),
             max_depth,
             max_buffer_size
-        )?;
+        ).map_err(|e| {
+            msg!(
+                "Error creating Merkle tree {} ({} bytes, {} lamports) with max_depth {} / max_buffer_size {}: {:?}",
+                tree_key, tree_len, tree_lamports, max_depth, max_buffer_size, e
+            );
+            ErrorCode::TreeCreationFailed
+        })?;
+
+        // The tree account, not the presence of a queue, decides the tree type
+        let output_queue = self.output_queue.as_ref().map(|q| q.key());
+        let tree_type = validate_tree_queue(&self.merkle_tree.to_account_info(), output_queue)?;
 
         campaign.merkle_tree = self.merkle_tree.key();
+        campaign.output_queue = output_queue.unwrap_or_default();
+        campaign.is_batched = tree_type.is_batched();
+
+        // Audit chain starts from zero: creator, mint, campaign id, title, tree
+        campaign.audit_hash = [0u8; 32];
+        let (creator, mint, merkle_tree) = (campaign.creator, campaign.mint, campaign.merkle_tree);
+        campaign.chain_audit(
+            crate::instruction::InitCampaign::DISCRIMINATOR,
+            &[creator.as_ref(), mint.as_ref(), &campaign_id.to_le_bytes(), title.as_bytes(), merkle_tree.as_ref()],
+        );
+
+        self.protocol_stats.record_campaign()?;
+        self.creator_stats.record_campaign(campaign.creator, now)?;
+
+        let link = &mut self.creator_campaign_link;
+        link.creator = campaign.creator;
+        link.campaign_id = campaign_id;
+        link.campaign = campaign.key();
+
+        let tree_link = &mut self.tree_campaign_link;
+        tree_link.merkle_tree = campaign.merkle_tree;
+        tree_link.campaign = campaign.key();
+
+        emit!(CampaignCreatedEvent {
+            campaign: campaign.key(),
+            creator: campaign.creator,
+            campaign_id,
+            title: campaign.title.clone(),
+            uri: campaign.uri.clone(),
+            keywords: campaign.keywords.clone(),
+            merkle_tree: campaign.merkle_tree,
+            timestamp: campaign.last_update_time,
+            audit_hash: campaign.audit_hash,
+            symbol: campaign.symbol.clone(),
+        });
 
         msg!("Campaign and Merkle Tree initialized. Campaign: {:?}, Merkle Tree: {}", campaign, campaign.merkle_tree);
         Ok(())
     }
 }
+
+/// Event emitted when a campaign is created
+#[event]
+pub struct CampaignCreatedEvent {
+    pub campaign: Pubkey,
+    pub creator: Pubkey,
+    pub campaign_id: u64,
+    pub title: String,
+    pub uri: String,
+    pub keywords: Vec<String>,
+    pub merkle_tree: Pubkey,
+    pub timestamp: i64,
+    // Start of the campaign's audit hash chain
+    pub audit_hash: [u8; 32],
+    // Display symbol of the campaign mint, empty if unset
+    pub symbol: String,
+}
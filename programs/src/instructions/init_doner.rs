@@ -1,5 +1,6 @@
 use anchor_lang::prelude::*;
-use crate::state::DonerInfo;
+use crate::constants::TIER_NONE;
+use crate::state::{CampaignInfo, DonerInfo};
 
 #[derive(Accounts)]
 #[instruction(campaign: Pubkey)]
@@ -7,6 +8,9 @@ pub struct InitDoner<'info> {
     #[account(mut)]
     pub doner: Signer<'info>,
 
+    #[account(address = campaign)]
+    pub campaign_account_info: Account<'info, CampaignInfo>,
+
     #[account(
         init,
         payer = doner,
@@ -25,6 +29,7 @@ impl<'info> InitDoner<'info> {
         doner_info.doner = self.doner.key();
         doner_info.amount = 0;
         doner_info.campaign = campaign;
+        doner_info.tier = TIER_NONE;
 
         msg!("Doner account initialized: {:?}", doner_info);
         Ok(())
@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{MAX_DONERS_BATCH, TIER_NONE};
+use crate::error::ErrorCode;
+use crate::state::{CampaignInfo, DonerInfo};
+use crate::util::create_pda_account;
+
+#[derive(Accounts)]
+pub struct InitDonersBatch<'info> {
+    /// Campaign creator, who pays rent for every doner account created. Only the
+    /// creator may pre-register donors: an existing doner account makes
+    /// donate_first_time fail for that donor, so strangers must not create them
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(
+        has_one = creator @ ErrorCode::Unauthorized,
+    )]
+    pub campaign_account_info: Account<'info, CampaignInfo>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitDonersBatch<'info> {
+    /// Create the doner accounts of up to MAX_DONERS_BATCH donors for this campaign
+    ///
+    /// `doner_accounts` holds each donor's `[b"doner", campaign, donor]` PDA in
+    /// the same order as `donors`. Donors whose account already exists are
+    /// skipped, so re-running a partially applied batch is harmless. Pre-registered
+    /// donors do not count towards max_donors until their first donation.
+    pub fn init_doners_batch(&mut self, donors: Vec<Pubkey>, doner_accounts: &[AccountInfo<'info>]) -> Result<()> {
+        require!(
+            !donors.is_empty() && donors.len() <= MAX_DONERS_BATCH && doner_accounts.len() == donors.len(),
+            ErrorCode::InvalidDonerBatch
+        );
+
+        let campaign = self.campaign_account_info.key();
+        let space = 8 + DonerInfo::INIT_SPACE;
+        let mut created = 0u32;
+
+        for (donor, doner_account) in donors.iter().zip(doner_accounts) {
+            let (expected, bump) = Pubkey::find_program_address(
+                &[b"doner", campaign.as_ref(), donor.as_ref()],
+                &crate::ID,
+            );
+            require_keys_eq!(doner_account.key(), expected, ErrorCode::DonorAccountMismatch);
+            if doner_account.owner == &crate::ID {
+                msg!("Doner account for {} already exists, skipping", donor);
+                continue;
+            }
+
+            create_pda_account(
+                &self.creator.to_account_info(),
+                doner_account,
+                &self.system_program.to_account_info(),
+                space,
+                &[b"doner", campaign.as_ref(), donor.as_ref(), &[bump]],
+            )?;
+
+            let doner_info = DonerInfo {
+                doner: *donor,
+                amount: 0,
+                campaign,
+                tier: TIER_NONE,
+            };
+            doner_info.try_serialize(&mut &mut doner_account.try_borrow_mut_data()?[..])?;
+            created += 1;
+        }
+
+        emit!(DonersBatchInitializedEvent {
+            campaign,
+            payer: self.creator.key(),
+            requested: donors.len() as u32,
+            created,
+        });
+
+        msg!("Initialized {} of {} doner accounts", created, donors.len());
+        Ok(())
+    }
+}
+
+/// Event emitted after a batch of doner accounts is initialized
+#[event]
+pub struct DonersBatchInitializedEvent {
+    pub campaign: Pubkey,
+    pub payer: Pubkey,
+    pub requested: u32,
+    // Accounts actually created; the rest already existed
+    pub created: u32,
+}
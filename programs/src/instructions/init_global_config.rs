@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{MAX_DESCRIPTION_LEN, MAX_TITLE_LEN, PAUSE_REASON_NONE};
+use crate::state::GlobalConfig;
+
+#[derive(Accounts)]
+pub struct InitGlobalConfig<'info> {
+    /// Becomes the admin of the configuration
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        init,
+        payer = admin,
+        seeds = [b"global_config"],
+        bump,
+        space = 8 + GlobalConfig::INIT_SPACE,
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitGlobalConfig<'info> {
+    /// Create the program-wide configuration; can only run once
+    pub fn init_global_config(&mut self, clock_skew_seconds: u64) -> Result<()> {
+        let config = &mut self.global_config;
+        config.admin = self.admin.key();
+        config.clock_skew_seconds = clock_skew_seconds;
+        config.paused = false;
+        config.pause_reason = PAUSE_REASON_NONE;
+        config.kyc_verifier = Pubkey::default();
+        config.max_title_len = MAX_TITLE_LEN as u16;
+        config.max_desc_len = MAX_DESCRIPTION_LEN as u16;
+        config.require_verified_creators = false;
+        config.creation_cooldown_seconds = 0;
+        config.max_campaign_duration = 0;
+
+        msg!("Global config initialized: {:?}", config);
+        Ok(())
+    }
+}
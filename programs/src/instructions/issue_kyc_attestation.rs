@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ErrorCode;
+use crate::state::{GlobalConfig, KycAttestation};
+
+#[derive(Accounts)]
+#[instruction(donor: Pubkey)]
+pub struct IssueKycAttestation<'info> {
+    #[account(mut)]
+    pub verifier: Signer<'info>,
+
+    #[account(
+        seeds = [b"global_config"],
+        bump,
+        constraint = global_config.kyc_verifier == verifier.key() @ ErrorCode::Unauthorized,
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = verifier,
+        seeds = [b"kyc_attestation", donor.as_ref()],
+        bump,
+        space = 8 + KycAttestation::INIT_SPACE,
+    )]
+    pub kyc_attestation: Account<'info, KycAttestation>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> IssueKycAttestation<'info> {
+    /// Attest that `donor` passed checks; re-issuing refreshes the verifier and timestamp
+    pub fn issue_kyc_attestation(&mut self, donor: Pubkey) -> Result<()> {
+        let attestation = &mut self.kyc_attestation;
+        attestation.donor = donor;
+        attestation.verifier = self.verifier.key();
+        attestation.issued_at = Clock::get()?.unix_timestamp;
+
+        msg!("KYC attestation issued for {}", donor);
+        Ok(())
+    }
+}
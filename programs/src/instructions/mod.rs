@@ -1,14 +1,116 @@
 pub mod initialize;
 pub use initialize::*;
 
+pub mod init_global_config;
+pub use init_global_config::*;
+
+pub mod update_global_config;
+pub use update_global_config::*;
+
+pub mod set_paused;
+pub use set_paused::*;
+
+pub mod set_campaign_paused;
+pub use set_campaign_paused::*;
+
+pub mod issue_kyc_attestation;
+pub use issue_kyc_attestation::*;
+
+pub mod verify_creator;
+pub use verify_creator::*;
+
+pub mod unverify_creator;
+pub use unverify_creator::*;
+
+pub mod create_campaign_tree_account;
+pub use create_campaign_tree_account::*;
+
 pub mod init_campaign;
 pub use init_campaign::*;
 
 pub mod init_doner;
 pub use init_doner::*;
 
+pub mod init_doners_batch;
+pub use init_doners_batch::*;
+
+pub mod donation_core;
+pub use donation_core::*;
+
 pub mod donate;
 pub use donate::*;
 
+pub mod donate_first_time;
+pub use donate_first_time::*;
+
 pub mod donate_compressed;
 pub use donate_compressed::*;
+
+pub mod donate_cpi;
+pub use donate_cpi::*;
+
+pub mod update_commitment;
+pub use update_commitment::*;
+
+pub mod update_campaign_metadata;
+pub use update_campaign_metadata::*;
+
+pub mod extend_deadline;
+pub use extend_deadline::*;
+
+pub mod change_campaign_mint;
+pub use change_campaign_mint::*;
+
+pub mod reset_counters;
+pub use reset_counters::*;
+
+pub mod start_new_season;
+pub use start_new_season::*;
+
+pub mod fund_refund_pool;
+pub use fund_refund_pool::*;
+
+pub mod refund_proportional;
+pub use refund_proportional::*;
+
+pub mod create_pledge;
+pub use create_pledge::*;
+
+pub mod fulfill_pledge;
+pub use fulfill_pledge::*;
+
+pub mod cancel_pledge;
+pub use cancel_pledge::*;
+
+pub mod withdraw_to_stream;
+pub use withdraw_to_stream::*;
+
+pub mod claim_stream;
+pub use claim_stream::*;
+
+pub mod snapshot_root;
+pub use snapshot_root::*;
+
+pub mod validate_proof;
+pub use validate_proof::*;
+
+pub mod get_campaign_by_tree;
+pub use get_campaign_by_tree::*;
+
+pub mod get_funding_progress;
+pub use get_funding_progress::*;
+
+pub mod get_full_state;
+pub use get_full_state::*;
+
+pub mod get_donor_lifetime;
+pub use get_donor_lifetime::*;
+
+pub mod get_recent_donations;
+pub use get_recent_donations::*;
+
+pub mod repair_tree_pointer;
+pub use repair_tree_pointer::*;
+
+pub mod upgrade_campaign_account;
+pub use upgrade_campaign_account::*;
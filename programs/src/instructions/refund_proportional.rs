@@ -0,0 +1,126 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{associated_token::AssociatedToken, token::*};
+
+use crate::error::ErrorCode;
+use crate::instructions::mark_failed_if_expired;
+use crate::state::{CampaignInfo, DonerInfo, RefundClaim};
+use crate::util::canonical_title;
+
+#[derive(Accounts)]
+#[instruction(campaign_id: u64, title: String)]
+pub struct RefundProportional<'info> {
+    #[account(mut)]
+    pub doner: Signer<'info>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [campaign_id.to_le_bytes().as_ref(), canonical_title(&title).as_bytes()],
+        bump,
+        has_one = mint @ ErrorCode::MintMismatch,
+    )]
+    pub campaign_account_info: Account<'info, CampaignInfo>,
+
+    #[account(
+        seeds = [b"doner", campaign_account_info.key().as_ref(), doner.key().as_ref()],
+        bump
+    )]
+    pub doner_account_info: Account<'info, DonerInfo>,
+
+    /// Tracks what the donor has already been paid, so repeat claims only pay
+    /// what later top-ups added to their share
+    #[account(
+        init_if_needed,
+        payer = doner,
+        seeds = [b"refund_claim", campaign_account_info.key().as_ref(), doner.key().as_ref()],
+        bump,
+        space = 8 + RefundClaim::INIT_SPACE,
+    )]
+    pub refund_claim: Account<'info, RefundClaim>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = campaign_account_info,
+    )]
+    pub refund_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = doner,
+    )]
+    pub doner_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+impl<'info> RefundProportional<'info> {
+    /// Pay the signer what is still owed of `donor_amount * refund_pool /
+    /// token_donation_total` once the campaign has failed
+    ///
+    /// Shares round down, so the sum of all claims never exceeds the pool, and
+    /// each payout is capped at what is left in the refund vault.
+    pub fn refund_proportional(&mut self, campaign_id: u64, title: String, campaign_bump: u8) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        mark_failed_if_expired(&mut self.campaign_account_info, now);
+        let campaign = &self.campaign_account_info;
+        require!(campaign.is_failed, ErrorCode::CampaignNotFailed);
+        require!(campaign.refund_pool > 0, ErrorCode::RefundPoolEmpty);
+
+        let refund_amount = campaign
+            .refund_share(self.doner_account_info.amount)?
+            .saturating_sub(self.refund_claim.paid)
+            .min(self.refund_vault.amount);
+        require!(refund_amount > 0, ErrorCode::NothingToRefund);
+
+        let campaign_seeds = &[
+            campaign_id.to_le_bytes().as_ref(),
+            canonical_title(&title).as_bytes(),
+            &[campaign_bump],
+        ];
+        let signer_seeds = &[&campaign_seeds[..]];
+        let cpi_accounts = TransferChecked {
+            from: self.refund_vault.to_account_info(),
+            to: self.doner_token_account.to_account_info(),
+            mint: self.mint.to_account_info(),
+            authority: self.campaign_account_info.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(self.token_program.to_account_info(), cpi_accounts, signer_seeds);
+        transfer_checked(cpi_ctx, refund_amount, self.mint.decimals)?;
+
+        let claim = &mut self.refund_claim;
+        claim.campaign = self.campaign_account_info.key();
+        claim.doner = self.doner.key();
+        claim.paid = claim.paid.checked_add(refund_amount)
+            .ok_or(error!(ErrorCode::ArithmeticOverflow))?;
+        claim.claimed_at = now;
+
+        emit!(RefundClaimedEvent {
+            campaign: claim.campaign,
+            doner: claim.doner,
+            donated: self.doner_account_info.amount,
+            amount: refund_amount,
+            paid: claim.paid,
+            timestamp: claim.claimed_at,
+        });
+
+        msg!("{} refunded {} of {} donated", claim.doner, refund_amount, self.doner_account_info.amount);
+        Ok(())
+    }
+}
+
+/// Event emitted when a donor claims their share of the refund pool
+#[event]
+pub struct RefundClaimedEvent {
+    pub campaign: Pubkey,
+    pub doner: Pubkey,
+    pub donated: u64,
+    pub amount: u64,
+    pub paid: u64,
+    pub timestamp: i64,
+}
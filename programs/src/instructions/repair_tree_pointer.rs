@@ -0,0 +1,116 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ErrorCode;
+use crate::instructions::{tree_owner, validate_tree_queue};
+use crate::state::{CampaignInfo, TreeCampaignLink};
+use crate::util::{canonical_title, close_pda_account};
+
+#[derive(Accounts)]
+#[instruction(campaign_id: u64, title: String)]
+pub struct RepairTreePointer<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [campaign_id.to_le_bytes().as_ref(), canonical_title(&title).as_bytes()],
+        bump,
+        has_one = creator @ ErrorCode::Unauthorized,
+    )]
+    pub campaign_account_info: Account<'info, CampaignInfo>,
+
+    /// CHECK: Replacement tree, concurrent or batched. The handler checks it is a
+    /// Light Protocol state tree (owner and discriminator) controlled by the campaign.
+    #[account(constraint = merkle_tree.key() != campaign_account_info.merkle_tree @ ErrorCode::TreeUnchanged)]
+    pub merkle_tree: UncheckedAccount<'info>,
+
+    /// CHECK: Output queue of the replacement tree, required for batched trees and
+    /// rejected for concurrent ones; checked against the tree's metadata by validate_tree_queue
+    pub output_queue: Option<UncheckedAccount<'info>>,
+
+    /// Reverse lookup from the replacement tree to this campaign
+    #[account(
+        init_if_needed,
+        payer = creator,
+        seeds = [b"tree_link", merkle_tree.key().as_ref()],
+        bump,
+        space = 8 + TreeCampaignLink::INIT_SPACE,
+    )]
+    pub tree_campaign_link: Account<'info, TreeCampaignLink>,
+
+    /// CHECK: Reverse lookup from the replaced tree, always passed at its derived
+    /// address. The handler closes it so it no longer resolves to this campaign;
+    /// it is only empty for campaigns created before tree links were introduced.
+    #[account(
+        mut,
+        seeds = [b"tree_link", campaign_account_info.merkle_tree.as_ref()],
+        bump,
+    )]
+    pub previous_tree_campaign_link: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> RepairTreePointer<'info> {
+    /// Point the campaign at a rolled-over or recreated Merkle tree
+    ///
+    /// The new tree must be controlled by the campaign PDA, as trees created
+    /// in init_campaign are. Its type decides `is_batched`, and a batched tree's
+    /// output queue replaces the recorded one.
+    pub fn repair_tree_pointer(&mut self) -> Result<()> {
+        let campaign_key = self.campaign_account_info.key();
+        let tree_info = self.merkle_tree.to_account_info();
+        let output_queue = self.output_queue.as_ref().map(|q| q.key());
+        let tree_type = validate_tree_queue(&tree_info, output_queue)?;
+        require_keys_eq!(tree_owner(&tree_info)?, campaign_key, ErrorCode::TreeNotControlledByCampaign);
+        self.close_previous_tree_link()?;
+
+        let campaign = &mut self.campaign_account_info;
+        let previous_tree = campaign.merkle_tree;
+        campaign.merkle_tree = self.merkle_tree.key();
+        campaign.output_queue = output_queue.unwrap_or_default();
+        campaign.is_batched = tree_type.is_batched();
+        campaign.last_update_time = Clock::get()?.unix_timestamp;
+
+        let tree_link = &mut self.tree_campaign_link;
+        tree_link.merkle_tree = campaign.merkle_tree;
+        tree_link.campaign = campaign_key;
+
+        emit!(TreePointerRepairedEvent {
+            campaign: campaign_key,
+            previous_tree,
+            new_tree: campaign.merkle_tree,
+            output_queue: campaign.output_queue,
+            is_batched: campaign.is_batched,
+            timestamp: campaign.last_update_time,
+        });
+
+        msg!("Campaign tree pointer repaired: {} -> {}", previous_tree, campaign.merkle_tree);
+        Ok(())
+    }
+
+    /// Close the replaced tree's link if it exists, after checking it points at
+    /// this campaign
+    fn close_previous_tree_link(&self) -> Result<()> {
+        let link_account = self.previous_tree_campaign_link.to_account_info();
+        if link_account.data_is_empty() {
+            return Ok(());
+        }
+        require_keys_eq!(*link_account.owner, crate::ID, ErrorCode::TreeLinkMismatch);
+        let link = TreeCampaignLink::try_deserialize(&mut &link_account.try_borrow_data()?[..])?;
+        require_keys_eq!(link.campaign, self.campaign_account_info.key(), ErrorCode::TreeLinkMismatch);
+        close_pda_account(&link_account, &self.creator.to_account_info())
+    }
+}
+
+/// Event emitted when a campaign's Merkle tree pointer is replaced
+#[event]
+pub struct TreePointerRepairedEvent {
+    pub campaign: Pubkey,
+    pub previous_tree: Pubkey,
+    pub new_tree: Pubkey,
+    // Default for concurrent trees
+    pub output_queue: Pubkey,
+    pub is_batched: bool,
+    pub timestamp: i64,
+}
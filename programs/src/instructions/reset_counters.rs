@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use crate::error::ErrorCode;
+use crate::state::CampaignInfo;
+use crate::util::canonical_title;
+
+#[derive(Accounts)]
+#[instruction(campaign_id: u64, title: String)]
+pub struct ResetCounters<'info> {
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [campaign_id.to_le_bytes().as_ref(), canonical_title(&title).as_bytes()],
+        bump,
+        has_one = creator @ ErrorCode::Unauthorized,
+    )]
+    pub campaign_account_info: Account<'info, CampaignInfo>,
+
+    /// Token account donations are paid into, which must be empty before a reset
+    #[account(address = campaign_account_info.token_account)]
+    pub campaign_token_account: Account<'info, TokenAccount>,
+}
+
+impl<'info> ResetCounters<'info> {
+    /// Zero the donation counters so the campaign PDA can be reused for a new season
+    ///
+    /// Only allowed once every donated token has left the campaign token account,
+    /// and never on a campaign with a refund pool, whose shares are computed
+    /// against total_donation_received. Campaigns with a goal or deadline are
+    /// rejected too: a zeroed total would read as a missed goal after the
+    /// deadline and latch is_failed on a campaign that succeeded.
+    ///
+    /// Only `donation_count`, `total_donation_received` and its
+    /// `token_donation_total` part are reset. DonerInfo
+    /// amounts and tiers, DonorGlobalStats, `unique_donor_count` (and so the
+    /// max_donors cap), `pledged_total` and `ever_received` are lifetime values
+    /// and carry over unchanged.
+    /// The Merkle root is kept unless `reset_merkle_root` is set.
+    pub fn reset_counters(&mut self, reset_merkle_root: bool) -> Result<()> {
+        require!(self.campaign_token_account.amount == 0, ErrorCode::FundsStillPresent);
+        require!(self.campaign_account_info.refund_pool == 0, ErrorCode::RefundPoolOutstanding);
+        require!(
+            self.campaign_account_info.goal_amount == 0 && self.campaign_account_info.deadline == 0,
+            ErrorCode::ResetNotAllowedWithGoal
+        );
+
+        let campaign = &mut self.campaign_account_info;
+        let previous_donation_count = campaign.donation_count;
+        let previous_total_donation_received = campaign.total_donation_received;
+
+        campaign.donation_count = 0;
+        campaign.total_donation_received = 0;
+        campaign.token_donation_total = 0;
+        if reset_merkle_root {
+            campaign.latest_merkle_root = [0u8; 32];
+        }
+        campaign.last_update_time = Clock::get()?.unix_timestamp;
+
+        emit!(CountersResetEvent {
+            campaign: campaign.key(),
+            previous_donation_count,
+            previous_total_donation_received,
+            merkle_root_reset: reset_merkle_root,
+            timestamp: campaign.last_update_time,
+        });
+
+        msg!(
+            "Campaign counters reset from {} donations totalling {}",
+            previous_donation_count,
+            previous_total_donation_received
+        );
+        Ok(())
+    }
+}
+
+/// Event emitted when a campaign's donation counters are reset
+#[event]
+pub struct CountersResetEvent {
+    pub campaign: Pubkey,
+    // Counter values just before the reset, for indexers closing out the season
+    pub previous_donation_count: u64,
+    pub previous_total_donation_received: u64,
+    pub merkle_root_reset: bool,
+    pub timestamp: i64,
+}
@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ErrorCode;
+use crate::state::{CampaignInfo, GlobalConfig};
+use crate::util::canonical_title;
+
+#[derive(Accounts)]
+#[instruction(campaign_id: u64, title: String)]
+pub struct SetCampaignPaused<'info> {
+    /// The campaign creator or the program admin
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [campaign_id.to_le_bytes().as_ref(), canonical_title(&title).as_bytes()],
+        bump,
+    )]
+    pub campaign_account_info: Account<'info, CampaignInfo>,
+
+    #[account(
+        seeds = [b"global_config"],
+        bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+}
+
+impl<'info> SetCampaignPaused<'info> {
+    /// Pause or unpause a single campaign, e.g. after its circuit breaker tripped
+    ///
+    /// Unpausing starts a fresh circuit breaker window.
+    pub fn set_campaign_paused(&mut self, paused: bool) -> Result<()> {
+        let authority = self.authority.key();
+        let campaign = &mut self.campaign_account_info;
+        require!(
+            authority == campaign.creator || authority == self.global_config.admin,
+            ErrorCode::Unauthorized
+        );
+
+        let now = Clock::get()?.unix_timestamp;
+        campaign.paused = paused;
+        if !paused {
+            campaign.breaker_window_start = now;
+            campaign.breaker_window_count = 0;
+        }
+        campaign.last_update_time = now;
+
+        emit!(CampaignPausedEvent {
+            campaign: campaign.key(),
+            paused,
+            authority,
+            timestamp: now,
+        });
+
+        msg!("Campaign paused: {}", paused);
+        Ok(())
+    }
+}
+
+/// Event emitted when a campaign is manually paused or unpaused
+#[event]
+pub struct CampaignPausedEvent {
+    pub campaign: Pubkey,
+    pub paused: bool,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ErrorCode;
+use crate::state::GlobalConfig;
+
+#[derive(Accounts)]
+pub struct SetPaused<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"global_config"],
+        bump,
+        has_one = admin @ ErrorCode::Unauthorized,
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+}
+
+impl<'info> SetPaused<'info> {
+    /// Pause or unpause the program, recording why for off-chain monitors
+    ///
+    /// `reason_code` is one of the `PAUSE_REASON_*` constants (or a custom value).
+    pub fn set_paused(&mut self, paused: bool, reason_code: u8) -> Result<()> {
+        let config = &mut self.global_config;
+        config.paused = paused;
+        config.pause_reason = reason_code;
+
+        emit!(PausedEvent {
+            paused,
+            reason_code,
+            timestamp: Clock::get()?.unix_timestamp,
+            admin: self.admin.key(),
+        });
+
+        msg!("Program paused: {}, reason code: {}", paused, reason_code);
+        Ok(())
+    }
+}
+
+/// Event emitted when the program is paused or unpaused
+#[event]
+pub struct PausedEvent {
+    pub paused: bool,
+    pub reason_code: u8,
+    pub timestamp: i64,
+    pub admin: Pubkey,
+}
@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ErrorCode;
+use crate::state::{CampaignInfo, RootSnapshot};
+
+#[derive(Accounts)]
+pub struct SnapshotRoot<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(mut)]
+    pub campaign_account_info: Account<'info, CampaignInfo>,
+
+    /// Next snapshot in sequence, indexed by the campaign's snapshot_count
+    #[account(
+        init,
+        payer = payer,
+        seeds = [
+            b"snapshot",
+            campaign_account_info.key().as_ref(),
+            campaign_account_info.snapshot_count.to_le_bytes().as_ref(),
+        ],
+        bump,
+        space = 8 + RootSnapshot::INIT_SPACE,
+    )]
+    pub root_snapshot: Account<'info, RootSnapshot>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SnapshotRoot<'info> {
+    /// Record the campaign's current Merkle root and donation count; anyone may pay for a snapshot
+    ///
+    /// The root is `latest_merkle_root` as stored on the campaign, not one read
+    /// from the tree account. donate_compressed still records a placeholder root
+    /// (see `extract_merkle_tree_update`), so until it parses the real root
+    /// from the tree, snapshots track donation counts and timestamps faithfully
+    /// but their `merkle_root` is not the tree's actual root.
+    pub fn snapshot_root(&mut self) -> Result<()> {
+        let campaign = &mut self.campaign_account_info;
+        let snapshot = &mut self.root_snapshot;
+        snapshot.campaign = campaign.key();
+        snapshot.index = campaign.snapshot_count;
+        snapshot.merkle_root = campaign.latest_merkle_root;
+        snapshot.donation_count = campaign.donation_count;
+        snapshot.timestamp = Clock::get()?.unix_timestamp;
+
+        campaign.snapshot_count = campaign.snapshot_count.checked_add(1)
+            .ok_or(error!(ErrorCode::ArithmeticOverflow))?;
+
+        emit!(RootSnapshotEvent {
+            campaign: snapshot.campaign,
+            index: snapshot.index,
+            merkle_root: snapshot.merkle_root,
+            donation_count: snapshot.donation_count,
+            timestamp: snapshot.timestamp,
+        });
+
+        msg!("Root snapshot {} taken at {} donations", snapshot.index, snapshot.donation_count);
+        Ok(())
+    }
+}
+
+/// Event emitted when a campaign's Merkle root is snapshotted
+#[event]
+pub struct RootSnapshotEvent {
+    pub campaign: Pubkey,
+    pub index: u64,
+    pub merkle_root: [u8; 32],
+    pub donation_count: u64,
+    pub timestamp: i64,
+}
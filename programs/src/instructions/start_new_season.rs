@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ErrorCode;
+use crate::state::{CampaignInfo, RecentDonations};
+use crate::util::canonical_title;
+
+#[derive(Accounts)]
+#[instruction(campaign_id: u64, title: String)]
+pub struct StartNewSeason<'info> {
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [campaign_id.to_le_bytes().as_ref(), canonical_title(&title).as_bytes()],
+        bump,
+        has_one = creator @ ErrorCode::Unauthorized,
+    )]
+    pub campaign_account_info: Account<'info, CampaignInfo>,
+
+    /// Campaign's recent-activity feed, absent until the first token donation
+    #[account(
+        mut,
+        seeds = [b"recent", campaign_account_info.key().as_ref()],
+        bump,
+    )]
+    pub recent_donations: Option<Account<'info, RecentDonations>>,
+}
+
+impl<'info> StartNewSeason<'info> {
+    /// Advance the campaign to its next season and clear the recent-donations feed
+    ///
+    /// Lifetime totals (DonerInfo amounts, DonorGlobalStats, total_donation_received)
+    /// are left untouched. The program keeps no per-season donor totals, so this is
+    /// not a leaderboard reset: per-season standings are built off-chain from the
+    /// season on each DonationReceivedEvent.
+    pub fn start_new_season(&mut self) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let campaign = &mut self.campaign_account_info;
+        let previous_season = campaign.season;
+        campaign.season = previous_season.checked_add(1)
+            .ok_or(error!(ErrorCode::ArithmeticOverflow))?;
+        campaign.last_update_time = now;
+
+        if let Some(recent_donations) = self.recent_donations.as_mut() {
+            recent_donations.clear();
+        }
+
+        emit!(SeasonStartedEvent {
+            campaign: campaign.key(),
+            previous_season,
+            season: campaign.season,
+            timestamp: now,
+        });
+
+        msg!("Campaign season advanced from {} to {}", previous_season, campaign.season);
+        Ok(())
+    }
+}
+
+/// Event emitted when a campaign starts a new season
+#[event]
+pub struct SeasonStartedEvent {
+    pub campaign: Pubkey,
+    pub previous_season: u16,
+    pub season: u16,
+    pub timestamp: i64,
+}
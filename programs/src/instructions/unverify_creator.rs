@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ErrorCode;
+use crate::state::{CreatorVerification, GlobalConfig};
+
+#[derive(Accounts)]
+#[instruction(creator: Pubkey)]
+pub struct UnverifyCreator<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"global_config"],
+        bump,
+        has_one = admin @ ErrorCode::Unauthorized,
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        mut,
+        close = admin,
+        seeds = [b"creator_verification", creator.as_ref()],
+        bump,
+    )]
+    pub creator_verification: Account<'info, CreatorVerification>,
+}
+
+impl<'info> UnverifyCreator<'info> {
+    /// Revoke `creator`'s verification by closing it; existing campaigns are unaffected
+    pub fn unverify_creator(&mut self, creator: Pubkey) -> Result<()> {
+        msg!("Creator unverified: {}", creator);
+        Ok(())
+    }
+}
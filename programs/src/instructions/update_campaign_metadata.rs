@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ErrorCode;
+use crate::state::CampaignInfo;
+use crate::util::canonical_title;
+
+#[derive(Accounts)]
+#[instruction(campaign_id: u64, title: String)]
+pub struct UpdateCampaignMetadata<'info> {
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [campaign_id.to_le_bytes().as_ref(), canonical_title(&title).as_bytes()],
+        bump,
+        has_one = creator @ ErrorCode::Unauthorized,
+    )]
+    pub campaign_account_info: Account<'info, CampaignInfo>,
+}
+
+impl<'info> UpdateCampaignMetadata<'info> {
+    /// Update the campaign's off-chain metadata pointer and search keywords
+    ///
+    /// Fields passed as `None` are left unchanged.
+    pub fn update_campaign_metadata(&mut self, uri: Option<String>, keywords: Option<Vec<String>>) -> Result<()> {
+        let campaign = &mut self.campaign_account_info;
+
+        if let Some(uri) = uri {
+            CampaignInfo::validate_uri(&uri)?;
+            campaign.uri = uri;
+        }
+        if let Some(keywords) = keywords {
+            CampaignInfo::validate_keywords(&keywords)?;
+            campaign.keywords = keywords;
+        }
+        campaign.last_update_time = Clock::get()?.unix_timestamp;
+
+        emit!(CampaignMetadataUpdatedEvent {
+            campaign: campaign.key(),
+            uri: campaign.uri.clone(),
+            keywords: campaign.keywords.clone(),
+            timestamp: campaign.last_update_time,
+        });
+
+        msg!("Campaign metadata updated: {}", campaign.uri);
+        Ok(())
+    }
+}
+
+/// Event emitted when a campaign's metadata changes
+#[event]
+pub struct CampaignMetadataUpdatedEvent {
+    pub campaign: Pubkey,
+    pub uri: String,
+    pub keywords: Vec<String>,
+    pub timestamp: i64,
+}
@@ -0,0 +1,123 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions as sysvar_instructions;
+
+use crate::error::ErrorCode;
+use crate::instructions::DonateCompressed;
+use crate::state::{CommitmentOwner, DonorGlobalStats};
+
+#[derive(Accounts)]
+#[instruction(new_commitment: [u8; 32])]
+pub struct UpdateCommitment<'info> {
+    #[account(mut)]
+    pub donor: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"donor_stats", donor.key().as_ref()],
+        bump,
+        has_one = donor @ ErrorCode::Unauthorized,
+    )]
+    pub donor_stats: Account<'info, DonorGlobalStats>,
+
+    /// Claimed for the donor here, so the new commitment is owned before the
+    /// rotation event publishes it
+    #[account(
+        init_if_needed,
+        payer = donor,
+        seeds = [b"commitment", new_commitment.as_ref()],
+        bump,
+        space = 8 + CommitmentOwner::INIT_SPACE,
+    )]
+    pub new_commitment_owner: Account<'info, CommitmentOwner>,
+
+    /// CHECK: Instructions sysvar, read to find the donor's ed25519 rotation signature
+    #[account(address = sysvar_instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> UpdateCommitment<'info> {
+    /// Replace the donor's stored commitment with `new_commitment`
+    ///
+    /// The instruction immediately before this one must be an ed25519 signature
+    /// by the donor over `rotation_message(donor, old, new)`, where `old` is the
+    /// stored commitment. Lifetime totals are keyed by the donor, not the
+    /// commitment, so they carry over unchanged.
+    ///
+    /// The new commitment's `CommitmentOwner` record is created for the donor,
+    /// or must already name them; otherwise anyone who saw the rotation event
+    /// could claim the commitment first through donate_compressed.
+    pub fn update_commitment(&mut self, new_commitment: [u8; 32]) -> Result<()> {
+        let old_commitment = self.donor_stats.donor_commitment;
+        require!(
+            old_commitment != [0u8; 32] && new_commitment != old_commitment,
+            ErrorCode::CommitmentRotationUnverified
+        );
+        self.verify_rotation(&old_commitment, &new_commitment)?;
+        let now = Clock::get()?.unix_timestamp;
+        self.claim_new_commitment(new_commitment, now)?;
+
+        self.donor_stats.donor_commitment = new_commitment;
+
+        emit!(CommitmentRotatedEvent {
+            donor: self.donor.key(),
+            old_commitment,
+            new_commitment,
+            lifetime_donated: self.donor_stats.lifetime_donated,
+            timestamp: now,
+        });
+
+        msg!("Donor commitment rotated for {}", self.donor.key());
+        Ok(())
+    }
+
+    /// Message the donor signs to rotate commitments: `donor || old_commitment || new_commitment`
+    pub fn rotation_message(donor: &Pubkey, old_commitment: &[u8; 32], new_commitment: &[u8; 32]) -> [u8; 96] {
+        let mut message = [0u8; 96];
+        message[..32].copy_from_slice(donor.as_ref());
+        message[32..64].copy_from_slice(old_commitment);
+        message[64..].copy_from_slice(new_commitment);
+        message
+    }
+
+    /// Record the donor as the owner of a freshly created `new_commitment_owner`,
+    /// or check an existing record already names them
+    fn claim_new_commitment(&mut self, new_commitment: [u8; 32], now: i64) -> Result<()> {
+        let record = &mut self.new_commitment_owner;
+        if record.owner == Pubkey::default() {
+            record.commitment = new_commitment;
+            record.owner = self.donor.key();
+            record.first_used_at = now;
+        }
+        require_keys_eq!(record.owner, self.donor.key(), ErrorCode::CommitmentOwnershipUnverified);
+        Ok(())
+    }
+
+    fn verify_rotation(&self, old_commitment: &[u8; 32], new_commitment: &[u8; 32]) -> Result<()> {
+        let sysvar = self.instructions_sysvar.to_account_info();
+        let current_index = sysvar_instructions::load_current_index_checked(&sysvar)?;
+        let signature_index = current_index
+            .checked_sub(1)
+            .ok_or(error!(ErrorCode::CommitmentRotationUnverified))?;
+        let signature_ix = sysvar_instructions::load_instruction_at_checked(usize::from(signature_index), &sysvar)?;
+
+        let expected_message = Self::rotation_message(&self.donor.key(), old_commitment, new_commitment);
+        require!(
+            DonateCompressed::ed25519_signature_valid(&signature_ix, &self.donor.key(), &expected_message),
+            ErrorCode::CommitmentRotationUnverified
+        );
+        Ok(())
+    }
+}
+
+/// Event emitted when a donor rotates their compressed-donation commitment
+#[event]
+pub struct CommitmentRotatedEvent {
+    pub donor: Pubkey,
+    pub old_commitment: [u8; 32],
+    pub new_commitment: [u8; 32],
+    // Unchanged by the rotation
+    pub lifetime_donated: u64,
+    pub timestamp: i64,
+}
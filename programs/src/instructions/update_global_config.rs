@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ErrorCode;
+use crate::state::GlobalConfig;
+
+/// New configuration values; fields left as `None` are unchanged
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default)]
+pub struct UpdateGlobalConfigParams {
+    pub new_admin: Option<Pubkey>,
+    pub clock_skew_seconds: Option<u64>,
+    pub kyc_verifier: Option<Pubkey>,
+    pub max_title_len: Option<u16>,
+    pub max_desc_len: Option<u16>,
+    pub require_verified_creators: Option<bool>,
+    pub creation_cooldown_seconds: Option<u64>,
+    pub max_campaign_duration: Option<u64>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateGlobalConfig<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"global_config"],
+        bump,
+        has_one = admin @ ErrorCode::Unauthorized,
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+}
+
+impl<'info> UpdateGlobalConfig<'info> {
+    pub fn update_global_config(&mut self, params: UpdateGlobalConfigParams) -> Result<()> {
+        let config = &mut self.global_config;
+
+        if let Some(new_admin) = params.new_admin {
+            config.admin = new_admin;
+        }
+        if let Some(clock_skew_seconds) = params.clock_skew_seconds {
+            config.clock_skew_seconds = clock_skew_seconds;
+        }
+        if let Some(kyc_verifier) = params.kyc_verifier {
+            config.kyc_verifier = kyc_verifier;
+        }
+        if let Some(max_title_len) = params.max_title_len {
+            config.max_title_len = max_title_len;
+        }
+        if let Some(max_desc_len) = params.max_desc_len {
+            config.max_desc_len = max_desc_len;
+        }
+        if let Some(require_verified_creators) = params.require_verified_creators {
+            config.require_verified_creators = require_verified_creators;
+        }
+        if let Some(creation_cooldown_seconds) = params.creation_cooldown_seconds {
+            config.creation_cooldown_seconds = creation_cooldown_seconds;
+        }
+        if let Some(max_campaign_duration) = params.max_campaign_duration {
+            config.max_campaign_duration = max_campaign_duration;
+        }
+        require!(
+            GlobalConfig::valid_text_limits(config.max_title_len, config.max_desc_len),
+            ErrorCode::InvalidTextLimits
+        );
+
+        msg!("Global config updated: {:?}", config);
+        Ok(())
+    }
+}
@@ -0,0 +1,196 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_spl::token::Mint;
+
+use crate::constants::{MAX_KEYWORDS, MAX_KEYWORD_LEN, MAX_SYMBOL_LEN};
+use crate::error::ErrorCode;
+use crate::instructions::TreeType;
+use crate::state::CampaignInfo;
+use crate::util::canonical_title;
+
+/// CampaignInfo::INIT_SPACE as of the version that appended `allow_self_donation`.
+/// Campaigns are always allocated at full size, so accounts smaller than this
+/// predate the field and get its default rather than the zero-filled `false`.
+const SELF_DONATION_LAYOUT_SPACE: usize = 738;
+
+/// CampaignInfo::INIT_SPACE as of the version that appended `leaf_version`
+const LEAF_VERSION_LAYOUT_SPACE: usize = 747;
+
+/// CampaignInfo::INIT_SPACE as of the version that appended `is_batched`
+const IS_BATCHED_LAYOUT_SPACE: usize = 1045;
+
+/// CampaignInfo::INIT_SPACE as of the version that appended `emit_events`
+const EMIT_EVENTS_LAYOUT_SPACE: usize = 1048;
+
+/// CampaignInfo::INIT_SPACE as of the version that appended `symbol`
+const SYMBOL_LAYOUT_SPACE: usize = 1063;
+
+/// CampaignInfo::INIT_SPACE as of the version that appended `token_donation_total`
+const TOKEN_DONATION_TOTAL_LAYOUT_SPACE: usize = 1072;
+
+// Each layout plus the fields appended after it must add up to the next layout,
+// and the newest to the current INIT_SPACE. Inserting a field anywhere but the
+// end of CampaignInfo, or appending one without listing it here, fails to compile.
+const _: () = assert!(
+    SELF_DONATION_LAYOUT_SPACE
+        + 8 // deadline
+        + 1 // leaf_version
+        == LEAF_VERSION_LAYOUT_SPACE
+);
+const _: () = assert!(
+    LEAF_VERSION_LAYOUT_SPACE
+        + 1 + 32 // gate_mint
+        + 4 + MAX_KEYWORDS * (4 + MAX_KEYWORD_LEN) // keywords
+        + 8 // refund_pool
+        + 8 // snapshot_count
+        + 8 // unique_donor_count
+        + 8 // max_donors
+        + 1 + 32 // platform_referrer
+        + 2 // platform_fee_bps
+        + 1 // paused
+        + 4 // circuit_breaker_threshold
+        + 8 // breaker_window_start
+        + 4 // breaker_window_count
+        + 8 // start_time
+        + 8 // max_single_donation
+        + 8 // pledged_total
+        + 32 // audit_hash
+        + 1 // is_batched
+        == IS_BATCHED_LAYOUT_SPACE
+);
+const _: () = assert!(
+    IS_BATCHED_LAYOUT_SPACE
+        + 2 // season
+        + 1 // emit_events
+        == EMIT_EVENTS_LAYOUT_SPACE
+);
+const _: () = assert!(
+    EMIT_EVENTS_LAYOUT_SPACE
+        + 1 // is_failed
+        + 4 + MAX_SYMBOL_LEN // symbol
+        == SYMBOL_LAYOUT_SPACE
+);
+const _: () = assert!(
+    SYMBOL_LAYOUT_SPACE
+        + 1 // ever_received
+        + 8 // token_donation_total
+        == TOKEN_DONATION_TOTAL_LAYOUT_SPACE
+);
+const _: () = assert!(TOKEN_DONATION_TOTAL_LAYOUT_SPACE == CampaignInfo::INIT_SPACE);
+
+/// Leaf schema served by campaigns created before leaf versioning
+const LEGACY_LEAF_VERSION: u8 = 1;
+
+#[derive(Accounts)]
+#[instruction(campaign_id: u64, title: String)]
+pub struct UpgradeCampaignAccount<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    /// CHECK: Possibly too short to deserialize as CampaignInfo. The owner is
+    /// checked here; discriminator and creator are checked in the handler.
+    #[account(
+        mut,
+        seeds = [campaign_id.to_le_bytes().as_ref(), canonical_title(&title).as_bytes()],
+        bump,
+        owner = crate::ID,
+    )]
+    pub campaign_account_info: UncheckedAccount<'info>,
+
+    /// Campaign mint, read to fill the cached mint_decimals
+    pub mint: Account<'info, Mint>,
+
+    /// CHECK: The campaign's Merkle tree, checked against campaign.merkle_tree
+    /// in the handler and read to fill is_batched from its account type
+    pub merkle_tree: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> UpgradeCampaignAccount<'info> {
+    /// Grow a campaign account created under an older layout to the current size
+    ///
+    /// New fields are appended to CampaignInfo, so zero-filling the extra space
+    /// gives them their zero defaults; fields whose zero value is not a sensible
+    /// default are set explicitly afterwards.
+    pub fn upgrade_campaign_account(&mut self) -> Result<()> {
+        let info = self.campaign_account_info.to_account_info();
+        let old_len = info.data_len();
+        let new_len = 8 + CampaignInfo::INIT_SPACE;
+        require!(old_len < new_len, ErrorCode::AccountAlreadyUpToDate);
+
+        {
+            let data = info.try_borrow_data()?;
+            require!(
+                data.starts_with(CampaignInfo::DISCRIMINATOR),
+                anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch
+            );
+            // creator is the first field after the discriminator
+            let creator = Pubkey::try_from(&data[8..40])
+                .map_err(|_| error!(anchor_lang::error::ErrorCode::AccountDidNotDeserialize))?;
+            require_keys_eq!(creator, self.creator.key(), ErrorCode::Unauthorized);
+        }
+
+        // Top up rent for the larger account, paid by the creator
+        let shortfall = Rent::get()?.minimum_balance(new_len).saturating_sub(info.lamports());
+        if shortfall > 0 {
+            transfer(
+                CpiContext::new(
+                    self.system_program.to_account_info(),
+                    Transfer {
+                        from: self.creator.to_account_info(),
+                        to: info.clone(),
+                    },
+                ),
+                shortfall,
+            )?;
+        }
+        info.realloc(new_len, true)?;
+
+        let mut campaign = CampaignInfo::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+        require_keys_eq!(campaign.mint, self.mint.key(), ErrorCode::MintMismatch);
+        campaign.mint_decimals = self.mint.decimals;
+        if old_len < 8 + SELF_DONATION_LAYOUT_SPACE {
+            campaign.allow_self_donation = true;
+        }
+        if old_len < 8 + LEAF_VERSION_LAYOUT_SPACE {
+            campaign.leaf_version = LEGACY_LEAF_VERSION;
+        }
+        if old_len < 8 + IS_BATCHED_LAYOUT_SPACE {
+            require_keys_eq!(campaign.merkle_tree, self.merkle_tree.key(), ErrorCode::InvalidMerkleTree);
+            campaign.is_batched = TreeType::of(&self.merkle_tree.to_account_info())?.is_batched();
+        }
+        if old_len < 8 + EMIT_EVENTS_LAYOUT_SPACE {
+            campaign.emit_events = true;
+        }
+        if old_len < 8 + SYMBOL_LAYOUT_SPACE {
+            // Best available signal for older accounts; a campaign reset before
+            // upgrading with no donors on record reads as never having received
+            campaign.ever_received = campaign.donation_count > 0
+                || campaign.total_donation_received > 0
+                || campaign.unique_donor_count > 0;
+        }
+        if old_len < 8 + TOKEN_DONATION_TOTAL_LAYOUT_SPACE {
+            // Older campaigns never split their total, so all of it stays claimable
+            campaign.token_donation_total = campaign.total_donation_received;
+        }
+        campaign.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])?;
+
+        emit!(CampaignAccountUpgradedEvent {
+            campaign: info.key(),
+            old_len: old_len as u64,
+            new_len: new_len as u64,
+        });
+
+        msg!("Campaign account upgraded from {} to {} bytes", old_len, new_len);
+        Ok(())
+    }
+}
+
+/// Event emitted when a campaign account is migrated to the current layout
+#[event]
+pub struct CampaignAccountUpgradedEvent {
+    pub campaign: Pubkey,
+    pub old_len: u64,
+    pub new_len: u64,
+}
@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{
+    LEAF_VERSION, PROOF_REASON_EMPTY, PROOF_REASON_TOO_SHORT, PROOF_REASON_UNSUPPORTED_VERSION, PROOF_REASON_WELL_FORMED,
+    PROOF_REASON_ZERO_AMOUNT,
+};
+use crate::instructions::DonateCompressed;
+
+#[derive(Accounts)]
+pub struct ValidateProof {}
+
+impl ValidateProof {
+    /// Run donate_compressed's campaign-independent format checks on a proof and
+    /// emit the outcome without modifying any state
+    ///
+    /// No cryptographic verification happens here: a well-formed proof may
+    /// still be rejected by donate_compressed.
+    pub fn validate_proof(&self, proof_data: Vec<u8>) -> Result<()> {
+        let (reason_code, amount) = Self::check_proof(&proof_data);
+
+        emit!(ProofValidationEvent {
+            valid: reason_code == PROOF_REASON_WELL_FORMED,
+            amount,
+            reason_code,
+        });
+        Ok(())
+    }
+
+    /// Reason code for `proof_data` and the amount it claims (0 if unparsable)
+    fn check_proof(proof_data: &[u8]) -> (u8, u64) {
+        if proof_data.is_empty() {
+            return (PROOF_REASON_EMPTY, 0);
+        }
+        let Ok(donation_data) = DonateCompressed::extract_donation_data(proof_data) else {
+            return (PROOF_REASON_TOO_SHORT, 0);
+        };
+
+        let amount = donation_data.public_inputs.amount;
+        let reason_code = if amount == 0 {
+            PROOF_REASON_ZERO_AMOUNT
+        } else if donation_data.leaf_version != LEAF_VERSION {
+            PROOF_REASON_UNSUPPORTED_VERSION
+        } else {
+            PROOF_REASON_WELL_FORMED
+        };
+        (reason_code, amount)
+    }
+}
+
+/// Event carrying the outcome of validate_proof
+#[event]
+pub struct ProofValidationEvent {
+    // Parses and passes the format checks; there is no verifying key to check
+    // the proof against, so this says nothing about the proof itself
+    pub valid: bool,
+    pub amount: u64,
+    // One of the PROOF_REASON_* constants
+    pub reason_code: u8,
+}
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ErrorCode;
+use crate::state::{CreatorVerification, GlobalConfig};
+
+#[derive(Accounts)]
+#[instruction(creator: Pubkey)]
+pub struct VerifyCreator<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"global_config"],
+        bump,
+        has_one = admin @ ErrorCode::Unauthorized,
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        seeds = [b"creator_verification", creator.as_ref()],
+        bump,
+        space = 8 + CreatorVerification::INIT_SPACE,
+    )]
+    pub creator_verification: Account<'info, CreatorVerification>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> VerifyCreator<'info> {
+    /// Mark `creator` as verified; re-verifying refreshes the admin and timestamp
+    pub fn verify_creator(&mut self, creator: Pubkey) -> Result<()> {
+        let verification = &mut self.creator_verification;
+        verification.creator = creator;
+        verification.verified_by = self.admin.key();
+        verification.verified_at = Clock::get()?.unix_timestamp;
+
+        msg!("Creator verified: {}", creator);
+        Ok(())
+    }
+}
@@ -0,0 +1,106 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{associated_token::AssociatedToken, token::*};
+
+use crate::error::ErrorCode;
+use crate::state::{CampaignInfo, Stream};
+use crate::util::{canonical_title, require_token_program};
+
+#[derive(Accounts)]
+#[instruction(campaign_id: u64, title: String, recipient: Pubkey)]
+pub struct WithdrawToStream<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        seeds = [campaign_id.to_le_bytes().as_ref(), canonical_title(&title).as_bytes()],
+        bump,
+        has_one = creator @ ErrorCode::Unauthorized,
+        has_one = mint @ ErrorCode::MintMismatch,
+    )]
+    pub campaign_account_info: Account<'info, CampaignInfo>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = creator,
+    )]
+    pub creator_token_account: Account<'info, TokenAccount>,
+
+    /// Created here, so a campaign has at most one stream per recipient
+    #[account(
+        init,
+        payer = creator,
+        seeds = [b"stream", campaign_account_info.key().as_ref(), recipient.as_ref()],
+        bump,
+        space = 8 + Stream::INIT_SPACE,
+    )]
+    pub stream: Account<'info, Stream>,
+
+    /// Vault holding the streamed tokens, owned by the stream PDA
+    #[account(
+        init,
+        payer = creator,
+        associated_token::mint = mint,
+        associated_token::authority = stream,
+    )]
+    pub stream_vault: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+impl<'info> WithdrawToStream<'info> {
+    /// Move `amount` from the creator into a stream paying `recipient` at `rate_per_second`
+    pub fn withdraw_to_stream(&mut self, recipient: Pubkey, amount: u64, rate_per_second: u64) -> Result<()> {
+        require!(amount > 0 && rate_per_second > 0, ErrorCode::InvalidStreamParams);
+        require_token_program(
+            &self.mint.to_account_info(),
+            &self.token_program.key(),
+            &self.creator_token_account.key(),
+            &self.creator.key(),
+        )?;
+
+        let cpi_accounts = TransferChecked {
+            from: self.creator_token_account.to_account_info(),
+            to: self.stream_vault.to_account_info(),
+            mint: self.mint.to_account_info(),
+            authority: self.creator.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(self.token_program.to_account_info(), cpi_accounts);
+        transfer_checked(cpi_ctx, amount, self.mint.decimals)?;
+
+        let stream = &mut self.stream;
+        stream.campaign = self.campaign_account_info.key();
+        stream.recipient = recipient;
+        stream.mint = self.mint.key();
+        stream.rate_per_second = rate_per_second;
+        stream.start_time = Clock::get()?.unix_timestamp;
+        stream.deposited = amount;
+        stream.claimed = 0;
+
+        emit!(StreamCreatedEvent {
+            campaign: stream.campaign,
+            recipient,
+            amount,
+            rate_per_second,
+            start_time: stream.start_time,
+        });
+
+        msg!("Streaming {} to {} at {} per second", amount, recipient, rate_per_second);
+        Ok(())
+    }
+}
+
+/// Event emitted when a creator moves campaign funds into a stream
+#[event]
+pub struct StreamCreatedEvent {
+    pub campaign: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub rate_per_second: u64,
+    pub start_time: i64,
+}
@@ -1,7 +1,11 @@
+#[cfg(feature = "client")]
+pub mod client;
 pub mod constants;
 pub mod error;
 pub mod instructions;
+pub mod merkle;
 pub mod state;
+pub mod util;
 
 use anchor_lang::prelude::*;
 
@@ -15,19 +19,190 @@ declare_id!("9FHnJ6S5P1UoWtyd6iqXYESy4WEGvGArA5W17f6H1gQk");
 pub mod heart_of_blockchain {
     use super::*;
 
-    pub fn init_campaign(ctx: Context<InitializeCampaign>, campaign_id: u64, title: String, description: String) -> Result<()> {
-        ctx.accounts.init_campaign(campaign_id, title, description)
+    pub fn init_global_config(ctx: Context<InitGlobalConfig>, clock_skew_seconds: u64) -> Result<()> {
+        ctx.accounts.init_global_config(clock_skew_seconds)
+    }
+
+    pub fn update_global_config(ctx: Context<UpdateGlobalConfig>, params: UpdateGlobalConfigParams) -> Result<()> {
+        ctx.accounts.update_global_config(params)
+    }
+
+    pub fn set_paused(ctx: Context<SetPaused>, paused: bool, reason_code: u8) -> Result<()> {
+        ctx.accounts.set_paused(paused, reason_code)
+    }
+
+    pub fn create_campaign_tree_account(ctx: Context<CreateCampaignTreeAccount>, max_depth: u32, max_buffer_size: u32) -> Result<()> {
+        ctx.accounts.create_campaign_tree_account(max_depth, max_buffer_size)
+    }
+
+    pub fn issue_kyc_attestation(ctx: Context<IssueKycAttestation>, donor: Pubkey) -> Result<()> {
+        ctx.accounts.issue_kyc_attestation(donor)
+    }
+
+    pub fn verify_creator(ctx: Context<VerifyCreator>, creator: Pubkey) -> Result<()> {
+        ctx.accounts.verify_creator(creator)
+    }
+
+    pub fn unverify_creator(ctx: Context<UnverifyCreator>, creator: Pubkey) -> Result<()> {
+        ctx.accounts.unverify_creator(creator)
+    }
+
+    pub fn init_campaign(
+        ctx: Context<InitializeCampaign>,
+        campaign_id: u64,
+        title: String,
+        params: InitCampaignParams,
+    ) -> Result<()> {
+        ctx.accounts.init_campaign(campaign_id, title, params)
+    }
+
+    pub fn update_campaign_metadata(
+        ctx: Context<UpdateCampaignMetadata>,
+        _campaign_id: u64,
+        _title: String,
+        uri: Option<String>,
+        keywords: Option<Vec<String>>,
+    ) -> Result<()> {
+        ctx.accounts.update_campaign_metadata(uri, keywords)
+    }
+
+    pub fn set_campaign_paused(ctx: Context<SetCampaignPaused>, _campaign_id: u64, _title: String, paused: bool) -> Result<()> {
+        ctx.accounts.set_campaign_paused(paused)
+    }
+
+    pub fn extend_deadline(ctx: Context<ExtendDeadline>, _campaign_id: u64, _title: String, new_deadline: i64) -> Result<()> {
+        ctx.accounts.extend_deadline(new_deadline)
+    }
+
+    pub fn change_campaign_mint(ctx: Context<ChangeCampaignMint>, _campaign_id: u64, _title: String) -> Result<()> {
+        ctx.accounts.change_campaign_mint()
+    }
+
+    pub fn reset_counters(ctx: Context<ResetCounters>, _campaign_id: u64, _title: String, reset_merkle_root: bool) -> Result<()> {
+        ctx.accounts.reset_counters(reset_merkle_root)
+    }
+
+    pub fn start_new_season(ctx: Context<StartNewSeason>, _campaign_id: u64, _title: String) -> Result<()> {
+        ctx.accounts.start_new_season()
+    }
+
+    pub fn fund_refund_pool(ctx: Context<FundRefundPool>, _campaign_id: u64, _title: String, amount: u64) -> Result<()> {
+        ctx.accounts.fund_refund_pool(amount)
+    }
+
+    pub fn refund_proportional(ctx: Context<RefundProportional>, campaign_id: u64, title: String) -> Result<()> {
+        let campaign_bump = ctx.bumps.campaign_account_info;
+        ctx.accounts.refund_proportional(campaign_id, title, campaign_bump)
+    }
+
+    pub fn withdraw_to_stream(
+        ctx: Context<WithdrawToStream>,
+        _campaign_id: u64,
+        _title: String,
+        recipient: Pubkey,
+        amount: u64,
+        rate_per_second: u64,
+    ) -> Result<()> {
+        ctx.accounts.withdraw_to_stream(recipient, amount, rate_per_second)
+    }
+
+    pub fn claim_stream(ctx: Context<ClaimStream>) -> Result<()> {
+        ctx.accounts.claim_stream(&ctx.bumps)
+    }
+
+    pub fn upgrade_campaign_account(ctx: Context<UpgradeCampaignAccount>, _campaign_id: u64, _title: String) -> Result<()> {
+        ctx.accounts.upgrade_campaign_account()
+    }
+
+    pub fn repair_tree_pointer(ctx: Context<RepairTreePointer>, _campaign_id: u64, _title: String) -> Result<()> {
+        ctx.accounts.repair_tree_pointer()
     }
 
     pub fn init_doner(ctx: Context<InitDoner>, campaign: Pubkey) -> Result<()> {
         ctx.accounts.init_doner(campaign)
     }
 
-    pub fn donate_amount(ctx: Context<DonateAmount>, campaign_id: u64, title: String, donation_amount: u64) -> Result<()> {
-        ctx.accounts.donate_amount(campaign_id, title, donation_amount)
+    pub fn init_doners_batch<'info>(
+        ctx: Context<'_, '_, '_, 'info, InitDonersBatch<'info>>,
+        donors: Vec<Pubkey>,
+    ) -> Result<()> {
+        ctx.accounts.init_doners_batch(donors, ctx.remaining_accounts)
+    }
+
+    pub fn donate_amount(
+        ctx: Context<DonateAmount>,
+        campaign_id: u64,
+        title: String,
+        donation_amount: u64,
+        beneficiary_donor: Option<Pubkey>,
+        gift_id: Option<u64>,
+    ) -> Result<()> {
+        ctx.accounts.donate_amount(campaign_id, title, donation_amount, beneficiary_donor, gift_id)
+    }
+
+    pub fn donate_percent_of_goal(ctx: Context<DonateAmount>, _campaign_id: u64, _title: String, percent_bps: u16) -> Result<()> {
+        ctx.accounts.donate_percent_of_goal(percent_bps)
+    }
+
+    pub fn donate_first_time(ctx: Context<DonateFirstTime>, _campaign_id: u64, _title: String, donation_amount: u64) -> Result<()> {
+        ctx.accounts.donate_first_time(donation_amount)
     }
     
-    pub fn donate_compressed(ctx: Context<DonateCompressed>, campaign_id: u64, title: String, proof_data: Vec<u8>) -> Result<()> {
-        ctx.accounts.donate_compressed(campaign_id, title, proof_data)
+    pub fn donate_cpi(ctx: Context<DonateCpi>, _campaign_id: u64, _title: String, donation_amount: u64) -> Result<()> {
+        ctx.accounts.donate_cpi(donation_amount)
+    }
+
+    pub fn donate_compressed(
+        ctx: Context<DonateCompressed>,
+        campaign_id: u64,
+        title: String,
+        proof_data: Vec<u8>,
+        recent_slot: u64,
+    ) -> Result<()> {
+        ctx.accounts.donate_compressed(campaign_id, title, proof_data, recent_slot)
+    }
+
+    pub fn update_commitment(ctx: Context<UpdateCommitment>, new_commitment: [u8; 32]) -> Result<()> {
+        ctx.accounts.update_commitment(new_commitment)
+    }
+
+    pub fn pledge(ctx: Context<CreatePledge>, _campaign_id: u64, _title: String, amount: u64, due_time: i64) -> Result<()> {
+        ctx.accounts.pledge(amount, due_time)
+    }
+
+    pub fn fulfill_pledge(ctx: Context<FulfillPledge>, _campaign_id: u64, _title: String) -> Result<()> {
+        ctx.accounts.fulfill_pledge()
+    }
+
+    pub fn cancel_pledge(ctx: Context<CancelPledge>) -> Result<()> {
+        ctx.accounts.cancel_pledge()
+    }
+
+    pub fn snapshot_root(ctx: Context<SnapshotRoot>) -> Result<()> {
+        ctx.accounts.snapshot_root()
+    }
+
+    pub fn validate_proof(ctx: Context<ValidateProof>, proof_data: Vec<u8>) -> Result<()> {
+        ctx.accounts.validate_proof(proof_data)
+    }
+
+    pub fn get_campaign_by_tree(ctx: Context<GetCampaignByTree>, _merkle_tree: Pubkey) -> Result<()> {
+        ctx.accounts.get_campaign_by_tree()
+    }
+
+    pub fn get_funding_progress(ctx: Context<GetFundingProgress>) -> Result<()> {
+        ctx.accounts.get_funding_progress()
+    }
+
+    pub fn get_full_state(ctx: Context<GetFullState>) -> Result<()> {
+        ctx.accounts.get_full_state()
+    }
+
+    pub fn get_donor_lifetime(ctx: Context<GetDonorLifetime>, _donor: Pubkey) -> Result<()> {
+        ctx.accounts.get_donor_lifetime()
+    }
+
+    pub fn get_recent_donations(ctx: Context<GetRecentDonations>, campaign: Pubkey) -> Result<()> {
+        ctx.accounts.get_recent_donations(campaign)
     }
 }
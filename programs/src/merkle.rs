@@ -0,0 +1,43 @@
+use anchor_lang::solana_program::keccak;
+use anchor_lang::solana_program::poseidon::{self, Endianness, Parameters};
+
+use crate::constants::MAX_MERKLE_PROOF_DEPTH;
+
+/// Verify that `leaf` is included in the Keccak-256 Merkle tree with the given `root`
+///
+/// Each level hashes the current node with its sibling in sorted order, so the
+/// proof does not need to encode left/right positions. Proofs deeper than
+/// `MAX_MERKLE_PROOF_DEPTH` are rejected to bound compute usage.
+pub fn verify_merkle_proof(leaf: [u8; 32], proof: &[[u8; 32]], root: [u8; 32]) -> bool {
+    compute_root(leaf, proof, |a, b| Some(keccak::hashv(&[a, b]).to_bytes())) == Some(root)
+}
+
+/// Poseidon (BN254, x^5) variant of `verify_merkle_proof`, for trees built with
+/// the same hash as Light Protocol's state trees
+///
+/// Returns false if any node is not a valid field element.
+pub fn verify_merkle_proof_poseidon(leaf: [u8; 32], proof: &[[u8; 32]], root: [u8; 32]) -> bool {
+    compute_root(leaf, proof, |a, b| {
+        poseidon::hashv(Parameters::Bn254X5, Endianness::BigEndian, &[a, b])
+            .ok()
+            .map(|hash| hash.to_bytes())
+    }) == Some(root)
+}
+
+/// Fold the proof into a root using sorted-pair hashing
+fn compute_root<F>(leaf: [u8; 32], proof: &[[u8; 32]], hash_pair: F) -> Option<[u8; 32]>
+where
+    F: Fn(&[u8], &[u8]) -> Option<[u8; 32]>,
+{
+    if proof.len() > MAX_MERKLE_PROOF_DEPTH {
+        return None;
+    }
+
+    proof.iter().try_fold(leaf, |node, sibling| {
+        if node <= *sibling {
+            hash_pair(&node, sibling)
+        } else {
+            hash_pair(sibling, &node)
+        }
+    })
+}
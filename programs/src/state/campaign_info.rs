@@ -1,4 +1,11 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::keccak;
+
+use crate::constants::{
+    BPS_DENOMINATOR, CIRCUIT_BREAKER_WINDOW_SECONDS, MAX_KEYWORDS, MAX_KEYWORD_LEN, MAX_SYMBOL_LEN, MAX_URI_LEN, TIER_BRONZE,
+    TIER_NONE,
+};
+use crate::error::ErrorCode;
 
 #[account]
 #[derive(Debug, InitSpace)]
@@ -6,8 +13,13 @@ pub struct DonerInfo {
     pub doner: Pubkey,
     pub amount: u64,
     pub campaign: Pubkey,
+
+    // Highest tier reached by this donor, never decreases
+    pub tier: u8,
 }
 
+/// New fields are only ever appended; upgrade_campaign_account checks its
+/// layout sizes against this struct at compile time
 #[account]
 #[derive(Debug, InitSpace)]
 pub struct CampaignInfo {
@@ -34,4 +46,310 @@ pub struct CampaignInfo {
     
     // Last update timestamp
     pub last_update_time: i64,
+
+    // Cumulative donation thresholds for the Bronze, Silver and Gold tiers (0 = tier unused)
+    pub tier_thresholds: [u64; 3],
+
+    // Pubkey of the output queue paired with the Merkle tree (default if the tree has no queue)
+    pub output_queue: Pubkey,
+
+    // URI of the off-chain metadata JSON (cover image, external links), empty if unset
+    #[max_len(200)]
+    pub uri: String,
+
+    // Reentrancy guard, set while a token transfer CPI is in flight
+    pub locked: bool,
+
+    // Fundraising goal in base units of the mint (0 = no goal configured)
+    pub goal_amount: u64,
+
+    // Decimals of the campaign mint, cached at creation
+    pub mint_decimals: u8,
+
+    // Only accept donations in whole tokens of the mint
+    pub require_whole_tokens: bool,
+
+    // Milestone that fires GoalReachedEvent while donations continue (0 = use goal_amount)
+    pub soft_goal: u64,
+
+    // Total beyond which donations are rejected (0 = uncapped)
+    pub hard_cap: u64,
+
+    // Donations above this amount require a KYC attestation (0 = never required)
+    pub kyc_threshold: u64,
+
+    // Whether the creator may donate to their own campaign
+    pub allow_self_donation: bool,
+
+    // Unix timestamp the campaign runs until (0 = no deadline)
+    pub deadline: i64,
+
+    // Leaf schema version compressed donation proofs must declare
+    pub leaf_version: u8,
+
+    // When set, donors must hold a nonzero balance of this mint to donate
+    pub gate_mint: Option<Pubkey>,
+
+    // Free-form search keywords for discovery, emitted for indexers
+    #[max_len(5, 20)]
+    pub keywords: Vec<String>,
+
+    // Tokens the creator has placed in the refund vault for pro-rata refunds
+    pub refund_pool: u64,
+
+    // Number of RootSnapshot accounts taken, also the index of the next one
+    pub snapshot_count: u64,
+
+    // Number of donors who have made a token donation to this campaign, counted
+    // on their first one
+    pub unique_donor_count: u64,
+
+    // Cap on unique_donor_count (0 = unlimited)
+    pub max_donors: u64,
+
+    // Partner platform that receives platform_fee_bps of each token donation
+    pub platform_referrer: Option<Pubkey>,
+
+    // Share of each token donation routed to platform_referrer, in basis points
+    pub platform_fee_bps: u16,
+
+    // Set by the circuit breaker (or the creator/admin); donations are rejected while set
+    pub paused: bool,
+
+    // Donations allowed per CIRCUIT_BREAKER_WINDOW_SECONDS before auto-pausing (0 = disabled)
+    pub circuit_breaker_threshold: u32,
+
+    // Start of the current circuit breaker window and the donations counted in it
+    pub breaker_window_start: i64,
+    pub breaker_window_count: u32,
+
+    // Unix timestamp donations open at (0 = open immediately)
+    pub start_time: i64,
+
+    // Largest amount accepted in a single donation (0 = no maximum)
+    pub max_single_donation: u64,
+
+    // Sum of outstanding Pledge amounts, not yet part of total_donation_received.
+    // Informational only: anyone can pledge any acceptable amount for the cost of
+    // rent, so no instruction may gate on it
+    pub pledged_total: u64,
+
+    // Head of the hash chain over init_campaign and every token donation, see chain_audit
+    pub audit_hash: [u8; 32],
+
+    // Tree was created with an output queue (batched); concurrent trees append without one
+    pub is_batched: bool,
+
+    // Current season, advanced by start_new_season; lifetime totals carry across seasons
+    pub season: u16,
+
+    // Emit per-donation events (DonationReceived, DonationProcessed, GiftInstallment,
+    // TierUpgraded); whale campaigns may turn this off and rely on snapshots. One-off
+    // campaign events (GoalReached, CircuitBreakerTripped, CampaignFailed) always fire.
+    pub emit_events: bool,
+
+    // Latched on the first interaction after the deadline with the goal missed; never cleared
+    pub is_failed: bool,
+
+    // Display symbol of the donation currency (e.g. "USDC") for events, empty if unset
+    #[max_len(10)]
+    pub symbol: String,
+
+    // Set by the first donation and never cleared, not even by reset_counters
+    pub ever_received: bool,
+
+    // Part of total_donation_received donated in tokens, which refund shares are
+    // computed against; compressed donors have no DonerInfo to claim with
+    pub token_donation_total: u64,
+}
+
+impl CampaignInfo {
+    /// Tier reached by a donor with the given cumulative donation amount
+    pub fn tier_for(&self, amount: u64) -> u8 {
+        let mut tier = TIER_NONE;
+        for (index, threshold) in self.tier_thresholds.iter().enumerate() {
+            if *threshold == 0 || amount < *threshold {
+                break;
+            }
+            tier = TIER_BRONZE + index as u8;
+        }
+        tier
+    }
+
+    /// Thresholds must strictly increase, and unused (zero) tiers may only trail the used ones
+    pub fn valid_tier_thresholds(tier_thresholds: &[u64; 3]) -> bool {
+        tier_thresholds
+            .windows(2)
+            .all(|pair| pair[1] == 0 || (pair[0] != 0 && pair[0] < pair[1]))
+    }
+
+    /// Neither the goal nor the soft goal may exceed the hard cap when it is set,
+    /// since donations past the cap are rejected and the goal could never be reached
+    pub fn valid_goal_config(goal_amount: u64, soft_goal: u64, hard_cap: u64) -> bool {
+        hard_cap == 0 || (goal_amount <= hard_cap && soft_goal <= hard_cap)
+    }
+
+    /// The start time must precede the deadline when both are set
+    pub fn valid_schedule(start_time: i64, deadline: i64) -> bool {
+        start_time == 0 || deadline == 0 || start_time < deadline
+    }
+
+    /// Whether donations are open at `now` under the campaign's start time
+    pub fn has_started(&self, now: i64) -> bool {
+        now >= self.start_time
+    }
+
+    /// Whether the deadline has passed at `now` with the goal still unmet
+    pub fn goal_missed(&self, now: i64) -> bool {
+        self.deadline != 0
+            && now > self.deadline
+            && self.goal_amount > 0
+            && self.total_donation_received < self.goal_amount
+    }
+
+    /// Total at which GoalReachedEvent fires: the soft goal if set, otherwise the goal
+    pub fn celebrated_goal(&self) -> u64 {
+        if self.soft_goal > 0 {
+            self.soft_goal
+        } else {
+            self.goal_amount
+        }
+    }
+
+    /// A platform fee needs a referrer to receive it and may not exceed 100%
+    pub fn valid_platform_fee(platform_referrer: Option<Pubkey>, platform_fee_bps: u16) -> bool {
+        u64::from(platform_fee_bps) <= BPS_DENOMINATOR && (platform_fee_bps == 0 || platform_referrer.is_some())
+    }
+
+    /// Part of a donation of `amount` owed to the platform referrer, rounded down
+    pub fn platform_fee(&self, amount: u64) -> u64 {
+        if self.platform_referrer.is_none() {
+            return 0;
+        }
+        (u128::from(amount) * u128::from(self.platform_fee_bps) / u128::from(BPS_DENOMINATOR)) as u64
+    }
+
+    /// Funding progress in basis points, capped at 10000 (0 when no goal is configured)
+    pub fn progress_bps(&self) -> u64 {
+        if self.goal_amount == 0 {
+            return 0;
+        }
+        let progress = u128::from(self.total_donation_received) * u128::from(BPS_DENOMINATOR)
+            / u128::from(self.goal_amount);
+        progress.min(u128::from(BPS_DENOMINATOR)) as u64
+    }
+
+    /// Pro-rata share of the refund pool for a token donor who gave `donor_amount`,
+    /// rounded down
+    ///
+    /// Shares are taken of the token donation total, so the pool is split among
+    /// the donors who can claim it rather than stranding the compressed part.
+    pub fn refund_share(&self, donor_amount: u64) -> Result<u64> {
+        if self.token_donation_total == 0 {
+            return Ok(0);
+        }
+        let share = u128::from(donor_amount) * u128::from(self.refund_pool)
+            / u128::from(self.token_donation_total);
+        u64::try_from(share).map_err(|_| error!(ErrorCode::ArithmeticOverflow))
+    }
+
+    /// Count a donor's first donation, rejecting it once the donor cap is reached
+    pub fn admit_donor(&mut self) -> Result<()> {
+        require!(
+            self.max_donors == 0 || self.unique_donor_count < self.max_donors,
+            ErrorCode::MaxDonorsReached
+        );
+        self.unique_donor_count = self.unique_donor_count.checked_add(1)
+            .ok_or(error!(ErrorCode::ArithmeticOverflow))?;
+        Ok(())
+    }
+
+    /// Count a donation made at `now` in the circuit breaker window, starting a new
+    /// window once the current one has elapsed
+    ///
+    /// Returns true when this donation takes the window's count past the threshold.
+    pub fn record_donation_rate(&mut self, now: i64) -> bool {
+        if self.circuit_breaker_threshold == 0 {
+            return false;
+        }
+        if now.saturating_sub(self.breaker_window_start) >= CIRCUIT_BREAKER_WINDOW_SECONDS {
+            self.breaker_window_start = now;
+            self.breaker_window_count = 0;
+        }
+        self.breaker_window_count = self.breaker_window_count.saturating_add(1);
+        self.breaker_window_count > self.circuit_breaker_threshold
+    }
+
+    /// Check a single donation's amount: nonzero, in whole tokens when required,
+    /// and at most max_single_donation
+    pub fn validate_donation_amount(&self, amount: u64) -> Result<()> {
+        require!(amount > 0, ErrorCode::ZeroDonation);
+        require!(self.accepts_amount(amount), ErrorCode::NonWholeTokenDonation);
+        require!(
+            self.max_single_donation == 0 || amount <= self.max_single_donation,
+            ErrorCode::DonationTooLarge
+        );
+        Ok(())
+    }
+
+    /// Batched trees append through the output queue recorded at init; concurrent
+    /// trees must not be given one
+    pub fn validate_output_queue(&self, output_queue: Option<Pubkey>) -> Result<()> {
+        match (self.is_batched, output_queue) {
+            (true, Some(queue)) => {
+                require_keys_eq!(queue, self.output_queue, ErrorCode::QueueMismatch);
+                Ok(())
+            }
+            (true, None) => err!(ErrorCode::OutputQueueRequired),
+            (false, Some(_)) => err!(ErrorCode::OutputQueueUnexpected),
+            (false, None) => Ok(()),
+        }
+    }
+
+    /// Extend the audit hash chain with a state-changing instruction:
+    /// `audit_hash = keccak(audit_hash || instruction_discriminator || key_fields...)`
+    ///
+    /// Auditors replaying the same instructions from a zero hash must arrive at
+    /// the stored value.
+    pub fn chain_audit(&mut self, instruction_discriminator: &[u8], key_fields: &[&[u8]]) -> [u8; 32] {
+        let mut inputs: Vec<&[u8]> = vec![&self.audit_hash, instruction_discriminator];
+        inputs.extend_from_slice(key_fields);
+        self.audit_hash = keccak::hashv(&inputs).to_bytes();
+        self.audit_hash
+    }
+
+    /// Whether `amount` is acceptable under the campaign's whole-token setting
+    pub fn accepts_amount(&self, amount: u64) -> bool {
+        if !self.require_whole_tokens {
+            return true;
+        }
+        // A token larger than u64::MAX base units leaves only zero as a whole amount
+        10u64
+            .checked_pow(u32::from(self.mint_decimals))
+            .map_or(amount == 0, |one_token| amount % one_token == 0)
+    }
+
+    /// A provided metadata URI must be non-empty and fit in the account
+    pub fn validate_uri(uri: &str) -> Result<()> {
+        require!(!uri.is_empty(), ErrorCode::EmptyUri);
+        require!(uri.len() <= MAX_URI_LEN, ErrorCode::UriTooLong);
+        Ok(())
+    }
+
+    /// A provided display symbol must be non-empty and fit in the account
+    pub fn validate_symbol(symbol: &str) -> Result<()> {
+        require!(!symbol.is_empty(), ErrorCode::EmptySymbol);
+        require!(symbol.len() <= MAX_SYMBOL_LEN, ErrorCode::SymbolTooLong);
+        Ok(())
+    }
+
+    /// Keywords must fit the account: at most MAX_KEYWORDS, each at most MAX_KEYWORD_LEN bytes
+    pub fn validate_keywords(keywords: &[String]) -> Result<()> {
+        require!(keywords.len() <= MAX_KEYWORDS, ErrorCode::TooManyKeywords);
+        require!(
+            keywords.iter().all(|keyword| keyword.len() <= MAX_KEYWORD_LEN),
+            ErrorCode::KeywordTooLong
+        );
+        Ok(())
+    }
 }
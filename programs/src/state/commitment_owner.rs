@@ -0,0 +1,14 @@
+use anchor_lang::prelude::*;
+
+/// First donor to use a compressed-donation commitment, seeds `[b"commitment", commitment]`
+///
+/// Created by that donor's first donate_compressed under the commitment, or by
+/// update_commitment when they rotate to it; every later donation under it must
+/// come from the same donor.
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct CommitmentOwner {
+    pub commitment: [u8; 32],
+    pub owner: Pubkey,
+    pub first_used_at: i64,
+}
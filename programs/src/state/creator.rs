@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ErrorCode;
+
+/// Per-creator statistics, seeds `[b"creator_stats", creator]`
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct CreatorStats {
+    pub creator: Pubkey,
+
+    // Number of campaigns created; CreatorCampaignLink accounts exist for indexes 0..campaign_count
+    pub campaign_count: u64,
+
+    // Unix timestamp of the creator's most recent campaign
+    pub last_campaign_time: i64,
+}
+
+impl CreatorStats {
+    /// Fail if the creator's previous campaign was created less than
+    /// `cooldown_seconds` before `now`; a creator's first campaign is never throttled
+    pub fn require_cooldown_elapsed(&self, cooldown_seconds: u64, now: i64) -> Result<()> {
+        if cooldown_seconds == 0 || self.campaign_count == 0 {
+            return Ok(());
+        }
+        let elapsed = now.saturating_sub(self.last_campaign_time);
+        require!(
+            elapsed >= 0 && elapsed.unsigned_abs() >= cooldown_seconds,
+            ErrorCode::CreationCooldownActive
+        );
+        Ok(())
+    }
+
+    /// Record a newly created campaign for this creator, created at `now`
+    pub fn record_campaign(&mut self, creator: Pubkey, now: i64) -> Result<()> {
+        self.creator = creator;
+        self.campaign_count = self.campaign_count.checked_add(1)
+            .ok_or(error!(ErrorCode::ArithmeticOverflow))?;
+        self.last_campaign_time = now;
+        Ok(())
+    }
+}
+
+/// Link from a creator to one of their campaigns, seeds `[b"creator_campaign", creator, index]`
+///
+/// `index` is the creator's CreatorStats::campaign_count when the campaign was
+/// created, so clients list a creator's campaigns by deriving the links for
+/// `0..campaign_count` instead of scanning.
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct CreatorCampaignLink {
+    pub creator: Pubkey,
+    pub campaign_id: u64,
+    pub campaign: Pubkey,
+}
+
+/// Admin attestation that a creator is verified, seeds `[b"creator_verification", creator]`
+///
+/// Required by init_campaign while GlobalConfig::require_verified_creators is set;
+/// closed by unverify_creator.
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct CreatorVerification {
+    pub creator: Pubkey,
+    pub verified_by: Pubkey,
+    pub verified_at: i64,
+}
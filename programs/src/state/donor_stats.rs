@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ErrorCode;
+
+/// A donor's giving across every campaign, seeds `[b"donor_stats", donor]`
+///
+/// Created lazily by the first donation and updated by every later one.
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct DonorGlobalStats {
+    pub donor: Pubkey,
+
+    // Sum of all donated amounts in base units, across mints
+    pub lifetime_donated: u64,
+
+    // Number of distinct campaigns the donor has made a token donation to
+    pub campaigns_supported: u64,
+
+    // Commitment set by the donor's first compressed donation and only changed by
+    // update_commitment (all zeroes until the first compressed donation)
+    pub donor_commitment: [u8; 32],
+}
+
+impl DonorGlobalStats {
+    /// Record a donation of `amount`, counting the campaign if it is the donor's first gift to it
+    pub fn record_donation(&mut self, donor: Pubkey, amount: u64, first_to_campaign: bool) -> Result<()> {
+        self.donor = donor;
+        self.lifetime_donated = self.lifetime_donated.checked_add(amount)
+            .ok_or(error!(ErrorCode::ArithmeticOverflow))?;
+        if first_to_campaign {
+            self.campaigns_supported = self.campaigns_supported.checked_add(1)
+                .ok_or(error!(ErrorCode::ArithmeticOverflow))?;
+        }
+        Ok(())
+    }
+
+    /// Adopt `commitment` on the donor's first compressed donation; later
+    /// donations must use the stored commitment until update_commitment rotates it
+    pub fn record_commitment(&mut self, commitment: [u8; 32]) -> Result<()> {
+        if self.donor_commitment == [0u8; 32] {
+            self.donor_commitment = commitment;
+        }
+        require!(self.donor_commitment == commitment, ErrorCode::DonorCommitmentMismatch);
+        Ok(())
+    }
+}
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ErrorCode;
+
+/// One logical gift paid in installments, seeds `[b"gift", campaign, donor, gift_id]`
+///
+/// Installments also count towards the campaign and donor totals as ordinary
+/// donations; this account only groups them.
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct Gift {
+    pub campaign: Pubkey,
+    pub donor: Pubkey,
+    pub gift_id: u64,
+
+    // Running total and number of installments paid towards this gift
+    pub total: u64,
+    pub installments: u32,
+
+    pub last_installment_at: i64,
+}
+
+impl Gift {
+    /// Add an installment of `amount` paid at `now`
+    pub fn record_installment(&mut self, amount: u64, now: i64) -> Result<()> {
+        self.total = self.total.checked_add(amount)
+            .ok_or(error!(ErrorCode::ArithmeticOverflow))?;
+        self.installments = self.installments.checked_add(1)
+            .ok_or(error!(ErrorCode::ArithmeticOverflow))?;
+        self.last_installment_at = now;
+        Ok(())
+    }
+}
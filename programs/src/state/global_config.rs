@@ -0,0 +1,110 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{MAX_DESCRIPTION_LEN, MAX_TITLE_LEN};
+use crate::error::ErrorCode;
+
+/// Program-wide configuration, seeds `[b"global_config"]`
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct GlobalConfig {
+    // Authority allowed to update this configuration
+    pub admin: Pubkey,
+
+    // Maximum allowed difference between a proof timestamp and the on-chain clock
+    pub clock_skew_seconds: u64,
+
+    // When set, campaign creation and donations are rejected
+    pub paused: bool,
+
+    // Reason code of the last pause/unpause (see PAUSE_REASON_* constants)
+    pub pause_reason: u8,
+
+    // Authority allowed to issue KYC attestations (default = none configured)
+    pub kyc_verifier: Pubkey,
+
+    // Deployment limits on campaign title and description length, at most the
+    // hard MAX_TITLE_LEN / MAX_DESCRIPTION_LEN (0 = use the hard limit)
+    pub max_title_len: u16,
+    pub max_desc_len: u16,
+
+    // When set, only creators with a CreatorVerification may create campaigns
+    pub require_verified_creators: bool,
+
+    // Minimum seconds between two campaigns by the same creator (0 = no cooldown)
+    pub creation_cooldown_seconds: u64,
+
+    // Longest a campaign deadline may lie ahead of the current time, in seconds (0 = no cap)
+    pub max_campaign_duration: u64,
+}
+
+impl GlobalConfig {
+    /// Fail if the admin has paused the program
+    pub fn require_not_paused(&self) -> Result<()> {
+        require!(!self.paused, ErrorCode::ProgramPaused);
+        Ok(())
+    }
+
+    /// When creator verification is required, the creator must present their verification
+    pub fn require_verified_creator(&self, verified: bool) -> Result<()> {
+        require!(!self.require_verified_creators || verified, ErrorCode::CreatorNotVerified);
+        Ok(())
+    }
+
+    /// A campaign KYC threshold can only be met through this config's verifier, so
+    /// one may not be set while none is configured
+    pub fn require_kyc_verifier(&self, kyc_threshold: u64) -> Result<()> {
+        require!(
+            kyc_threshold == 0 || self.kyc_verifier != Pubkey::default(),
+            ErrorCode::KycVerifierNotConfigured
+        );
+        Ok(())
+    }
+
+    /// Check a campaign title and description against the configured soft limits
+    pub fn validate_campaign_text(&self, title: &str, description: &str) -> Result<()> {
+        require!(
+            title.len() <= Self::effective_limit(self.max_title_len, MAX_TITLE_LEN),
+            ErrorCode::TitleTooLong
+        );
+        require!(
+            description.len() <= Self::effective_limit(self.max_desc_len, MAX_DESCRIPTION_LEN),
+            ErrorCode::DescriptionTooLong
+        );
+        Ok(())
+    }
+
+    /// A soft limit may tighten the hard limit but never loosen it
+    pub fn valid_text_limits(max_title_len: u16, max_desc_len: u16) -> bool {
+        usize::from(max_title_len) <= MAX_TITLE_LEN && usize::from(max_desc_len) <= MAX_DESCRIPTION_LEN
+    }
+
+    fn effective_limit(soft_limit: u16, hard_limit: usize) -> usize {
+        if soft_limit == 0 {
+            hard_limit
+        } else {
+            usize::from(soft_limit).min(hard_limit)
+        }
+    }
+
+    /// While max_campaign_duration is set, a deadline set at `now` must be in the
+    /// future and at most that many seconds away, so open-ended (0) and already
+    /// expired deadlines cannot slip past the cap
+    pub fn validate_deadline(&self, deadline: i64, now: i64) -> Result<()> {
+        if self.max_campaign_duration == 0 {
+            return Ok(());
+        }
+        require!(now < deadline, ErrorCode::DeadlineRequired);
+        let max_deadline = i128::from(now) + i128::from(self.max_campaign_duration);
+        require!(i128::from(deadline) <= max_deadline, ErrorCode::CampaignTooLong);
+        Ok(())
+    }
+
+    /// Check that a timestamp taken from a proof is within the configured skew of `now`
+    pub fn validate_proof_timestamp(&self, timestamp: i64, now: i64) -> Result<()> {
+        require!(
+            timestamp.abs_diff(now) <= self.clock_skew_seconds,
+            ErrorCode::ProofTimestampOutOfRange
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,16 @@
+use anchor_lang::prelude::*;
+
+/// Proof that a donor passed off-chain checks, seeds `[b"kyc_attestation", donor]`
+///
+/// Issued by the verifier configured on GlobalConfig; required for donations above
+/// a campaign's kyc_threshold.
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct KycAttestation {
+    pub donor: Pubkey,
+
+    // Verifier that issued the attestation; only valid while it is still the configured verifier
+    pub verifier: Pubkey,
+
+    pub issued_at: i64,
+}
@@ -1,2 +1,41 @@
 pub mod campaign_info;
 pub use campaign_info::*;
+
+pub mod protocol_stats;
+pub use protocol_stats::*;
+
+pub mod creator;
+pub use creator::*;
+
+pub mod global_config;
+pub use global_config::*;
+
+pub mod kyc;
+pub use kyc::*;
+
+pub mod refund;
+pub use refund::*;
+
+pub mod donor_stats;
+pub use donor_stats::*;
+
+pub mod recent_donations;
+pub use recent_donations::*;
+
+pub mod stream;
+pub use stream::*;
+
+pub mod snapshot;
+pub use snapshot::*;
+
+pub mod tree_link;
+pub use tree_link::*;
+
+pub mod pledge;
+pub use pledge::*;
+
+pub mod gift;
+pub use gift::*;
+
+pub mod commitment_owner;
+pub use commitment_owner::*;
@@ -0,0 +1,19 @@
+use anchor_lang::prelude::*;
+
+/// A donor's commitment to donate later, seeds `[b"pledge", campaign, donor]`
+///
+/// No tokens move until the pledge is fulfilled; until then `amount` only
+/// counts towards the campaign's `pledged_total`. A change of campaign mint
+/// voids the pledge: it can then only be cancelled.
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct Pledge {
+    pub campaign: Pubkey,
+    pub donor: Pubkey,
+    pub amount: u64,
+    // Campaign mint at pledge time, the mint `amount` is denominated in
+    pub mint: Pubkey,
+    // When the donor intends to pay, informational only
+    pub due_time: i64,
+    pub created_at: i64,
+}
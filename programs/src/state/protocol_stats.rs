@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ErrorCode;
+
+/// Program-wide aggregate of campaigns and donations, used by protocol dashboards
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct ProtocolStats {
+    // Number of campaigns created
+    pub total_campaigns: u64,
+
+    // Number of donations processed, regular and compressed
+    pub total_donations: u64,
+
+    // Sum of all donated amounts in base units
+    pub total_volume: u64,
+}
+
+impl ProtocolStats {
+    /// Record a newly created campaign
+    pub fn record_campaign(&mut self) -> Result<()> {
+        self.total_campaigns = self.total_campaigns.checked_add(1)
+            .ok_or(error!(ErrorCode::ArithmeticOverflow))?;
+
+        self.emit_update()
+    }
+
+    /// Record a processed donation of `amount` base units
+    pub fn record_donation(&mut self, amount: u64) -> Result<()> {
+        self.total_donations = self.total_donations.checked_add(1)
+            .ok_or(error!(ErrorCode::ArithmeticOverflow))?;
+        self.total_volume = self.total_volume.checked_add(amount)
+            .ok_or(error!(ErrorCode::ArithmeticOverflow))?;
+
+        self.emit_update()
+    }
+
+    fn emit_update(&self) -> Result<()> {
+        emit!(ProtocolStatsEvent {
+            total_campaigns: self.total_campaigns,
+            total_donations: self.total_donations,
+            total_volume: self.total_volume,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+}
+
+/// Event emitted whenever the protocol statistics change
+#[event]
+pub struct ProtocolStatsEvent {
+    pub total_campaigns: u64,
+    pub total_donations: u64,
+    pub total_volume: u64,
+    pub timestamp: i64,
+}
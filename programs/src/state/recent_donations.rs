@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::RECENT_DONATIONS_LEN;
+
+/// One entry of a campaign's recent-activity feed
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, InitSpace)]
+pub struct RecentDonation {
+    pub donor: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+/// Ring buffer of a campaign's last RECENT_DONATIONS_LEN token donations, seeds `[b"recent", campaign]`
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct RecentDonations {
+    pub campaign: Pubkey,
+
+    // Slot the next donation is written to
+    pub next_index: u8,
+
+    // Number of filled slots, up to RECENT_DONATIONS_LEN
+    pub count: u8,
+
+    pub entries: [RecentDonation; RECENT_DONATIONS_LEN],
+}
+
+impl RecentDonations {
+    /// Record a donation, overwriting the oldest entry once the buffer is full
+    pub fn push(&mut self, campaign: Pubkey, entry: RecentDonation) {
+        self.campaign = campaign;
+        self.entries[usize::from(self.next_index)] = entry;
+        self.next_index = ((usize::from(self.next_index) + 1) % RECENT_DONATIONS_LEN) as u8;
+        if usize::from(self.count) < RECENT_DONATIONS_LEN {
+            self.count += 1;
+        }
+    }
+
+    /// Empty the feed at a season boundary
+    pub fn clear(&mut self) {
+        self.next_index = 0;
+        self.count = 0;
+        self.entries = [RecentDonation::default(); RECENT_DONATIONS_LEN];
+    }
+
+    /// Filled entries ordered from newest to oldest, following wrap-around
+    pub fn newest_first(&self) -> Vec<RecentDonation> {
+        (1..=usize::from(self.count))
+            .map(|age| {
+                let index = (usize::from(self.next_index) + RECENT_DONATIONS_LEN - age) % RECENT_DONATIONS_LEN;
+                self.entries[index]
+            })
+            .collect()
+    }
+}
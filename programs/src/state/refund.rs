@@ -0,0 +1,15 @@
+use anchor_lang::prelude::*;
+
+/// Record of a donor's pro-rata refund, seeds `[b"refund_claim", campaign, doner]`
+///
+/// Created on the first claim and kept across later ones, so a donor who claims
+/// before the creator tops up the pool can come back for the difference.
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct RefundClaim {
+    pub campaign: Pubkey,
+    pub doner: Pubkey,
+    /// Total refunded to the donor so far
+    pub paid: u64,
+    pub claimed_at: i64,
+}
@@ -0,0 +1,17 @@
+use anchor_lang::prelude::*;
+
+/// Timestamped record of a campaign's tree state, seeds `[b"snapshot", campaign, index]`
+///
+/// Written once by snapshot_root and never modified, so auditors can replay
+/// how the Merkle root evolved.
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct RootSnapshot {
+    pub campaign: Pubkey,
+    pub index: u64,
+    // Campaign's latest_merkle_root at snapshot time, a placeholder until
+    // donate_compressed reads the real root from the tree
+    pub merkle_root: [u8; 32],
+    pub donation_count: u64,
+    pub timestamp: i64,
+}
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+/// Linear payout of campaign funds to a recipient, seeds `[b"stream", campaign, recipient]`
+///
+/// Tokens sit in the stream PDA's associated token account and vest at
+/// `rate_per_second` from `start_time` until `deposited` is reached.
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct Stream {
+    pub campaign: Pubkey,
+    pub recipient: Pubkey,
+    /// Mint of the tokens held in the vault; claims must use the same mint
+    pub mint: Pubkey,
+    pub rate_per_second: u64,
+    pub start_time: i64,
+    pub deposited: u64,
+    pub claimed: u64,
+}
+
+impl Stream {
+    /// Total vested at `now`: `rate_per_second * elapsed`, capped at the deposit
+    pub fn vested_at(&self, now: i64) -> u64 {
+        let elapsed = u64::try_from(now.saturating_sub(self.start_time)).unwrap_or(0);
+        self.rate_per_second.saturating_mul(elapsed).min(self.deposited)
+    }
+
+    /// Vested tokens not yet claimed at `now`
+    pub fn claimable_at(&self, now: i64) -> u64 {
+        self.vested_at(now).saturating_sub(self.claimed)
+    }
+}
@@ -0,0 +1,11 @@
+use anchor_lang::prelude::*;
+
+/// Link from a Merkle tree to the campaign that owns it, seeds `[b"tree_link", merkle_tree]`
+///
+/// Lets event processors that only see the tree account derive and fetch the campaign.
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct TreeCampaignLink {
+    pub merkle_tree: Pubkey,
+    pub campaign: Pubkey,
+}
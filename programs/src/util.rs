@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{
+    allocate, assign, create_account, transfer, Allocate, Assign, CreateAccount, Transfer,
+};
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+
+use crate::error::ErrorCode;
+
+/// Canonical form of a campaign title, as stored on the campaign and used in its PDA seeds
+///
+/// Leading and trailing whitespace is trimmed, so a client that pads the title
+/// differently from the one used at creation still derives the same campaign PDA.
+pub fn canonical_title(title: &str) -> &str {
+    title.trim()
+}
+
+/// The mint must be owned by `token_program`, and `token_account` must be the
+/// associated token account of `authority` derived under that same program
+///
+/// Guards against a classic-derived ATA being paired with a Token-2022 mint (or
+/// the reverse), which otherwise only fails deep inside the transfer CPI.
+pub fn require_token_program(
+    mint: &AccountInfo,
+    token_program: &Pubkey,
+    token_account: &Pubkey,
+    authority: &Pubkey,
+) -> Result<()> {
+    require_keys_eq!(*mint.owner, *token_program, ErrorCode::TokenProgramMismatch);
+    require_keys_eq!(
+        *token_account,
+        get_associated_token_address_with_program_id(authority, mint.key, token_program),
+        ErrorCode::TokenProgramMismatch
+    );
+    Ok(())
+}
+
+/// Create the program-owned PDA `account` with `space` bytes, paid for by `payer`
+///
+/// Mirrors Anchor's `init`: anyone can send lamports to a PDA address before it
+/// exists, which makes `create_account` fail, so an already funded account is
+/// topped up to rent exemption, then allocated and assigned instead.
+pub fn create_pda_account<'info>(
+    payer: &AccountInfo<'info>,
+    account: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    space: usize,
+    signer_seeds: &[&[u8]],
+) -> Result<()> {
+    let rent_exempt = Rent::get()?.minimum_balance(space);
+    let signer = &[signer_seeds];
+
+    if account.lamports() == 0 {
+        return create_account(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                CreateAccount { from: payer.clone(), to: account.clone() },
+                signer,
+            ),
+            rent_exempt,
+            space as u64,
+            &crate::ID,
+        );
+    }
+
+    let top_up = rent_exempt.saturating_sub(account.lamports());
+    if top_up > 0 {
+        transfer(
+            CpiContext::new(system_program.clone(), Transfer { from: payer.clone(), to: account.clone() }),
+            top_up,
+        )?;
+    }
+    allocate(
+        CpiContext::new_with_signer(system_program.clone(), Allocate { account_to_allocate: account.clone() }, signer),
+        space as u64,
+    )?;
+    assign(
+        CpiContext::new_with_signer(system_program.clone(), Assign { account_to_assign: account.clone() }, signer),
+        &crate::ID,
+    )
+}
+
+/// Close the program-owned `account`, sending its lamports to `destination`
+///
+/// Mirrors Anchor's `close` for accounts the handler only learns exist at run
+/// time: the account is drained, handed back to the system program and emptied.
+pub fn close_pda_account<'info>(account: &AccountInfo<'info>, destination: &AccountInfo<'info>) -> Result<()> {
+    let lamports = account.lamports();
+    **destination.try_borrow_mut_lamports()? = destination
+        .lamports()
+        .checked_add(lamports)
+        .ok_or(error!(ErrorCode::ArithmeticOverflow))?;
+    **account.try_borrow_mut_lamports()? = 0;
+    account.assign(&anchor_lang::system_program::ID);
+    account.realloc(0, false)?;
+    Ok(())
+}